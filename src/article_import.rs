@@ -0,0 +1,319 @@
+use html5ever::parse_document;
+use html5ever::tendril::TendrilSink;
+use markup5ever_rcdom::{NodeData, RcDom};
+use std::rc::Rc;
+
+/// Tags whose subtree never contributes to the article body, regardless of
+/// how much text they contain.
+const SKIPPED_TAGS: &[&str] = &[
+    "script", "style", "nav", "header", "footer", "aside", "form", "button", "noscript",
+];
+
+pub struct ExtractedArticle {
+    pub title: String,
+    pub byline: Option<String>,
+    pub content_html: String,
+}
+
+/// Fetches `url` and runs readability-style main-content extraction on the
+/// returned HTML. Used by `BookManager::import_article_from_url` to turn a
+/// web article into an importable EPUB chapter.
+pub fn fetch_and_extract(url: &str) -> Result<ExtractedArticle, String> {
+    let html =
+        reqwest::blocking::get(url).and_then(|r| r.text()).map_err(|e| {
+            format!("Failed to fetch {url}: {e}")
+        })?;
+    Ok(extract_article(&html))
+}
+
+/// Runs the extraction over already-fetched `html` (split out from
+/// `fetch_and_extract` so the readability heuristic itself can be exercised
+/// without a network round-trip).
+pub fn extract_article(html: &str) -> ExtractedArticle {
+    let dom = parse_document(RcDom::default(), Default::default())
+        .from_utf8()
+        .read_from(&mut html.as_bytes())
+        .unwrap();
+
+    let title = find_title(&dom.document).unwrap_or_else(|| "Untitled Article".to_string());
+    let byline = find_byline(&dom.document);
+
+    let content_html = match find_best_candidate(&dom.document) {
+        Some(node) => serialize_children(&node),
+        None => String::new(),
+    };
+
+    let mut body = String::new();
+    body.push_str(&format!("<h1>{}</h1>\n", escape_html(&title)));
+    if let Some(byline) = &byline {
+        body.push_str(&format!("<p><em>{}</em></p>\n", escape_html(byline)));
+    }
+    body.push_str(&content_html);
+
+    ExtractedArticle {
+        title,
+        byline,
+        content_html: body,
+    }
+}
+
+fn tag_name(node: &Rc<markup5ever_rcdom::Node>) -> Option<String> {
+    match &node.data {
+        NodeData::Element { name, .. } => Some(name.local.as_ref().to_string()),
+        _ => None,
+    }
+}
+
+fn attr_value(node: &Rc<markup5ever_rcdom::Node>, attr: &str) -> Option<String> {
+    match &node.data {
+        NodeData::Element { attrs, .. } => attrs
+            .borrow()
+            .iter()
+            .find(|a| a.name.local.as_ref() == attr)
+            .map(|a| a.value.to_string()),
+        _ => None,
+    }
+}
+
+fn find_title(node: &Rc<markup5ever_rcdom::Node>) -> Option<String> {
+    if tag_name(node).as_deref() == Some("title") {
+        return Some(text_content(node).trim().to_string()).filter(|s| !s.is_empty());
+    }
+    for child in node.children.borrow().iter() {
+        if let Some(title) = find_title(child) {
+            return Some(title);
+        }
+    }
+    None
+}
+
+/// Looks for the first element whose `class` or `id` mentions "byline" or
+/// "author" - the common convention article templates use for the
+/// author/date line - and returns its text.
+fn find_byline(node: &Rc<markup5ever_rcdom::Node>) -> Option<String> {
+    let looks_like_byline = ["class", "id"].iter().any(|attr| {
+        attr_value(node, attr)
+            .map(|v| {
+                let v = v.to_lowercase();
+                v.contains("byline") || v.contains("author")
+            })
+            .unwrap_or(false)
+    });
+    if looks_like_byline {
+        let text = text_content(node).trim().to_string();
+        if !text.is_empty() {
+            return Some(text);
+        }
+    }
+    for child in node.children.borrow().iter() {
+        if let Some(byline) = find_byline(child) {
+            return Some(byline);
+        }
+    }
+    None
+}
+
+fn text_content(node: &Rc<markup5ever_rcdom::Node>) -> String {
+    let mut result = String::new();
+    collect_text(node, &mut result);
+    result
+}
+
+fn collect_text(node: &Rc<markup5ever_rcdom::Node>, out: &mut String) {
+    match &node.data {
+        NodeData::Text { contents } => out.push_str(&contents.borrow()),
+        _ => {
+            for child in node.children.borrow().iter() {
+                collect_text(child, out);
+            }
+        }
+    }
+}
+
+/// Walks the DOM scoring every element by text density (text length minus
+/// link-text length, boosted for `<p>`/`<article>`, penalized for high link
+/// density) and returns the highest-scoring subtree - the readability
+/// heuristic's "article root".
+fn find_best_candidate(root: &Rc<markup5ever_rcdom::Node>) -> Option<Rc<markup5ever_rcdom::Node>> {
+    let mut best: Option<(f64, Rc<markup5ever_rcdom::Node>)> = None;
+    score_candidates(root, &mut best);
+    best.map(|(_, node)| node)
+}
+
+fn score_candidates(
+    node: &Rc<markup5ever_rcdom::Node>,
+    best: &mut Option<(f64, Rc<markup5ever_rcdom::Node>)>,
+) {
+    if let Some(tag) = tag_name(node) {
+        if SKIPPED_TAGS.contains(&tag.as_str()) {
+            return;
+        }
+        if matches!(
+            tag.as_str(),
+            "div" | "section" | "article" | "main" | "td" | "body"
+        ) {
+            let score = score_node(node, &tag);
+            if score > 0.0 && best.as_ref().map(|(s, _)| score > *s).unwrap_or(true) {
+                *best = Some((score, node.clone()));
+            }
+        }
+    }
+    for child in node.children.borrow().iter() {
+        score_candidates(child, best);
+    }
+}
+
+/// Text-density score for `node`: total text length minus the text found
+/// inside `<a>` tags, boosted for paragraph-heavy/article containers and
+/// penalized the higher the fraction of its text that's link text.
+fn score_node(node: &Rc<markup5ever_rcdom::Node>, tag: &str) -> f64 {
+    let text_len = text_content(node).trim().len() as f64;
+    if text_len == 0.0 {
+        return 0.0;
+    }
+    let link_len = link_text_len(node) as f64;
+    let link_density = link_len / text_len;
+
+    let mut score = text_len - link_len;
+    score += (count_descendant_tag(node, "p") as f64) * 25.0;
+    if tag == "article" {
+        score += 200.0;
+    }
+    if tag == "main" {
+        score += 100.0;
+    }
+    score * (1.0 - link_density.min(0.9))
+}
+
+fn link_text_len(node: &Rc<markup5ever_rcdom::Node>) -> usize {
+    let mut total = 0;
+    if tag_name(node).as_deref() == Some("a") {
+        total += text_content(node).len();
+    }
+    for child in node.children.borrow().iter() {
+        total += link_text_len(child);
+    }
+    total
+}
+
+fn count_descendant_tag(node: &Rc<markup5ever_rcdom::Node>, tag: &str) -> usize {
+    let mut count = if tag_name(node).as_deref() == Some(tag) {
+        1
+    } else {
+        0
+    };
+    for child in node.children.borrow().iter() {
+        count += count_descendant_tag(child, tag);
+    }
+    count
+}
+
+/// Serializes `node`'s children back to an HTML string, dropping skipped
+/// tags (scripts, nav chrome, ...) encountered along the way.
+fn serialize_children(node: &Rc<markup5ever_rcdom::Node>) -> String {
+    let mut html = String::new();
+    for child in node.children.borrow().iter() {
+        serialize_node(child, &mut html);
+    }
+    html
+}
+
+fn serialize_node(node: &Rc<markup5ever_rcdom::Node>, html: &mut String) {
+    match &node.data {
+        NodeData::Element { name, attrs, .. } => {
+            let tag = name.local.as_ref();
+            if SKIPPED_TAGS.contains(&tag) {
+                return;
+            }
+            html.push('<');
+            html.push_str(tag);
+            for attr in attrs.borrow().iter() {
+                html.push(' ');
+                html.push_str(&attr.name.local);
+                html.push_str("=\"");
+                html.push_str(&escape_html_attr(&attr.value));
+                html.push('"');
+            }
+            html.push('>');
+            for child in node.children.borrow().iter() {
+                serialize_node(child, html);
+            }
+            html.push_str("</");
+            html.push_str(tag);
+            html.push('>');
+        }
+        NodeData::Text { contents } => {
+            html.push_str(&escape_html(&contents.borrow()));
+        }
+        _ => {
+            for child in node.children.borrow().iter() {
+                serialize_node(child, html);
+            }
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Like `escape_html`, but also escapes `"` so the result is safe to embed
+/// inside a double-quoted attribute value (`escape_html` alone would let a
+/// `"` in e.g. a `title`/`alt`/`href` close the attribute early and corrupt
+/// the serialized tag).
+fn escape_html_attr(text: &str) -> String {
+    escape_html(text).replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_article_body_over_a_link_heavy_sidebar() {
+        let html = r#"
+            <html><head><title>A Great Story</title></head>
+            <body>
+                <nav><a href="/a">Home</a><a href="/b">About</a><a href="/c">Contact</a></nav>
+                <div class="sidebar">
+                    <a href="/x">Related 1</a>
+                    <a href="/y">Related 2</a>
+                    <a href="/z">Related 3</a>
+                </div>
+                <article>
+                    <p class="byline">By Jane Doe</p>
+                    <p>Once upon a time, in a land far away, there lived a curious
+                    programmer who loved reading books on the command line.</p>
+                    <p>One day, the programmer decided to build a reader that could
+                    import articles directly from the web for offline reading.</p>
+                </article>
+            </body>
+            </html>
+        "#;
+
+        let article = extract_article(html);
+        assert_eq!(article.title, "A Great Story");
+        assert!(article.content_html.contains("curious programmer"));
+        assert!(!article.content_html.contains("Related 1"));
+    }
+
+    #[test]
+    fn extracts_a_byline_by_class_name() {
+        let html = r#"
+            <html><head><title>T</title></head>
+            <body>
+                <article>
+                    <p class="byline">By Jane Doe</p>
+                    <p>Body text that is long enough to be picked as the
+                    main content of this very small test article.</p>
+                </article>
+            </body>
+            </html>
+        "#;
+
+        let article = extract_article(html);
+        assert_eq!(article.byline.as_deref(), Some("By Jane Doe"));
+    }
+}
@@ -5,14 +5,29 @@ use ratatui::{
     layout::Rect,
     style::Style,
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, ListState},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+/// Press-drag-release state for reordering the book list, distinct from
+/// the content panel's text-selection drag state. `PressedOnItem` hasn't
+/// moved far enough yet to count as a drag (see `DRAG_THRESHOLD_ROWS`), so
+/// a plain click still just selects/opens the item underneath it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DragState {
+    PressedOnItem { index: usize, press_row: u16 },
+    Dragging { from: usize, target: usize },
+}
+
+/// Rows the pointer must travel from its initial press before a press is
+/// reinterpreted as a drag rather than a click.
+const DRAG_THRESHOLD_ROWS: u16 = 2;
+
 pub struct BookList {
     pub selected: usize,
     pub list_state: ListState,
     book_infos: Vec<BookInfo>,
+    drag: Option<DragState>,
 }
 
 impl BookList {
@@ -28,6 +43,7 @@ impl BookList {
             selected: 0,
             list_state,
             book_infos: books,
+            drag: None,
         }
     }
 
@@ -65,24 +81,101 @@ impl BookList {
     /// Handle mouse click at the given position
     /// Returns true if an item was clicked
     pub fn handle_mouse_click(&mut self, _x: u16, y: u16, area: Rect) -> bool {
-        // Account for the border (1 line at top and bottom)
-        if y > area.y && y < area.y + area.height - 1 {
-            let relative_y = y - area.y - 1; // Subtract 1 for the top border
+        if let Some(new_index) = self.row_to_index(y, area) {
+            self.selected = new_index;
+            self.list_state.select(Some(new_index));
+            return true;
+        }
+        false
+    }
 
-            // Get the current scroll offset from the list_state
+    /// Maps a screen row to a book index, accounting for the border and
+    /// current scroll offset, or `None` if `y` falls outside the list.
+    fn row_to_index(&self, y: u16, area: Rect) -> Option<usize> {
+        if y > area.y && y < area.y + area.height.saturating_sub(1) {
+            let relative_y = y - area.y - 1; // Subtract 1 for the top border
             let offset = self.list_state.offset();
+            let index = offset + relative_y as usize;
+            if index < self.book_infos.len() {
+                return Some(index);
+            }
+        }
+        None
+    }
 
-            // Calculate the actual index in the list
-            let new_index = offset + relative_y as usize;
+    /// A mouse press on `y` arms a potential drag on whatever item is
+    /// underneath, without moving anything yet - `handle_drag` decides
+    /// once the pointer has actually moved past the threshold.
+    pub fn start_press(&mut self, y: u16, area: Rect) {
+        if let Some(index) = self.row_to_index(y, area) {
+            self.drag = Some(DragState::PressedOnItem {
+                index,
+                press_row: y,
+            });
+        }
+    }
+
+    /// Updates the in-progress press/drag as the pointer moves to `y`,
+    /// promoting a bare press to an actual drag once it crosses
+    /// `DRAG_THRESHOLD_ROWS`.
+    pub fn handle_drag(&mut self, y: u16, area: Rect) {
+        let Some(state) = self.drag else {
+            return;
+        };
 
-            // Check if the click is within the valid range
-            if new_index < self.book_infos.len() {
-                self.selected = new_index;
-                self.list_state.select(Some(new_index));
-                return true;
+        match state {
+            DragState::PressedOnItem { index, press_row } => {
+                if y.abs_diff(press_row) >= DRAG_THRESHOLD_ROWS {
+                    let target = self.row_to_index(y, area).unwrap_or(index);
+                    self.drag = Some(DragState::Dragging {
+                        from: index,
+                        target,
+                    });
+                }
+            }
+            DragState::Dragging { from, target } => {
+                let new_target = self.row_to_index(y, area).unwrap_or(target);
+                self.drag = Some(DragState::Dragging {
+                    from,
+                    target: new_target,
+                });
             }
         }
-        false
+    }
+
+    /// Whether the pointer has moved far enough to be an active drag
+    /// (rather than just a pressed, unmoved item).
+    pub fn is_dragging(&self) -> bool {
+        matches!(self.drag, Some(DragState::Dragging { .. }))
+    }
+
+    /// Resolves a press/drag on mouse release: reorders the list if an
+    /// actual drag had started and landed somewhere new, and clears the
+    /// drag state either way. Returns the `(from, to)` indices moved, for
+    /// the caller to mirror into `BookManager`.
+    pub fn end_drag(&mut self) -> Option<(usize, usize)> {
+        match self.drag.take() {
+            Some(DragState::Dragging { from, target }) if from != target => {
+                self.reorder(from, target);
+                Some((from, target))
+            }
+            _ => None,
+        }
+    }
+
+    /// Moves the book at `from` to `to`, keeping the selection and
+    /// highlight following the moved item.
+    fn reorder(&mut self, from: usize, to: usize) {
+        if from == to || from >= self.book_infos.len() || to >= self.book_infos.len() {
+            return;
+        }
+        let book = self.book_infos.remove(from);
+        self.book_infos.insert(to, book);
+
+        if self.selected == from {
+            self.selected = to;
+        }
+        self.list_state.select(Some(self.selected));
     }
 
     pub fn render(
@@ -150,6 +243,43 @@ impl BookList {
             .style(Style::default().bg(palette.base_00));
 
         f.render_stateful_widget(files, area, &mut self.list_state);
+
+        if let Some(DragState::Dragging { from, target }) = self.drag
+            && let Some(book_info) = self.book_infos.get(from)
+        {
+            self.render_drag_indicator(f, area, target, &book_info.display_name, palette);
+        }
+    }
+
+    /// Renders a one-line floating indicator carrying the dragged book's
+    /// title over the row it would land on if dropped now, so the user
+    /// can see where the reorder is headed.
+    fn render_drag_indicator(
+        &self,
+        f: &mut Frame,
+        area: Rect,
+        target: usize,
+        display_name: &str,
+        palette: &Base16Palette,
+    ) {
+        let Some(target_row) = target.checked_sub(self.list_state.offset()) else {
+            return;
+        };
+        let row = area.y + 1 + target_row as u16;
+        if row >= area.y + area.height.saturating_sub(1) {
+            return;
+        }
+
+        let indicator_area = Rect::new(area.x + 1, row, area.width.saturating_sub(2), 1);
+        f.render_widget(Clear, indicator_area);
+        f.render_widget(
+            Paragraph::new(display_name.to_string()).style(
+                Style::default()
+                    .bg(palette.base_0a)
+                    .fg(palette.base_00),
+            ),
+            indicator_area,
+        );
     }
 }
 
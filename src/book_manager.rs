@@ -126,9 +126,7 @@ impl BookManager {
         html_content: &str,
         original_path: &str,
     ) -> Result<EpubDoc<BufReader<std::fs::File>>, String> {
-        use std::io::Write;
         use tempfile::NamedTempFile;
-        use zip::{ZipWriter, write::FileOptions};
 
         // Extract title from HTML, or use full filename (with .html) as fallback
         let filename = Path::new(original_path)
@@ -146,116 +144,12 @@ impl BookManager {
 
         let temp_path = temp_file.path().to_path_buf();
 
-        {
-            let file = std::fs::File::create(&temp_path)
-                .map_err(|e| format!("Failed to create temp EPUB file: {}", e))?;
-
-            let mut zip = ZipWriter::new(file);
-            let options = FileOptions::default().compression_method(zip::CompressionMethod::Stored);
-
-            // Add mimetype file
-            zip.start_file("mimetype", options)
-                .map_err(|e| format!("Failed to add mimetype: {}", e))?;
-            zip.write_all(b"application/epub+zip")
-                .map_err(|e| format!("Failed to write mimetype: {}", e))?;
-
-            // Add META-INF/container.xml
-            zip.start_file("META-INF/container.xml", options)
-                .map_err(|e| format!("Failed to add container.xml: {}", e))?;
-            let container_xml = r#"<?xml version="1.0" encoding="UTF-8"?>
-<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
-    <rootfiles>
-        <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
-    </rootfiles>
-</container>"#;
-            zip.write_all(container_xml.as_bytes())
-                .map_err(|e| format!("Failed to write container.xml: {}", e))?;
-
-            // Add OEBPS/content.opf
-            zip.start_file("OEBPS/content.opf", options)
-                .map_err(|e| format!("Failed to add content.opf: {}", e))?;
-            let content_opf = format!(
-                r#"<?xml version="1.0" encoding="UTF-8"?>
-<package xmlns="http://www.idpf.org/2007/opf" unique-identifier="bookid" version="2.0">
-    <metadata>
-        <dc:title xmlns:dc="http://purl.org/dc/elements/1.1/">{}</dc:title>
-        <dc:identifier xmlns:dc="http://purl.org/dc/elements/1.1/" id="bookid">html-{}</dc:identifier>
-        <dc:language xmlns:dc="http://purl.org/dc/elements/1.1/">en</dc:language>
-    </metadata>
-    <manifest>
-        <item id="chapter1" href="chapter1.xhtml" media-type="application/xhtml+xml"/>
-        <item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>
-    </manifest>
-    <spine toc="ncx">
-        <itemref idref="chapter1"/>
-    </spine>
-</package>"#,
-                title,
-                original_path.replace('/', "_")
-            );
-            zip.write_all(content_opf.as_bytes())
-                .map_err(|e| format!("Failed to write content.opf: {}", e))?;
-
-            // Add OEBPS/toc.ncx (table of contents)
-            zip.start_file("OEBPS/toc.ncx", options)
-                .map_err(|e| format!("Failed to add toc.ncx: {}", e))?;
-            let toc_ncx = format!(
-                r#"<?xml version="1.0" encoding="UTF-8"?>
-<ncx xmlns="http://www.daisy.org/z3986/2005/ncx/" version="2005-1">
-    <head>
-        <meta name="dtb:uid" content="html-{}"/>
-        <meta name="dtb:depth" content="1"/>
-        <meta name="dtb:totalPageCount" content="0"/>
-        <meta name="dtb:maxPageNumber" content="0"/>
-    </head>
-    <docTitle>
-        <text>{}</text>
-    </docTitle>
-    <navMap>
-        <navPoint id="chapter1" playOrder="1">
-            <navLabel>
-                <text>{}</text>
-            </navLabel>
-            <content src="chapter1.xhtml"/>
-        </navPoint>
-    </navMap>
-</ncx>"#,
-                original_path.replace('/', "_"),
-                title,
-                filename
-            );
-            zip.write_all(toc_ncx.as_bytes())
-                .map_err(|e| format!("Failed to write toc.ncx: {}", e))?;
-
-            // Add OEBPS/chapter1.xhtml with the HTML content
-            zip.start_file("OEBPS/chapter1.xhtml", options)
-                .map_err(|e| format!("Failed to add chapter1.xhtml: {}", e))?;
-
-            // Wrap HTML content in proper XHTML structure if it's not already
-            let xhtml_content = if html_content.contains("<!DOCTYPE") {
-                html_content.to_string()
-            } else {
-                format!(
-                    r#"<?xml version="1.0" encoding="UTF-8"?>
-<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.1//EN" "http://www.w3.org/TR/xhtml11/DTD/xhtml11.dtd">
-<html xmlns="http://www.w3.org/1999/xhtml">
-<head>
-    <title>{}</title>
-</head>
-<body>
-{}
-</body>
-</html>"#,
-                    title, html_content
-                )
-            };
-
-            zip.write_all(xhtml_content.as_bytes())
-                .map_err(|e| format!("Failed to write chapter1.xhtml: {}", e))?;
-
-            zip.finish()
-                .map_err(|e| format!("Failed to finish ZIP: {}", e))?;
-        }
+        write_minimal_epub(
+            &title,
+            &original_path.replace('/', "_"),
+            html_content,
+            &temp_path,
+        )?;
 
         // Now open the temporary EPUB file
         match EpubDoc::new(&temp_path) {
@@ -304,6 +198,19 @@ impl BookManager {
         self.books = Self::discover_books_in_dir(&self.scan_directory);
     }
 
+    /// Moves the book at `from` to `to`, for drag-and-drop reordering in
+    /// the book list. Out-of-range or no-op indices are ignored. The new
+    /// order lives only in `self.books`, same as the rest of this list's
+    /// state - a later `refresh_books` rescan starts over from whatever
+    /// order the filesystem returns, just as it already does today.
+    pub fn reorder(&mut self, from: usize, to: usize) {
+        if from == to || from >= self.books.len() || to >= self.books.len() {
+            return;
+        }
+        let book = self.books.remove(from);
+        self.books.insert(to, book);
+    }
+
     pub fn find_book_index_by_path(&self, path: &str) -> Option<usize> {
         self.books.iter().position(|book| book.path == path)
     }
@@ -319,4 +226,160 @@ impl BookManager {
             None => false,
         }
     }
+
+    /// Fetches `url`, runs readability-style main-content extraction on it
+    /// (see `crate::article_import`), writes the result as a minimal
+    /// single-chapter EPUB into this manager's scan directory, and
+    /// refreshes the book list so it shows up immediately. Returns the new
+    /// file's path.
+    pub fn import_article_from_url(&mut self, url: &str) -> Result<String, String> {
+        let article = crate::article_import::fetch_and_extract(url)?;
+        let file_name = format!("{}.epub", slugify(&article.title));
+        let dest_path = Path::new(&self.scan_directory).join(&file_name);
+
+        write_minimal_epub(&article.title, url, &article.content_html, &dest_path)?;
+
+        info!("Imported article from {} into {:?}", url, dest_path);
+        self.refresh_books();
+        Ok(dest_path.to_string_lossy().to_string())
+    }
+}
+
+/// Lowercases `title`, keeps alphanumerics, and joins words with `-`, for
+/// use as a filesystem-safe EPUB file name.
+fn slugify(title: &str) -> String {
+    let slug: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let slug = slug.split_whitespace().collect::<Vec<_>>().join("-");
+    if slug.is_empty() {
+        "imported-article".to_string()
+    } else {
+        slug
+    }
+}
+
+/// Writes a minimal single-chapter EPUB containing `body_html` to
+/// `dest_path`, with `title` as its metadata title and `unique_id` folded
+/// into its EPUB identifier. Shared by `create_minimal_epub_from_html`
+/// (which writes to a temp file) and `BookManager::import_article_from_url`
+/// (which writes straight into the library directory).
+fn write_minimal_epub(
+    title: &str,
+    unique_id: &str,
+    body_html: &str,
+    dest_path: &Path,
+) -> Result<(), String> {
+    use std::io::Write;
+    use zip::{ZipWriter, write::FileOptions};
+
+    let file =
+        std::fs::File::create(dest_path).map_err(|e| format!("Failed to create EPUB file: {}", e))?;
+
+    let mut zip = ZipWriter::new(file);
+    let options = FileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    // Add mimetype file
+    zip.start_file("mimetype", options)
+        .map_err(|e| format!("Failed to add mimetype: {}", e))?;
+    zip.write_all(b"application/epub+zip")
+        .map_err(|e| format!("Failed to write mimetype: {}", e))?;
+
+    // Add META-INF/container.xml
+    zip.start_file("META-INF/container.xml", options)
+        .map_err(|e| format!("Failed to add container.xml: {}", e))?;
+    let container_xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+    <rootfiles>
+        <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+    </rootfiles>
+</container>"#;
+    zip.write_all(container_xml.as_bytes())
+        .map_err(|e| format!("Failed to write container.xml: {}", e))?;
+
+    // Add OEBPS/content.opf
+    zip.start_file("OEBPS/content.opf", options)
+        .map_err(|e| format!("Failed to add content.opf: {}", e))?;
+    let content_opf = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" unique-identifier="bookid" version="2.0">
+    <metadata>
+        <dc:title xmlns:dc="http://purl.org/dc/elements/1.1/">{}</dc:title>
+        <dc:identifier xmlns:dc="http://purl.org/dc/elements/1.1/" id="bookid">html-{}</dc:identifier>
+        <dc:language xmlns:dc="http://purl.org/dc/elements/1.1/">en</dc:language>
+    </metadata>
+    <manifest>
+        <item id="chapter1" href="chapter1.xhtml" media-type="application/xhtml+xml"/>
+        <item id="ncx" href="toc.ncx" media-type="application/x-dtbncx+xml"/>
+    </manifest>
+    <spine toc="ncx">
+        <itemref idref="chapter1"/>
+    </spine>
+</package>"#,
+        title, unique_id
+    );
+    zip.write_all(content_opf.as_bytes())
+        .map_err(|e| format!("Failed to write content.opf: {}", e))?;
+
+    // Add OEBPS/toc.ncx (table of contents)
+    zip.start_file("OEBPS/toc.ncx", options)
+        .map_err(|e| format!("Failed to add toc.ncx: {}", e))?;
+    let toc_ncx = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ncx xmlns="http://www.daisy.org/z3986/2005/ncx/" version="2005-1">
+    <head>
+        <meta name="dtb:uid" content="html-{}"/>
+        <meta name="dtb:depth" content="1"/>
+        <meta name="dtb:totalPageCount" content="0"/>
+        <meta name="dtb:maxPageNumber" content="0"/>
+    </head>
+    <docTitle>
+        <text>{}</text>
+    </docTitle>
+    <navMap>
+        <navPoint id="chapter1" playOrder="1">
+            <navLabel>
+                <text>{}</text>
+            </navLabel>
+            <content src="chapter1.xhtml"/>
+        </navPoint>
+    </navMap>
+</ncx>"#,
+        unique_id, title, title
+    );
+    zip.write_all(toc_ncx.as_bytes())
+        .map_err(|e| format!("Failed to write toc.ncx: {}", e))?;
+
+    // Add OEBPS/chapter1.xhtml with the HTML content
+    zip.start_file("OEBPS/chapter1.xhtml", options)
+        .map_err(|e| format!("Failed to add chapter1.xhtml: {}", e))?;
+
+    // Wrap HTML content in proper XHTML structure if it's not already
+    let xhtml_content = if body_html.contains("<!DOCTYPE") {
+        body_html.to_string()
+    } else {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html PUBLIC "-//W3C//DTD XHTML 1.1//EN" "http://www.w3.org/TR/xhtml11/DTD/xhtml11.dtd">
+<html xmlns="http://www.w3.org/1999/xhtml">
+<head>
+    <title>{}</title>
+</head>
+<body>
+{}
+</body>
+</html>"#,
+            title, body_html
+        )
+    };
+
+    zip.write_all(xhtml_content.as_bytes())
+        .map_err(|e| format!("Failed to write chapter1.xhtml: {}", e))?;
+
+    zip.finish()
+        .map_err(|e| format!("Failed to finish ZIP: {}", e))?;
+
+    Ok(())
 }
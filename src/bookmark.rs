@@ -10,6 +10,22 @@ pub struct Bookmark {
     pub last_read: chrono::DateTime<chrono::Utc>,
     #[serde(default)]
     pub total_chapters: usize,
+    /// User-labeled positions within the book, in addition to the single
+    /// implicit last-read position above (e.g. "cliffhanger", "argument
+    /// starts here"). Old bookmark files predate this field, hence the
+    /// default so they keep deserializing cleanly.
+    #[serde(default)]
+    pub named_bookmarks: Vec<NamedBookmark>,
+}
+
+/// A single user-labeled position inside a book, distinct from the
+/// implicit last-read position tracked on `Bookmark` itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedBookmark {
+    pub label: String,
+    pub chapter: usize,
+    pub scroll_offset: usize,
+    pub created_at: chrono::DateTime<chrono::Utc>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -88,6 +104,14 @@ impl Bookmarks {
         scroll_offset: usize,
         total_chapters: usize,
     ) {
+        // Preserve any named bookmarks already recorded for this book; only
+        // the implicit last-read position is being replaced.
+        let named_bookmarks = self
+            .books
+            .get(path)
+            .map(|b| b.named_bookmarks.clone())
+            .unwrap_or_default();
+
         self.books.insert(
             path.to_string(),
             Bookmark {
@@ -95,8 +119,80 @@ impl Bookmarks {
                 scroll_offset,
                 last_read: chrono::Utc::now(),
                 total_chapters,
+                named_bookmarks,
             },
         );
+        self.save_if_persistent();
+    }
+
+    /// Adds a new named bookmark for `path`, creating a (zero-progress)
+    /// book entry first if one doesn't exist yet. Returns the index of the
+    /// new bookmark within that book's list.
+    pub fn add_named_bookmark(
+        &mut self,
+        path: &str,
+        label: String,
+        chapter: usize,
+        scroll_offset: usize,
+    ) -> usize {
+        let book = self.books.entry(path.to_string()).or_insert_with(|| Bookmark {
+            chapter,
+            scroll_offset,
+            last_read: chrono::Utc::now(),
+            total_chapters: 0,
+            named_bookmarks: Vec::new(),
+        });
+
+        book.named_bookmarks.push(NamedBookmark {
+            label,
+            chapter,
+            scroll_offset,
+            created_at: chrono::Utc::now(),
+        });
+        let index = book.named_bookmarks.len() - 1;
+        self.save_if_persistent();
+        index
+    }
+
+    /// Renames the named bookmark at `index` for `path`. Returns `false` if
+    /// `path` or `index` doesn't exist.
+    pub fn rename_named_bookmark(&mut self, path: &str, index: usize, new_label: String) -> bool {
+        let Some(bookmark) = self
+            .books
+            .get_mut(path)
+            .and_then(|b| b.named_bookmarks.get_mut(index))
+        else {
+            return false;
+        };
+        bookmark.label = new_label;
+        self.save_if_persistent();
+        true
+    }
+
+    /// Deletes the named bookmark at `index` for `path`. Returns `false` if
+    /// `path` or `index` doesn't exist.
+    pub fn delete_named_bookmark(&mut self, path: &str, index: usize) -> bool {
+        let Some(book) = self.books.get_mut(path) else {
+            return false;
+        };
+        if index >= book.named_bookmarks.len() {
+            return false;
+        }
+        book.named_bookmarks.remove(index);
+        self.save_if_persistent();
+        true
+    }
+
+    /// Named bookmarks recorded for `path`, in creation order, or an empty
+    /// slice if the book has none (or isn't tracked at all yet).
+    pub fn named_bookmarks(&self, path: &str) -> &[NamedBookmark] {
+        self.books
+            .get(path)
+            .map(|b| b.named_bookmarks.as_slice())
+            .unwrap_or(&[])
+    }
+
+    fn save_if_persistent(&self) {
         // Only try to save if we have at least one bookmark and we're not ephemeral
         if !self.books.is_empty() && self.file_path.is_some() {
             if let Err(e) = self.save() {
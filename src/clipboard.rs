@@ -0,0 +1,36 @@
+/// Abstraction over the OS clipboard so callers don't depend on `arboard`
+/// directly, and so tests can inject a mock and assert on copied contents
+/// without touching the real system clipboard.
+pub trait ClipboardProvider: std::fmt::Debug {
+    fn set_text(&mut self, text: String) -> Result<(), String>;
+}
+
+/// Default `ClipboardProvider` backed by the real OS clipboard via `arboard`.
+#[derive(Debug, Default)]
+pub struct SystemClipboard;
+
+impl ClipboardProvider for SystemClipboard {
+    fn set_text(&mut self, text: String) -> Result<(), String> {
+        let mut clipboard =
+            arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {}", e))?;
+        clipboard
+            .set_text(text)
+            .map_err(|e| format!("Failed to copy to clipboard: {}", e))
+    }
+}
+
+/// Test double that records copied text instead of touching the real
+/// clipboard, so `SimulatedEventSource`-driven tests can assert on it.
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Debug, Default)]
+pub struct MockClipboard {
+    pub copied: Vec<String>,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl ClipboardProvider for MockClipboard {
+    fn set_text(&mut self, text: String) -> Result<(), String> {
+        self.copied.push(text);
+        Ok(())
+    }
+}
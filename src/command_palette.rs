@@ -0,0 +1,227 @@
+use crate::search::{SearchMatch, find_fuzzy_matches_in_text};
+use crate::theme::Base16Palette;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// One action the palette can dispatch, identified so `App` can match on it
+/// and call the exact same handler a keybinding would - the palette is a
+/// second way to reach these actions, not a separate implementation of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandId {
+    ToggleToc,
+    ToggleViCursorMode,
+    GoToChapter,
+    CopySelection,
+    ReloadBook,
+    JumpToReadingPosition,
+    CycleTheme,
+}
+
+struct CommandEntry {
+    id: CommandId,
+    label: &'static str,
+}
+
+const COMMANDS: &[CommandEntry] = &[
+    CommandEntry {
+        id: CommandId::ToggleToc,
+        label: "Toggle table of contents",
+    },
+    CommandEntry {
+        id: CommandId::ToggleViCursorMode,
+        label: "Toggle vi cursor mode",
+    },
+    CommandEntry {
+        id: CommandId::GoToChapter,
+        label: "Go to chapter",
+    },
+    CommandEntry {
+        id: CommandId::CopySelection,
+        label: "Copy selection to clipboard",
+    },
+    CommandEntry {
+        id: CommandId::ReloadBook,
+        label: "Reload book",
+    },
+    CommandEntry {
+        id: CommandId::JumpToReadingPosition,
+        label: "Jump to reading position",
+    },
+    CommandEntry {
+        id: CommandId::CycleTheme,
+        label: "Change theme",
+    },
+];
+
+fn command_labels() -> Vec<String> {
+    COMMANDS.iter().map(|c| c.label.to_string()).collect()
+}
+
+/// `SearchMatch`es for every command, in declared order, as if each matched
+/// with no highlighted ranges - used as the "no query typed yet" list.
+fn all_commands_matched() -> Vec<SearchMatch> {
+    (0..COMMANDS.len())
+        .map(|index| SearchMatch {
+            index,
+            score: 1.0,
+            highlight_ranges: Vec::new(),
+        })
+        .collect()
+}
+
+/// What `App` should do after a palette key event.
+pub enum CommandPaletteAction {
+    Run(CommandId),
+    Close,
+}
+
+/// Fuzzy-filtered command palette overlay (`:` or `Ctrl-P`), inspired by
+/// Helix's `command_palette`: lists every reader action by name and
+/// dispatches the selected one back to `App`, which runs it through the
+/// same handler its keybinding already calls. Filtering reuses the same
+/// subsequence-match helper as the TOC picker and vim-style `/` search.
+pub struct CommandPalette {
+    query: String,
+    matches: Vec<SearchMatch>,
+    selected: usize,
+}
+
+impl Default for CommandPalette {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            matches: all_commands_matched(),
+            selected: 0,
+        }
+    }
+
+    fn refilter(&mut self) {
+        self.matches = if self.query.is_empty() {
+            all_commands_matched()
+        } else {
+            find_fuzzy_matches_in_text(&self.query, &command_labels())
+        };
+        self.selected = 0;
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let len = self.matches.len() as i32;
+        self.selected = (self.selected as i32 + delta).rem_euclid(len) as usize;
+    }
+
+    /// Routes one key event to the palette. Returns `Close` on Esc,
+    /// `Run(id)` on Enter against a selected command, or `None` while the
+    /// user is still typing/navigating.
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<CommandPaletteAction> {
+        match key.code {
+            KeyCode::Esc => Some(CommandPaletteAction::Close),
+            KeyCode::Up => {
+                self.move_selection(-1);
+                None
+            }
+            KeyCode::Down => {
+                self.move_selection(1);
+                None
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.refilter();
+                None
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.refilter();
+                None
+            }
+            KeyCode::Enter => self
+                .matches
+                .get(self.selected)
+                .and_then(|m| COMMANDS.get(m.index))
+                .map(|command| CommandPaletteAction::Run(command.id)),
+            _ => None,
+        }
+    }
+
+    /// Draws the palette as a centered popup: a query line on top, the
+    /// fuzzy-filtered command list below it with the selection highlighted.
+    pub fn render(&self, f: &mut Frame, area: Rect, palette: &Base16Palette) {
+        let popup_area = centered_rect(50, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(popup_area);
+
+        let query_block = Block::default()
+            .borders(Borders::ALL)
+            .title("Command Palette")
+            .style(Style::default().bg(palette.base_00).fg(palette.base_05));
+        let query_paragraph = Paragraph::new(format!("> {}", self.query))
+            .block(query_block)
+            .style(Style::default().fg(palette.base_06));
+        f.render_widget(query_paragraph, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .matches
+            .iter()
+            .filter_map(|m| COMMANDS.get(m.index))
+            .map(|command| ListItem::new(Line::from(Span::raw(command.label))))
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().bg(palette.base_00).fg(palette.base_05)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(palette.base_02)
+                    .fg(palette.base_06)
+                    .add_modifier(Modifier::BOLD),
+            );
+
+        let mut list_state = ListState::default();
+        if !self.matches.is_empty() {
+            list_state.select(Some(self.selected));
+        }
+        f.render_stateful_widget(list, chunks[1], &mut list_state);
+    }
+}
+
+/// Centers a `percent_x` x `percent_y` rect within `r`.
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -1,9 +1,19 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::collections::HashMap;
+use slotmap::{SecondaryMap, SlotMap, new_key_type};
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::search::edit_distance;
+
+new_key_type! {
+    /// Stable identity for a [`Comment`] within a [`BookComments`] store,
+    /// valid across deletions (unlike a `Vec` index, which shifts).
+    pub struct CommentKey;
+}
 
 /// PDF selection rectangle (pixel coordinates relative to page)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -347,6 +357,10 @@ pub struct Comment {
     pub updated_at: DateTime<Utc>,
     /// The quoted/selected text that this comment refers to (primarily for PDF)
     pub quoted_text: Option<String>,
+    /// User-assigned labels (e.g. "todo", "question") for filtering across chapters
+    pub tags: Vec<String>,
+    /// The comment this one replies to, if any. Forms a thread when chained.
+    pub parent_id: Option<String>,
 }
 
 /// Serde representation for Text CommentTarget (EPUB format with node_index + subtarget)
@@ -370,6 +384,10 @@ struct CommentTextSerde {
     pub updated_at: DateTime<Utc>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quoted_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
 }
 
 /// Modern format for PDF comments
@@ -385,6 +403,10 @@ struct CommentPdfSerde {
     pub updated_at: DateTime<Utc>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quoted_text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
 }
 
 /// Legacy modern format (has node_index but no target_type, assumed to be Text)
@@ -471,6 +493,8 @@ impl From<CommentLegacyParagraphSerde> for Comment {
             content: legacy.content,
             updated_at: legacy.updated_at,
             quoted_text: None,
+            tags: Vec::new(),
+            parent_id: None,
         }
     }
 }
@@ -484,6 +508,8 @@ impl From<CommentLegacyCodeBlockSerde> for Comment {
             content: legacy.content,
             updated_at: legacy.updated_at,
             quoted_text: None,
+            tags: Vec::new(),
+            parent_id: None,
         }
     }
 }
@@ -500,6 +526,8 @@ impl From<CommentModernLegacySerde> for Comment {
             content: modern.content,
             updated_at: modern.updated_at,
             quoted_text: None,
+            tags: Vec::new(),
+            parent_id: None,
         }
     }
 }
@@ -516,6 +544,8 @@ impl From<CommentTextSerde> for Comment {
             content: text.content,
             updated_at: text.updated_at,
             quoted_text: text.quoted_text,
+            tags: text.tags,
+            parent_id: text.parent_id,
         }
     }
 }
@@ -532,6 +562,8 @@ impl From<CommentPdfSerde> for Comment {
             content: pdf.content,
             updated_at: pdf.updated_at,
             quoted_text: pdf.quoted_text,
+            tags: pdf.tags,
+            parent_id: pdf.parent_id,
         }
     }
 }
@@ -557,6 +589,8 @@ impl Serialize for Comment {
                     content: self.content.clone(),
                     updated_at: self.updated_at,
                     quoted_text: self.quoted_text.clone(),
+                    tags: self.tags.clone(),
+                    parent_id: self.parent_id.clone(),
                 };
                 serde.serialize(serializer)
             }
@@ -570,6 +604,8 @@ impl Serialize for Comment {
                     content: self.content.clone(),
                     updated_at: self.updated_at,
                     quoted_text: self.quoted_text.clone(),
+                    tags: self.tags.clone(),
+                    parent_id: self.parent_id.clone(),
                 };
                 serde.serialize(serializer)
             }
@@ -606,6 +642,8 @@ impl Comment {
             content,
             updated_at,
             quoted_text: None,
+            tags: Vec::new(),
+            parent_id: None,
         }
     }
 
@@ -623,9 +661,17 @@ impl Comment {
             content,
             updated_at,
             quoted_text,
+            tags: Vec::new(),
+            parent_id: None,
         }
     }
 
+    /// Marks this comment as a reply to `parent_id`, forming a thread.
+    pub fn reply_to(mut self, parent_id: String) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
     /// Returns the node index for Text targets, or None for Pdf targets
     pub fn node_index(&self) -> Option<usize> {
         self.target.node_index()
@@ -662,6 +708,388 @@ impl Comment {
     pub fn matches_location(&self, chapter_href: &str, target: &CommentTarget) -> bool {
         self.chapter_href == chapter_href && self.target == *target
     }
+
+    /// Populates `quoted_text` for a Pdf target by extracting the text under
+    /// its selection rectangles from the document's already-rendered line
+    /// bounds (keyed by page number). No-op for Text targets, for a Pdf
+    /// target with no rectangles, or if none of the referenced pages are in
+    /// `pages`. Rectangles spanning multiple pages are extracted per page
+    /// (in page order) and joined with a blank line between pages.
+    #[cfg(feature = "pdf")]
+    pub fn extract_pdf_quoted_text(&mut self, pages: &HashMap<usize, Vec<crate::pdf::LineBounds>>) {
+        let CommentTarget::Pdf { rects, .. } = &self.target else {
+            return;
+        };
+        if rects.is_empty() {
+            return;
+        }
+
+        let mut rects_by_page: HashMap<usize, Vec<crate::pdf::SelectionRect>> = HashMap::new();
+        for r in rects {
+            rects_by_page
+                .entry(r.page)
+                .or_default()
+                .push(crate::pdf::SelectionRect {
+                    page: r.page,
+                    topleft_x: r.topleft_x,
+                    topleft_y: r.topleft_y,
+                    bottomright_x: r.bottomright_x,
+                    bottomright_y: r.bottomright_y,
+                });
+        }
+
+        let mut page_numbers: Vec<usize> = rects_by_page.keys().copied().collect();
+        page_numbers.sort_unstable();
+
+        let sections: Vec<String> = page_numbers
+            .into_iter()
+            .filter_map(|page| {
+                let line_bounds = pages.get(&page)?;
+                let page_rects = &rects_by_page[&page];
+                let text = crate::pdf::extract_text_for_rects(line_bounds, page_rects);
+                (!text.is_empty()).then_some(text)
+            })
+            .collect();
+
+        if !sections.is_empty() {
+            self.quoted_text = Some(sections.join("\n\n"));
+        }
+    }
+}
+
+/// A human-readable locator for a citation: a 1-indexed page for Pdf
+/// targets, or a 1-indexed paragraph/node number for Text targets.
+fn export_locator(comment: &Comment) -> String {
+    match comment.page() {
+        Some(page) => format!("p. {}", page + 1),
+        None => format!("¶{}", comment.node_index().unwrap_or(0) + 1),
+    }
+}
+
+/// Like [`export_locator`], but splits a code-block comment's line range
+/// into a start/end pair (for RIS's `SP`/`EP` tags) instead of collapsing
+/// it to a single locator string.
+fn export_locator_range(comment: &Comment) -> (String, Option<String>) {
+    match comment.target.line_range() {
+        Some((start, end)) => (format!("l. {start}"), Some(format!("l. {end}"))),
+        None => (export_locator(comment), None),
+    }
+}
+
+/// Book-level metadata for citation exports (`export_ris`, `export_bibtex`,
+/// `export_csl_json`). `BookComments` only knows `compute_book_hash` from
+/// the filename, so this carries a human-readable title/author instead.
+#[derive(Debug, Clone, Default)]
+pub struct BookMetadata {
+    pub title: String,
+    pub author: String,
+    pub identifier: Option<String>,
+}
+
+#[derive(Serialize)]
+struct CslJsonAuthor {
+    literal: String,
+}
+
+#[derive(Serialize)]
+struct CslJsonDate {
+    #[serde(rename = "date-parts")]
+    date_parts: Vec<Vec<i32>>,
+}
+
+#[derive(Serialize)]
+struct CslJsonEntry {
+    id: String,
+    #[serde(rename = "type")]
+    entry_type: String,
+    title: String,
+    author: Vec<CslJsonAuthor>,
+    note: String,
+    locator: String,
+    issued: CslJsonDate,
+}
+
+/// Output format for [`BookComments::export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    Latex,
+}
+
+/// A short heading identifying where a comment anchors: its page for PDF
+/// comments, its line range for code-block comments, or its paragraph
+/// number otherwise.
+fn export_anchor_label(comment: &Comment) -> String {
+    if let Some(page) = comment.page() {
+        format!("Page {}", page + 1)
+    } else if let Some((start, end)) = comment.target.line_range() {
+        format!("Lines {start}-{end}")
+    } else {
+        format!("Paragraph {}", comment.node_index().unwrap_or(0) + 1)
+    }
+}
+
+/// Renders `chapters` (each a chapter href paired with its comments, in
+/// reading order) as a Markdown annotations document.
+fn export_markdown(chapters: &[(&str, Vec<&Comment>)]) -> String {
+    let mut out = String::from("# Annotations\n\n");
+    for (chapter_href, comments) in chapters {
+        out.push_str(&format!("## {chapter_href}\n\n"));
+        for comment in comments {
+            out.push_str(&format!("**{}**\n\n", export_anchor_label(comment)));
+            if let Some(quoted) = &comment.quoted_text {
+                if comment.target.is_code_block() {
+                    out.push_str(&format!("```\n{quoted}\n```\n\n"));
+                } else {
+                    for line in quoted.lines() {
+                        out.push_str(&format!("> {line}\n"));
+                    }
+                    out.push('\n');
+                }
+            }
+            if !comment.content.is_empty() {
+                out.push_str(&comment.content);
+                out.push_str("\n\n");
+            }
+            if !comment.tags.is_empty() {
+                out.push_str(&format!("Tags: {}\n\n", comment.tags.join(", ")));
+            }
+        }
+    }
+    out
+}
+
+/// Renders `chapters` (each a chapter href paired with its comments, in
+/// reading order) as a LaTeX annotations document, suitable for inclusion
+/// via `\input`.
+fn export_latex(chapters: &[(&str, Vec<&Comment>)]) -> String {
+    let mut out = String::new();
+    for (chapter_href, comments) in chapters {
+        out.push_str(&format!("\\section{{{}}}\n\n", escape_latex(chapter_href)));
+        for comment in comments {
+            out.push_str(&format!(
+                "\\textbf{{{}}}\n\n",
+                escape_latex(&export_anchor_label(comment))
+            ));
+            if let Some(quoted) = &comment.quoted_text {
+                if comment.target.is_code_block() {
+                    let first_line = comment.target.line_range().map(|(start, _)| start).unwrap_or(1);
+                    out.push_str(&format!(
+                        "\\begin{{lstlisting}}[numbers=left, firstnumber={first_line}]\n{quoted}\n\\end{{lstlisting}}\n\n"
+                    ));
+                } else {
+                    out.push_str(&format!(
+                        "\\begin{{quote}}\n{}\n\\end{{quote}}\n\n",
+                        escape_latex(quoted)
+                    ));
+                }
+            }
+            if !comment.content.is_empty() {
+                out.push_str(&escape_latex(&comment.content));
+                out.push_str("\n\n");
+            }
+            if !comment.tags.is_empty() {
+                out.push_str(&format!(
+                    "\\textit{{Tags: {}}}\n\n",
+                    escape_latex(&comment.tags.join(", "))
+                ));
+            }
+        }
+    }
+    out
+}
+
+/// Escapes LaTeX's special characters so comment text renders literally.
+fn escape_latex(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\textbackslash{}"),
+            '{' | '}' | '%' | '&' | '$' | '#' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '~' => out.push_str("\\textasciitilde{}"),
+            '^' => out.push_str("\\textasciicircum{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Sanitizes a book title into a BibTeX citation-key fragment (lowercase
+/// alphanumerics only, so the key stays valid regardless of the title).
+fn bibtex_key(book_title: &str) -> String {
+    let key: String = book_title
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if key.is_empty() { "comment".to_string() } else { key }
+}
+
+/// Escapes BibTeX's special characters so the field round-trips through
+/// [`unescape_bibtex`] unchanged.
+fn escape_bibtex(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '{' | '}' | '%' | '&' | '$' | '#' | '_' => {
+                out.push('\\');
+                out.push(c);
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Reverses [`escape_bibtex`]. Exported for round-trip verification by
+/// callers that re-import BibTeX this tool exported.
+#[allow(dead_code)]
+pub fn unescape_bibtex(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                out.push(next);
+                continue;
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Tokenizes a comment's content and quoted text, in order, for indexing.
+fn tokenize_comment(comment: &Comment) -> Vec<String> {
+    let quoted = comment.quoted_text.as_deref().unwrap_or("");
+    format!("{} {quoted}", comment.content)
+        .unicode_words()
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+/// Edit-distance tolerance for a search term, scaled by its length so short
+/// terms don't drown in false positives: 0 typos under 5 characters, 1 for
+/// 5-8, 2 for 9 or more.
+fn edit_distance_tolerance(term: &str) -> usize {
+    match term.chars().count() {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+/// Smallest span (in token positions) covering at least one occurrence from
+/// every non-empty list in `term_positions`. Empty lists (terms that didn't
+/// match this comment) are ignored, so the span only reflects terms that did.
+fn smallest_span(term_positions: &[Vec<usize>]) -> usize {
+    let mut entries: Vec<(usize, usize)> = term_positions
+        .iter()
+        .enumerate()
+        .flat_map(|(list_idx, positions)| positions.iter().map(move |&pos| (pos, list_idx)))
+        .collect();
+    if entries.is_empty() {
+        return 0;
+    }
+    entries.sort_unstable();
+
+    let num_lists = term_positions.iter().filter(|p| !p.is_empty()).count();
+    let mut counts = vec![0usize; term_positions.len()];
+    let mut distinct_covered = 0;
+    let mut left = 0;
+    let mut best = usize::MAX;
+
+    for right in 0..entries.len() {
+        let (_, list_idx) = entries[right];
+        if counts[list_idx] == 0 {
+            distinct_covered += 1;
+        }
+        counts[list_idx] += 1;
+
+        while distinct_covered == num_lists {
+            best = best.min(entries[right].0 - entries[left].0);
+            let (_, left_list_idx) = entries[left];
+            counts[left_list_idx] -= 1;
+            if counts[left_list_idx] == 0 {
+                distinct_covered -= 1;
+            }
+            left += 1;
+        }
+    }
+
+    best
+}
+
+/// Options controlling a [`BookComments::search`] query.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SearchOptions {
+    /// Caps the number of hits returned; `None` returns every match.
+    pub limit: Option<usize>,
+}
+
+/// A single `search` match: the comment itself, a snippet of its content
+/// windowed around the matched terms, and `highlight_ranges` marking each
+/// matched term's byte range within `snippet`.
+#[derive(Debug, Clone)]
+pub struct SearchHit<'a> {
+    pub comment: &'a Comment,
+    pub snippet: String,
+    pub highlight_ranges: Vec<(usize, usize)>,
+}
+
+/// Number of bytes of context (widened to the nearest char boundaries) kept
+/// on either side of the first matched word's start when windowing a
+/// comment's content into a search snippet.
+const SNIPPET_RADIUS_CHARS: usize = 60;
+
+/// Windows `content` around its matched word positions (indices into
+/// `content.unicode_words()`; positions beyond the end of `content` matched
+/// in `quoted_text` instead and are ignored here), truncating to
+/// [`SNIPPET_RADIUS_CHARS`] of context on each side and prefixing/suffixing
+/// with an ellipsis where truncated. `highlight_ranges` gives each matched
+/// word's byte range relative to the returned snippet.
+fn build_snippet(content: &str, matched_word_positions: &[usize]) -> (String, Vec<(usize, usize)>) {
+    let word_ranges: Vec<(usize, usize)> = content
+        .unicode_word_indices()
+        .map(|(start, w)| (start, start + w.len()))
+        .collect();
+
+    let mut matched_ranges: Vec<(usize, usize)> = matched_word_positions
+        .iter()
+        .filter_map(|&pos| word_ranges.get(pos).copied())
+        .collect();
+    matched_ranges.sort_unstable();
+    matched_ranges.dedup();
+
+    let center = matched_ranges.first().map_or(0, |&(start, _)| start);
+    let raw_start = center.saturating_sub(SNIPPET_RADIUS_CHARS);
+    let raw_end = (center + SNIPPET_RADIUS_CHARS).min(content.len());
+    let window_start = (0..=raw_start).rev().find(|&i| content.is_char_boundary(i)).unwrap_or(0);
+    let window_end = (raw_end..=content.len())
+        .find(|&i| content.is_char_boundary(i))
+        .unwrap_or(content.len());
+
+    let truncated_start = window_start > 0;
+    let mut snippet = String::new();
+    if truncated_start {
+        snippet.push('\u{2026}');
+    }
+    snippet.push_str(&content[window_start..window_end]);
+    if window_end < content.len() {
+        snippet.push('\u{2026}');
+    }
+
+    let prefix_len = if truncated_start { '\u{2026}'.len_utf8() } else { 0 };
+    let highlight_ranges = matched_ranges
+        .into_iter()
+        .filter(|&(start, end)| start >= window_start && end <= window_end)
+        .map(|(start, end)| (start - window_start + prefix_len, end - window_start + prefix_len))
+        .collect();
+
+    (snippet, highlight_ranges)
 }
 
 fn generate_comment_id() -> String {
@@ -674,10 +1102,22 @@ fn generate_comment_id() -> String {
 
 pub struct BookComments {
     pub file_path: PathBuf,
-    comments: Vec<Comment>,
-    // chapter_href -> node_index -> comment indices
-    comments_by_location: HashMap<String, HashMap<usize, Vec<usize>>>,
-    comments_by_id: HashMap<String, usize>,
+    comments: SlotMap<CommentKey, Comment>,
+    // Display/save order, maintained by `sort_comments`/`add_to_indices`.
+    ordered_keys: Vec<CommentKey>,
+    // chapter_href -> node_index -> comment keys
+    comments_by_location: HashMap<String, HashMap<usize, Vec<CommentKey>>>,
+    comments_by_id: HashMap<String, CommentKey>,
+    // token -> comment keys whose content/quoted_text contain that token
+    search_index: HashMap<String, Vec<CommentKey>>,
+    // a comment's content+quoted_text, tokenized in order, for proximity ranking
+    comment_tokens: SecondaryMap<CommentKey, Vec<String>>,
+    // tag -> comment keys carrying that tag
+    comments_by_tag: HashMap<String, Vec<CommentKey>>,
+    // parent comment id -> keys of comments replying to it
+    children_by_parent: HashMap<String, Vec<CommentKey>>,
+    // Book title/author/identifier for citation exports; see `with_metadata`.
+    book_metadata: Option<BookMetadata>,
 }
 
 impl BookComments {
@@ -701,12 +1141,26 @@ impl BookComments {
     pub fn new_empty() -> Self {
         Self {
             file_path: PathBuf::new(),
-            comments: Vec::new(),
+            comments: SlotMap::with_key(),
+            ordered_keys: Vec::new(),
             comments_by_location: HashMap::new(),
             comments_by_id: HashMap::new(),
+            search_index: HashMap::new(),
+            comment_tokens: SecondaryMap::new(),
+            comments_by_tag: HashMap::new(),
+            children_by_parent: HashMap::new(),
+            book_metadata: None,
         }
     }
 
+    /// Attaches book title/author/identifier so citation exports
+    /// (`export_ris`, `export_bibtex`, `export_csl_json`) can cite the
+    /// book itself rather than just locating the comment within it.
+    pub fn with_metadata(mut self, metadata: BookMetadata) -> Self {
+        self.book_metadata = Some(metadata);
+        self
+    }
+
     fn new_with_path(file_path: PathBuf) -> Result<Self> {
         let comments = if file_path.exists() {
             Self::load_from_file(&file_path)?
@@ -716,14 +1170,19 @@ impl BookComments {
 
         let mut book_comments = Self {
             file_path,
-            comments: Vec::new(),
+            comments: SlotMap::with_key(),
+            ordered_keys: Vec::new(),
             comments_by_location: HashMap::new(),
             comments_by_id: HashMap::new(),
+            search_index: HashMap::new(),
+            comment_tokens: SecondaryMap::new(),
+            comments_by_tag: HashMap::new(),
+            children_by_parent: HashMap::new(),
+            book_metadata: None,
         };
 
         for comment in comments {
-            book_comments.add_to_indices(&comment);
-            book_comments.comments.push(comment);
+            book_comments.add_to_indices(comment);
         }
 
         Ok(book_comments)
@@ -735,8 +1194,15 @@ impl BookComments {
             comment.id = generate_comment_id();
         }
 
-        self.add_to_indices(&comment);
-        self.comments.push(comment);
+        // A reply whose parent doesn't exist (e.g. already deleted) becomes
+        // a top-level comment rather than a dangling reference.
+        if let Some(parent_id) = &comment.parent_id {
+            if !self.comments_by_id.contains_key(parent_id) {
+                comment.parent_id = None;
+            }
+        }
+
+        self.add_to_indices(comment);
 
         self.sort_comments();
         self.save_to_disk()
@@ -748,47 +1214,47 @@ impl BookComments {
         target: &CommentTarget,
         new_content: String,
     ) -> Result<()> {
-        let idx = self
-            .find_comment_index(chapter_href, target)
+        let key = self
+            .find_comment_key(chapter_href, target)
             .context("Comment not found")?;
 
-        self.comments[idx].content = new_content;
-        self.comments[idx].updated_at = Utc::now();
+        let comment = &mut self.comments[key];
+        comment.content = new_content;
+        comment.updated_at = Utc::now();
+        self.rebuild_indices();
 
         self.save_to_disk()
     }
 
     pub fn update_comment_by_id(&mut self, comment_id: &str, new_content: String) -> Result<()> {
-        let idx = self
-            .find_comment_index_by_id(comment_id)
+        let key = self
+            .find_comment_key_by_id(comment_id)
             .context("Comment not found")?;
 
-        self.comments[idx].content = new_content;
-        self.comments[idx].updated_at = Utc::now();
+        let comment = &mut self.comments[key];
+        comment.content = new_content;
+        comment.updated_at = Utc::now();
+        self.rebuild_indices();
 
         self.save_to_disk()
     }
 
     pub fn delete_comment(&mut self, chapter_href: &str, target: &CommentTarget) -> Result<()> {
-        let idx = self
-            .find_comment_index(chapter_href, target)
+        let key = self
+            .find_comment_key(chapter_href, target)
             .context("Comment not found")?;
 
-        let _comment = self.comments.remove(idx);
-
-        self.rebuild_indices();
+        self.remove_from_indices(key);
 
         self.save_to_disk()
     }
 
     pub fn delete_comment_by_id(&mut self, comment_id: &str) -> Result<()> {
-        let idx = self
-            .find_comment_index_by_id(comment_id)
+        let key = self
+            .find_comment_key_by_id(comment_id)
             .context("Comment not found")?;
 
-        let _comment = self.comments.remove(idx);
-
-        self.rebuild_indices();
+        self.remove_from_indices(key);
 
         self.save_to_disk()
     }
@@ -798,11 +1264,10 @@ impl BookComments {
         self.comments_by_location
             .get(chapter_href)
             .and_then(|chapter_map| chapter_map.get(&node_index))
-            .map(|indices| {
-                indices
-                    .iter()
-                    .filter_map(|&i| {
-                        let c = self.comments.get(i)?;
+            .map(|keys| {
+                keys.iter()
+                    .filter_map(|&key| {
+                        let c = self.comments.get(key)?;
                         if c.is_text() { Some(c) } else { None }
                     })
                     .collect()
@@ -815,11 +1280,10 @@ impl BookComments {
         self.comments_by_location
             .get(doc_id)
             .and_then(|doc_map| doc_map.get(&page))
-            .map(|indices| {
-                indices
-                    .iter()
-                    .filter_map(|&i| {
-                        let c = self.comments.get(i)?;
+            .map(|keys| {
+                keys.iter()
+                    .filter_map(|&key| {
+                        let c = self.comments.get(key)?;
                         if c.is_pdf() { Some(c) } else { None }
                     })
                     .collect()
@@ -834,9 +1298,9 @@ impl BookComments {
             .map(|doc_map| {
                 doc_map
                     .values()
-                    .flat_map(|indices| {
-                        indices.iter().filter_map(|&i| {
-                            let c = self.comments.get(i)?;
+                    .flat_map(|keys| {
+                        keys.iter().filter_map(|&key| {
+                            let c = self.comments.get(key)?;
                             if c.is_pdf() { Some(c) } else { None }
                         })
                     })
@@ -848,7 +1312,7 @@ impl BookComments {
     pub fn get_comment_by_id(&self, comment_id: &str) -> Option<&Comment> {
         self.comments_by_id
             .get(comment_id)
-            .and_then(|&idx| self.comments.get(idx))
+            .and_then(|&key| self.comments.get(key))
     }
 
     pub fn get_chapter_comments(&self, chapter_href: &str) -> Vec<&Comment> {
@@ -857,14 +1321,177 @@ impl BookComments {
             .map(|chapter_map| {
                 chapter_map
                     .values()
-                    .flat_map(|indices| indices.iter().map(|&i| &self.comments[i]))
+                    .flat_map(|keys| keys.iter().map(|&key| &self.comments[key]))
                     .collect()
             })
             .unwrap_or_default()
     }
 
-    pub fn get_all_comments(&self) -> &[Comment] {
-        &self.comments
+    /// All comments in display/save order.
+    pub fn get_all_comments(&self) -> Vec<&Comment> {
+        self.ordered_keys
+            .iter()
+            .filter_map(|&key| self.comments.get(key))
+            .collect()
+    }
+
+    /// Comments worth citing (has a quote and/or a note), grouped by chapter
+    /// then ordered by `secondary_sort_key`, for `export_ris`/`export_bibtex`.
+    fn comments_for_export(&self) -> Vec<&Comment> {
+        let mut refs: Vec<&Comment> = self
+            .comments
+            .values()
+            .filter(|c| c.quoted_text.is_some() || !c.content.is_empty())
+            .collect();
+        refs.sort_by(|a, b| {
+            a.chapter_href.cmp(&b.chapter_href).then(
+                a.target
+                    .secondary_sort_key()
+                    .cmp(&b.target.secondary_sort_key()),
+            )
+        });
+        refs
+    }
+
+    /// Renders every comment as a readable annotations document, grouped
+    /// under a heading per chapter in the existing reading order (see
+    /// `sort_comments`): paragraph/PDF comments render their quoted text as
+    /// a blockquote, code-block comments render it in a fenced code
+    /// block/`lstlisting` environment annotated with their line range, and
+    /// every comment is labeled with its page (PDF) or paragraph/line
+    /// anchor.
+    pub fn export(&self, format: ExportFormat) -> Result<String> {
+        let all = self.get_all_comments();
+        let mut chapters: Vec<(&str, Vec<&Comment>)> = Vec::new();
+        for comment in all {
+            match chapters.last_mut() {
+                Some((href, group)) if *href == comment.chapter_href.as_str() => {
+                    group.push(comment);
+                }
+                _ => chapters.push((comment.chapter_href.as_str(), vec![comment])),
+            }
+        }
+
+        Ok(match format {
+            ExportFormat::Markdown => export_markdown(&chapters),
+            ExportFormat::Latex => export_latex(&chapters),
+        })
+    }
+
+    /// Renders every citable comment as an RIS (Research Information
+    /// Systems) entry: `AU` the book's author (when [`BookMetadata`] was
+    /// attached via `with_metadata`), `AB` the quoted text, `N1` the note,
+    /// `SP`/`EP` a page/paragraph/line-range locator derived from the
+    /// target, and `DA` the last update date. Comments with neither a quote
+    /// nor a note are skipped.
+    pub fn export_ris(&self) -> String {
+        let mut output = String::new();
+        for comment in self.comments_for_export() {
+            output.push_str("TY  - GEN\n");
+            output.push_str(&format!("ID  - {}\n", comment.id));
+            output.push_str(&format!("TI  - {}\n", comment.chapter_href));
+            if let Some(author) = self.book_metadata.as_ref().map(|m| &m.author) {
+                output.push_str(&format!("AU  - {author}\n"));
+            }
+            if let Some(quote) = &comment.quoted_text {
+                output.push_str(&format!("AB  - {quote}\n"));
+            }
+            if !comment.content.is_empty() {
+                output.push_str(&format!("N1  - {}\n", comment.content));
+            }
+            let (start, end) = export_locator_range(comment);
+            output.push_str(&format!("SP  - {start}\n"));
+            if let Some(end) = end {
+                output.push_str(&format!("EP  - {end}\n"));
+            }
+            output.push_str(&format!("DA  - {}\n", comment.updated_at.format("%Y/%m/%d")));
+            output.push_str("ER  - \n\n");
+        }
+        output
+    }
+
+    /// Renders every citable comment as an `@incollection` BibTeX entry —
+    /// `title` the comment's chapter, `booktitle`/`author` the book's
+    /// metadata (attached via `with_metadata`; blank if none was given),
+    /// `annote` the quoted text, `note` the comment's own note, `pages` a
+    /// page/paragraph/line-range locator, and `year` taken from the last
+    /// update. Field values are escaped so the output round-trips back to
+    /// the original text (see [`escape_bibtex`]/[`unescape_bibtex`]).
+    pub fn export_bibtex(&self) -> String {
+        let metadata = self.book_metadata.clone().unwrap_or_default();
+        let mut output = String::new();
+        for (i, comment) in self.comments_for_export().into_iter().enumerate() {
+            output.push_str(&format!(
+                "@incollection{{{}-{},\n",
+                bibtex_key(&metadata.title),
+                i + 1
+            ));
+            output.push_str(&format!(
+                "  title = {{{}}},\n",
+                escape_bibtex(&comment.chapter_href)
+            ));
+            output.push_str(&format!(
+                "  booktitle = {{{}}},\n",
+                escape_bibtex(&metadata.title)
+            ));
+            output.push_str(&format!("  author = {{{}}},\n", escape_bibtex(&metadata.author)));
+            output.push_str(&format!("  year = {{{}}},\n", comment.updated_at.format("%Y")));
+            if let Some(identifier) = &metadata.identifier {
+                output.push_str(&format!("  identifier = {{{}}},\n", escape_bibtex(identifier)));
+            }
+            if let Some(quote) = &comment.quoted_text {
+                output.push_str(&format!("  annote = {{{}}},\n", escape_bibtex(quote)));
+            }
+            if !comment.content.is_empty() {
+                output.push_str(&format!("  note = {{{}}},\n", escape_bibtex(&comment.content)));
+            }
+            let pages = match export_locator_range(comment) {
+                (start, Some(end)) => format!("{start}--{end}"),
+                (start, None) => start,
+            };
+            output.push_str(&format!("  pages = {{{}}},\n", escape_bibtex(&pages)));
+            output.push_str("}\n\n");
+        }
+        output
+    }
+
+    /// Renders every citable comment as a CSL-JSON reference: `note` holds
+    /// the comment (quoted text followed by the comment's own note, if
+    /// both are present), `locator` a page/paragraph/line-range locator,
+    /// and `issued` the last update's year. Uses [`BookMetadata`] (attached
+    /// via `with_metadata`) for `title`/`author`, blank if none was given.
+    pub fn export_csl_json(&self) -> Result<String> {
+        let metadata = self.book_metadata.clone().unwrap_or_default();
+        let entries: Vec<CslJsonEntry> = self
+            .comments_for_export()
+            .into_iter()
+            .map(|comment| {
+                let mut note_parts = Vec::new();
+                if let Some(quote) = &comment.quoted_text {
+                    note_parts.push(quote.clone());
+                }
+                if !comment.content.is_empty() {
+                    note_parts.push(comment.content.clone());
+                }
+                CslJsonEntry {
+                    id: comment.id.clone(),
+                    entry_type: "chapter".to_string(),
+                    title: metadata.title.clone(),
+                    author: vec![CslJsonAuthor {
+                        literal: metadata.author.clone(),
+                    }],
+                    note: note_parts.join("\n\n"),
+                    locator: match export_locator_range(comment) {
+                        (start, Some(end)) => format!("{start}-{end}"),
+                        (start, None) => start,
+                    },
+                    issued: CslJsonDate {
+                        date_parts: vec![vec![comment.updated_at.format("%Y").to_string().parse().unwrap_or(0)]],
+                    },
+                }
+            })
+            .collect();
+        serde_json::to_string_pretty(&entries).context("Failed to serialize CSL-JSON")
     }
 
     fn compute_book_hash(book_path: &Path) -> String {
@@ -914,50 +1541,354 @@ impl BookComments {
             return Ok(());
         }
 
-        let yaml = serde_yaml::to_string(&self.comments).context("Failed to serialize comments")?;
+        let yaml =
+            serde_yaml::to_string(&self.get_all_comments()).context("Failed to serialize comments")?;
 
         fs::write(&self.file_path, yaml).context("Failed to write comments file")?;
 
         Ok(())
     }
 
-    fn find_comment_index(&self, chapter_href: &str, target: &CommentTarget) -> Option<usize> {
+    fn find_comment_key(&self, chapter_href: &str, target: &CommentTarget) -> Option<CommentKey> {
         self.comments
             .iter()
-            .position(|c| c.matches_location(chapter_href, target))
+            .find(|(_, c)| c.matches_location(chapter_href, target))
+            .map(|(key, _)| key)
     }
 
-    fn find_comment_index_by_id(&self, comment_id: &str) -> Option<usize> {
+    fn find_comment_key_by_id(&self, comment_id: &str) -> Option<CommentKey> {
         self.comments_by_id.get(comment_id).copied()
     }
 
-    fn add_to_indices(&mut self, comment: &Comment) {
-        let idx = self.comments.len();
+    /// Inserts `comment` into the slotmap and every derived index, appending
+    /// its key to `ordered_keys`. Returns the new key.
+    fn add_to_indices(&mut self, comment: Comment) -> CommentKey {
+        let chapter_href = comment.chapter_href.clone();
+        let index_key = comment.index_key();
+        let id = comment.id.clone();
+        let tokens = tokenize_comment(&comment);
+        let tags = comment.tags.clone();
+        let parent_id = comment.parent_id.clone();
+
+        let key = self.comments.insert(comment);
+        self.ordered_keys.push(key);
+
         self.comments_by_location
-            .entry(comment.chapter_href.clone())
+            .entry(chapter_href)
             .or_default()
-            .entry(comment.index_key())
+            .entry(index_key)
             .or_default()
-            .push(idx);
-        self.comments_by_id.insert(comment.id.clone(), idx);
+            .push(key);
+        self.comments_by_id.insert(id, key);
+
+        for token in &tokens {
+            self.search_index.entry(token.clone()).or_default().push(key);
+        }
+        self.comment_tokens.insert(key, tokens);
+
+        for tag in &tags {
+            self.comments_by_tag.entry(tag.clone()).or_default().push(key);
+        }
+
+        if let Some(parent_id) = &parent_id {
+            self.children_by_parent.entry(parent_id.clone()).or_default().push(key);
+        }
+
+        key
+    }
+
+    /// Removes `key` from the slotmap and surgically unwinds it from every
+    /// derived index, without rebuilding any of them from scratch.
+    fn remove_from_indices(&mut self, key: CommentKey) -> Option<Comment> {
+        let comment = self.comments.remove(key)?;
+
+        self.ordered_keys.retain(|&k| k != key);
+
+        if let Some(chapter_map) = self.comments_by_location.get_mut(&comment.chapter_href) {
+            if let Some(keys) = chapter_map.get_mut(&comment.index_key()) {
+                keys.retain(|&k| k != key);
+                if keys.is_empty() {
+                    chapter_map.remove(&comment.index_key());
+                }
+            }
+            if chapter_map.is_empty() {
+                self.comments_by_location.remove(&comment.chapter_href);
+            }
+        }
+
+        self.comments_by_id.remove(&comment.id);
+
+        if let Some(tokens) = self.comment_tokens.remove(key) {
+            for token in &tokens {
+                if let Some(keys) = self.search_index.get_mut(token) {
+                    keys.retain(|&k| k != key);
+                    if keys.is_empty() {
+                        self.search_index.remove(token);
+                    }
+                }
+            }
+        }
+
+        for tag in &comment.tags {
+            if let Some(keys) = self.comments_by_tag.get_mut(tag) {
+                keys.retain(|&k| k != key);
+                if keys.is_empty() {
+                    self.comments_by_tag.remove(tag);
+                }
+            }
+        }
+
+        if let Some(parent_id) = &comment.parent_id {
+            if let Some(keys) = self.children_by_parent.get_mut(parent_id) {
+                keys.retain(|&k| k != key);
+                if keys.is_empty() {
+                    self.children_by_parent.remove(parent_id);
+                }
+            }
+        }
+
+        Some(comment)
     }
 
+    /// Fully reconstructs every derived index from `self.comments` in
+    /// `ordered_keys` order. Used after operations (like `sort_comments`)
+    /// that touch order or fields that many indices depend on at once.
     fn rebuild_indices(&mut self) {
         self.comments_by_location.clear();
         self.comments_by_id.clear();
-        for (idx, comment) in self.comments.iter().enumerate() {
+        self.search_index.clear();
+        self.comment_tokens.clear();
+        self.comments_by_tag.clear();
+        self.children_by_parent.clear();
+        for &key in &self.ordered_keys {
+            let Some(comment) = self.comments.get(key) else {
+                continue;
+            };
             self.comments_by_location
                 .entry(comment.chapter_href.clone())
                 .or_default()
                 .entry(comment.index_key())
                 .or_default()
-                .push(idx);
-            self.comments_by_id.insert(comment.id.clone(), idx);
+                .push(key);
+            self.comments_by_id.insert(comment.id.clone(), key);
+
+            let tokens = tokenize_comment(comment);
+            for token in &tokens {
+                self.search_index.entry(token.clone()).or_default().push(key);
+            }
+            self.comment_tokens.insert(key, tokens);
+
+            for tag in &comment.tags {
+                self.comments_by_tag.entry(tag.clone()).or_default().push(key);
+            }
+
+            if let Some(parent_id) = &comment.parent_id {
+                self.children_by_parent.entry(parent_id.clone()).or_default().push(key);
+            }
+        }
+    }
+
+    /// Ancestors of `id`, closest first, walking `parent_id` up to the
+    /// thread root. Stops (without error) if a parent id doesn't resolve to
+    /// a known comment, and guards against cycles in a malformed chain.
+    pub fn ancestors(&self, id: &str) -> Vec<&Comment> {
+        let mut result = Vec::new();
+        let mut visited: HashSet<String> = HashSet::new();
+        visited.insert(id.to_string());
+
+        let mut current = self.comments_by_id.get(id).copied();
+        while let Some(key) = current {
+            let Some(parent_id) = &self.comments[key].parent_id else {
+                break;
+            };
+            if !visited.insert(parent_id.clone()) {
+                break;
+            }
+            let Some(&parent_key) = self.comments_by_id.get(parent_id) else {
+                break;
+            };
+            result.push(&self.comments[parent_key]);
+            current = Some(parent_key);
         }
+        result
     }
 
+    /// The topmost comment of `id`'s thread, or `id`'s own comment if it has
+    /// no parent. `None` if `id` isn't a known comment.
+    pub fn thread_root(&self, id: &str) -> Option<&Comment> {
+        self.ancestors(id)
+            .into_iter()
+            .last()
+            .or_else(|| self.get_comment_by_id(id))
+    }
+
+    /// Direct replies to `id`, in thread order.
+    pub fn replies(&self, id: &str) -> Vec<&Comment> {
+        self.children_by_parent
+            .get(id)
+            .map(|keys| keys.iter().map(|&key| &self.comments[key]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every tag in use, with how many comments carry it, most-used first
+    /// (ties broken alphabetically for stable ordering).
+    pub fn tags(&self) -> Vec<(String, usize)> {
+        let mut tags: Vec<(String, usize)> = self
+            .comments_by_tag
+            .iter()
+            .map(|(tag, keys)| (tag.clone(), keys.len()))
+            .collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        tags
+    }
+
+    /// All comments (across every chapter/page) carrying `tag`.
+    pub fn comments_with_tag(&self, tag: &str) -> Vec<&Comment> {
+        self.comments_by_tag
+            .get(tag)
+            .map(|keys| keys.iter().map(|&key| &self.comments[key]).collect())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the tag set of the comment identified by `id` and persists
+    /// the change, rebuilding `comments_by_tag` so stale associations from
+    /// its previous tags are dropped.
+    pub fn set_tags_by_id(&mut self, id: &str, tags: Vec<String>) -> Result<()> {
+        let key = self.find_comment_key_by_id(id).context("Comment not found")?;
+
+        self.comments[key].tags = tags;
+        self.rebuild_indices();
+        self.save_to_disk()
+    }
+
+    /// Typo-tolerant full-text search over every comment's content and
+    /// quoted text. The query is split into terms; every term but the last
+    /// must match an indexed token within an edit-distance tolerance that
+    /// grows with the term's length (0 for <5 chars, 1 for 5-8, 2 for 9+),
+    /// while the last term matches as a prefix so results update as the
+    /// user keeps typing. A comment only needs to match one term to be
+    /// included, but hits are ranked by the number of distinct terms
+    /// matched (descending), then by the smallest span covering one
+    /// occurrence of each matched term (ascending), then by recency
+    /// (descending). `opts.limit`, if set, caps the number of hits returned.
+    pub fn search(&self, query: &str, opts: SearchOptions) -> Vec<SearchHit<'_>> {
+        let terms: Vec<String> = query.unicode_words().map(|w| w.to_lowercase()).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let last_term_idx = terms.len() - 1;
+
+        // For each term, the comment keys it matched and the token
+        // positions (within that comment) responsible for the match.
+        let mut matches_per_term: Vec<HashMap<CommentKey, Vec<usize>>> =
+            vec![HashMap::new(); terms.len()];
+        for (token, comment_keys) in &self.search_index {
+            for (term_idx, term) in terms.iter().enumerate() {
+                let matched = if term_idx == last_term_idx {
+                    token.starts_with(term.as_str())
+                } else {
+                    edit_distance(term, token) <= edit_distance_tolerance(term)
+                };
+                if !matched {
+                    continue;
+                }
+                for &comment_key in comment_keys {
+                    let positions: Vec<usize> = self.comment_tokens[comment_key]
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, t)| *t == token)
+                        .map(|(pos, _)| pos)
+                        .collect();
+                    matches_per_term[term_idx]
+                        .entry(comment_key)
+                        .or_default()
+                        .extend(positions);
+                }
+            }
+        }
+
+        let mut matched_keys: Vec<CommentKey> = Vec::new();
+        for term_matches in &matches_per_term {
+            for &key in term_matches.keys() {
+                if !matched_keys.contains(&key) {
+                    matched_keys.push(key);
+                }
+            }
+        }
+
+        let mut ranked: Vec<(CommentKey, usize, usize, DateTime<Utc>, Vec<usize>)> = matched_keys
+            .into_iter()
+            .map(|key| {
+                let term_positions: Vec<Vec<usize>> = matches_per_term
+                    .iter()
+                    .filter_map(|m| m.get(&key).cloned())
+                    .collect();
+                let distinct_terms_matched = term_positions.len();
+                let span = smallest_span(&term_positions);
+                let matched_positions: Vec<usize> = term_positions.into_iter().flatten().collect();
+                (
+                    key,
+                    distinct_terms_matched,
+                    span,
+                    self.comments[key].updated_at,
+                    matched_positions,
+                )
+            })
+            .collect();
+
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.2.cmp(&b.2)).then(b.3.cmp(&a.3)));
+
+        if let Some(limit) = opts.limit {
+            ranked.truncate(limit);
+        }
+
+        ranked
+            .into_iter()
+            .map(|(key, _, _, _, positions)| {
+                let comment = &self.comments[key];
+                let (snippet, highlight_ranges) = build_snippet(&comment.content, &positions);
+                SearchHit {
+                    comment,
+                    snippet,
+                    highlight_ranges,
+                }
+            })
+            .collect()
+    }
+
+    /// Orders comments depth-first by thread: root comments are ordered by
+    /// location/recency as before, but a reply is always placed directly
+    /// beneath its parent (and ahead of its parent's later siblings),
+    /// regardless of `updated_at`.
     fn sort_comments(&mut self) {
-        self.comments.sort_by(|a, b| {
+        let existing_ids: HashSet<&str> = self.comments.values().map(|c| c.id.as_str()).collect();
+
+        let mut children_by_parent: HashMap<String, Vec<CommentKey>> = HashMap::new();
+        for (key, c) in self.comments.iter() {
+            if let Some(parent_id) = &c.parent_id {
+                if existing_ids.contains(parent_id.as_str()) {
+                    children_by_parent.entry(parent_id.clone()).or_default().push(key);
+                }
+            }
+        }
+        for keys in children_by_parent.values_mut() {
+            keys.sort_by(|&a, &b| self.comments[a].updated_at.cmp(&self.comments[b].updated_at));
+        }
+
+        // A "root" is a comment with no parent, or whose parent_id doesn't
+        // resolve to a comment we still have (an orphaned reply).
+        let mut root_keys: Vec<CommentKey> = self
+            .comments
+            .iter()
+            .filter(|(_, c)| match &c.parent_id {
+                None => true,
+                Some(parent_id) => !existing_ids.contains(parent_id.as_str()),
+            })
+            .map(|(key, _)| key)
+            .collect();
+        root_keys.sort_by(|&a, &b| {
+            let a = &self.comments[a];
+            let b = &self.comments[b];
             a.chapter_href
                 .cmp(&b.chapter_href)
                 .then(a.index_key().cmp(&b.index_key()))
@@ -970,6 +1901,32 @@ impl BookComments {
                 .then(a.updated_at.cmp(&b.updated_at))
         });
 
+        // Depth-first walk. `visited` guards against parent_id cycles, which
+        // shouldn't occur but must not hang or silently drop comments.
+        let mut visited: HashSet<CommentKey> = HashSet::new();
+        let mut ordered_keys: Vec<CommentKey> = Vec::with_capacity(self.comments.len());
+        let mut stack: Vec<CommentKey> = root_keys.into_iter().rev().collect();
+        while let Some(key) = stack.pop() {
+            if !visited.insert(key) {
+                continue;
+            }
+            ordered_keys.push(key);
+            if let Some(child_keys) = children_by_parent.get(&self.comments[key].id) {
+                for &child in child_keys.iter().rev() {
+                    if !visited.contains(&child) {
+                        stack.push(child);
+                    }
+                }
+            }
+        }
+        for (key, _) in self.comments.iter() {
+            if !visited.contains(&key) {
+                ordered_keys.push(key);
+            }
+        }
+
+        self.ordered_keys = ordered_keys;
+
         self.rebuild_indices();
     }
 }
@@ -1157,4 +2114,490 @@ mod tests {
         assert_eq!(comments[1].content, "Second note");
         assert_eq!(comments[2].content, "Third note");
     }
+
+    fn create_pdf_comment(chapter: &str, page: usize, content: &str, quoted: &str) -> Comment {
+        Comment::with_quoted_text(
+            chapter.to_string(),
+            CommentTarget::pdf(page, vec![]),
+            content.to_string(),
+            Utc::now(),
+            Some(quoted.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_search_matches_content_across_text_and_pdf_comments() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_paragraph_comment(
+                "chapter1.xhtml",
+                0,
+                "Remember to revisit this argument about entropy",
+            ))
+            .unwrap();
+        book_comments
+            .add_comment(create_pdf_comment(
+                "doc.pdf",
+                3,
+                "Key claim here",
+                "entropy always increases in a closed system",
+            ))
+            .unwrap();
+        book_comments
+            .add_comment(create_paragraph_comment("chapter2.xhtml", 1, "Unrelated note"))
+            .unwrap();
+
+        let results = book_comments.search("entropy", SearchOptions::default());
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|hit| hit.comment.content.contains("entropy")
+            || hit.comment.quoted_text.as_deref().unwrap_or("").contains("entropy")));
+    }
+
+    #[test]
+    fn test_search_tolerates_typos_except_in_short_terms() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_paragraph_comment(
+                "chapter1.xhtml",
+                0,
+                "A note about algorithms",
+            ))
+            .unwrap();
+
+        // "algorithn" is a non-final term, so it's matched with typo
+        // tolerance (edit distance 2 allowed for a 9-character term); "note"
+        // is the final term, matched as a prefix.
+        let results = book_comments.search("algorithn note", SearchOptions::default());
+        assert_eq!(results.len(), 1);
+
+        // Neither term matches: "algx" is short enough to get zero typo
+        // tolerance and no indexed token equals it, and "zzzznomatch" has no
+        // prefix match either.
+        let results = book_comments.search("algx zzzznomatch", SearchOptions::default());
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_search_ranks_more_matched_terms_and_proximity_first() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let far_both_terms = create_paragraph_comment(
+            "chapter1.xhtml",
+            0,
+            "alpha filler filler filler filler filler filler beta",
+        );
+        let close_both_terms =
+            create_paragraph_comment("chapter1.xhtml", 1, "some alpha beta nearby");
+        let only_one_term = create_paragraph_comment("chapter1.xhtml", 2, "alpha only here");
+
+        book_comments.add_comment(far_both_terms).unwrap();
+        book_comments.add_comment(close_both_terms.clone()).unwrap();
+        book_comments.add_comment(only_one_term).unwrap();
+
+        let results = book_comments.search("alpha beta", SearchOptions::default());
+        assert_eq!(results.len(), 3);
+        // Both terms matched and close together ranks above both-matched-far,
+        // which in turn ranks above only one term matched.
+        assert_eq!(results[0].comment.content, close_both_terms.content);
+        assert_eq!(results[2].comment.content, "alpha only here");
+    }
+
+    #[test]
+    fn test_search_snippet_highlights_matched_word_and_respects_limit() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_paragraph_comment(
+                "chapter1.xhtml",
+                0,
+                "A long note about the entropy of closed systems",
+            ))
+            .unwrap();
+        book_comments
+            .add_comment(create_paragraph_comment("chapter1.xhtml", 1, "entropy again"))
+            .unwrap();
+
+        let results = book_comments.search("entropy", SearchOptions::default());
+        assert_eq!(results.len(), 2);
+        let hit = &results[0];
+        let (start, end) = hit.highlight_ranges[0];
+        assert_eq!(&hit.snippet[start..end], "entropy");
+
+        let limited = book_comments.search("entropy", SearchOptions { limit: Some(1) });
+        assert_eq!(limited.len(), 1);
+    }
+
+    #[test]
+    fn test_export_ris_includes_quote_note_and_locator() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_paragraph_comment("chapter1.xhtml", 2, "My note"))
+            .unwrap();
+        let ris = book_comments.export_ris();
+
+        assert!(ris.contains("TY  - GEN"));
+        assert!(ris.contains("TI  - chapter1.xhtml"));
+        assert!(ris.contains("N1  - My note"));
+        assert!(ris.contains("SP  - ¶3"));
+        assert!(ris.contains("ER  - "));
+    }
+
+    #[test]
+    fn test_export_ris_uses_page_locator_for_pdf_comments() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_pdf_comment("doc.pdf", 4, "note", "quoted passage"))
+            .unwrap();
+        let ris = book_comments.export_ris();
+
+        assert!(ris.contains("AB  - quoted passage"));
+        assert!(ris.contains("SP  - p. 5"));
+    }
+
+    #[test]
+    fn test_export_skips_comments_with_no_quote_or_content() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_paragraph_comment("chapter1.xhtml", 0, ""))
+            .unwrap();
+
+        assert!(book_comments.export_ris().is_empty());
+        assert!(book_comments.export_bibtex().is_empty());
+    }
+
+    #[test]
+    fn test_export_markdown_renders_quote_note_and_chapter_heading() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let mut comment = create_paragraph_comment("chapter1.xhtml", 2, "My note");
+        comment.quoted_text = Some("the quoted passage".to_string());
+        book_comments.add_comment(comment).unwrap();
+
+        let markdown = book_comments.export(ExportFormat::Markdown).unwrap();
+
+        assert!(markdown.contains("## chapter1.xhtml"));
+        assert!(markdown.contains("**Paragraph 3**"));
+        assert!(markdown.contains("> the quoted passage"));
+        assert!(markdown.contains("My note"));
+    }
+
+    #[test]
+    fn test_export_markdown_renders_code_block_with_line_range() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let mut comment = create_code_comment("chapter1.xhtml", 1, (4, 6), "Watch this loop");
+        comment.quoted_text = Some("for i in 0..n {}".to_string());
+        book_comments.add_comment(comment).unwrap();
+
+        let markdown = book_comments.export(ExportFormat::Markdown).unwrap();
+
+        assert!(markdown.contains("**Lines 4-6**"));
+        assert!(markdown.contains("```\nfor i in 0..n {}\n```"));
+    }
+
+    #[test]
+    fn test_export_latex_uses_lstlisting_for_code_and_escapes_special_chars() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let mut comment = create_code_comment("chapter1.xhtml", 1, (4, 6), "50% faster & cheaper");
+        comment.quoted_text = Some("let x = 1;".to_string());
+        book_comments.add_comment(comment).unwrap();
+
+        let latex = book_comments.export(ExportFormat::Latex).unwrap();
+
+        assert!(latex.contains("\\section{chapter1.xhtml}"));
+        assert!(latex.contains("\\begin{lstlisting}[numbers=left, firstnumber=4]"));
+        assert!(latex.contains("50\\% faster \\& cheaper"));
+    }
+
+    #[test]
+    fn test_export_labels_pdf_comments_with_page() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_pdf_comment("doc.pdf", 4, "note", "quoted passage"))
+            .unwrap();
+
+        let markdown = book_comments.export(ExportFormat::Markdown).unwrap();
+        assert!(markdown.contains("**Page 5**"));
+    }
+
+    #[test]
+    fn test_export_bibtex_groups_by_chapter_then_secondary_sort_key() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir))
+            .unwrap()
+            .with_metadata(BookMetadata {
+                title: "My Book".to_string(),
+                author: "A. Author".to_string(),
+                identifier: None,
+            });
+
+        book_comments
+            .add_comment(create_paragraph_comment("b.xhtml", 0, "Second chapter note"))
+            .unwrap();
+        book_comments
+            .add_comment(create_paragraph_comment("a.xhtml", 0, "First chapter note"))
+            .unwrap();
+
+        let bibtex = book_comments.export_bibtex();
+        let first_pos = bibtex.find("First chapter note").unwrap();
+        let second_pos = bibtex.find("Second chapter note").unwrap();
+        assert!(first_pos < second_pos);
+        assert!(bibtex.contains("author = {A. Author}"));
+        assert!(bibtex.contains("booktitle = {My Book}"));
+        assert!(bibtex.contains("title = {a.xhtml}"));
+    }
+
+    #[test]
+    fn test_export_ris_includes_author_when_metadata_set() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir))
+            .unwrap()
+            .with_metadata(BookMetadata {
+                title: "My Book".to_string(),
+                author: "A. Author".to_string(),
+                identifier: None,
+            });
+
+        book_comments
+            .add_comment(create_paragraph_comment("chapter1.xhtml", 0, "A note"))
+            .unwrap();
+
+        assert!(book_comments.export_ris().contains("AU  - A. Author"));
+    }
+
+    #[test]
+    fn test_export_ris_splits_code_line_range_into_sp_and_ep() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        book_comments
+            .add_comment(create_code_comment("chapter1.xhtml", 1, (4, 6), "Watch this loop"))
+            .unwrap();
+
+        let ris = book_comments.export_ris();
+        assert!(ris.contains("SP  - l. 4"));
+        assert!(ris.contains("EP  - l. 6"));
+    }
+
+    #[test]
+    fn test_export_csl_json_includes_title_author_and_locator() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir))
+            .unwrap()
+            .with_metadata(BookMetadata {
+                title: "My Book".to_string(),
+                author: "A. Author".to_string(),
+                identifier: Some("isbn:123".to_string()),
+            });
+
+        book_comments
+            .add_comment(create_paragraph_comment("chapter1.xhtml", 2, "My note"))
+            .unwrap();
+
+        let csl_json = book_comments.export_csl_json().unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&csl_json).unwrap();
+        let entry = &parsed[0];
+        assert_eq!(entry["title"], "My Book");
+        assert_eq!(entry["author"][0]["literal"], "A. Author");
+        assert_eq!(entry["note"], "My note");
+        assert_eq!(entry["locator"], "¶3");
+    }
+
+    #[test]
+    fn test_bibtex_escaping_round_trips_special_characters() {
+        let original = "50% off {braces} & \\backslash_underscore#hash$dollar";
+        let escaped = escape_bibtex(original);
+        assert_eq!(unescape_bibtex(&escaped), original);
+        // Special characters must actually be escaped, not passed through.
+        assert!(escaped.contains("\\%"));
+        assert!(escaped.contains("\\{"));
+        assert!(escaped.contains("\\&"));
+    }
+
+    #[test]
+    fn test_search_empty_query_returns_no_results() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+        book_comments
+            .add_comment(create_paragraph_comment("chapter1.xhtml", 0, "Some note"))
+            .unwrap();
+
+        assert!(book_comments.search("   ", SearchOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_set_tags_by_id_and_retrieve_by_tag() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let comment = create_paragraph_comment("chapter1.xhtml", 0, "Look into this later");
+        book_comments.add_comment(comment.clone()).unwrap();
+
+        book_comments
+            .set_tags_by_id(&comment.id, vec!["todo".to_string(), "question".to_string()])
+            .unwrap();
+
+        let todo_comments = book_comments.comments_with_tag("todo");
+        assert_eq!(todo_comments.len(), 1);
+        assert_eq!(todo_comments[0].content, "Look into this later");
+        assert_eq!(book_comments.comments_with_tag("question").len(), 1);
+        assert!(book_comments.comments_with_tag("nonexistent").is_empty());
+    }
+
+    #[test]
+    fn test_tags_sorted_by_frequency_then_alphabetically() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let a = create_paragraph_comment("chapter1.xhtml", 0, "A");
+        let b = create_paragraph_comment("chapter1.xhtml", 1, "B");
+        let c = create_paragraph_comment("chapter1.xhtml", 2, "C");
+        book_comments.add_comment(a.clone()).unwrap();
+        book_comments.add_comment(b.clone()).unwrap();
+        book_comments.add_comment(c.clone()).unwrap();
+
+        book_comments.set_tags_by_id(&a.id, vec!["todo".to_string()]).unwrap();
+        book_comments.set_tags_by_id(&b.id, vec!["todo".to_string()]).unwrap();
+        book_comments
+            .set_tags_by_id(&c.id, vec!["important".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            book_comments.tags(),
+            vec![("todo".to_string(), 2), ("important".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_set_tags_by_id_replaces_previous_tags() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let comment = create_paragraph_comment("chapter1.xhtml", 0, "Note");
+        book_comments.add_comment(comment.clone()).unwrap();
+
+        book_comments
+            .set_tags_by_id(&comment.id, vec!["todo".to_string()])
+            .unwrap();
+        book_comments
+            .set_tags_by_id(&comment.id, vec!["done".to_string()])
+            .unwrap();
+
+        assert!(book_comments.comments_with_tag("todo").is_empty());
+        assert_eq!(book_comments.comments_with_tag("done").len(), 1);
+    }
+
+    #[test]
+    fn test_legacy_comment_deserialize_defaults_tags_to_empty() {
+        let legacy_yaml = r#"
+- chapter_href: ch.xhtml
+  paragraph_index: 5
+  content: legacy
+  updated_at: "2024-01-01T12:00:00Z"
+"#;
+        let parsed: Vec<Comment> = serde_yaml::from_str(legacy_yaml).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert!(parsed[0].tags.is_empty());
+        assert!(parsed[0].parent_id.is_none());
+    }
+
+    #[test]
+    fn test_reply_renders_directly_beneath_parent() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let parent = create_paragraph_comment("chapter1.xhtml", 0, "Root note");
+        book_comments.add_comment(parent.clone()).unwrap();
+
+        let other_root = create_paragraph_comment("chapter1.xhtml", 1, "Other root");
+        book_comments.add_comment(other_root).unwrap();
+
+        // Reply to the first root; added after `other_root` but must still
+        // sort directly beneath its parent, not by recency.
+        let reply = create_paragraph_comment("chapter1.xhtml", 0, "A reply").reply_to(parent.id.clone());
+        book_comments.add_comment(reply.clone()).unwrap();
+
+        let all = book_comments.get_all_comments();
+        let parent_pos = all.iter().position(|c| c.id == parent.id).unwrap();
+        assert_eq!(all[parent_pos + 1].id, reply.id);
+    }
+
+    #[test]
+    fn test_ancestors_and_thread_root_walk_chain() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let root = create_paragraph_comment("chapter1.xhtml", 0, "Root");
+        book_comments.add_comment(root.clone()).unwrap();
+
+        let reply1 = create_paragraph_comment("chapter1.xhtml", 0, "Reply 1").reply_to(root.id.clone());
+        book_comments.add_comment(reply1.clone()).unwrap();
+
+        let reply2 = create_paragraph_comment("chapter1.xhtml", 0, "Reply 2").reply_to(reply1.id.clone());
+        book_comments.add_comment(reply2.clone()).unwrap();
+
+        let ancestors = book_comments.ancestors(&reply2.id);
+        assert_eq!(ancestors.len(), 2);
+        assert_eq!(ancestors[0].id, reply1.id);
+        assert_eq!(ancestors[1].id, root.id);
+
+        assert_eq!(book_comments.thread_root(&reply2.id).unwrap().id, root.id);
+        assert!(book_comments.ancestors(&root.id).is_empty());
+        assert_eq!(book_comments.thread_root(&root.id).unwrap().id, root.id);
+    }
+
+    #[test]
+    fn test_replies_lists_direct_children_only() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let root = create_paragraph_comment("chapter1.xhtml", 0, "Root");
+        book_comments.add_comment(root.clone()).unwrap();
+
+        let reply1 = create_paragraph_comment("chapter1.xhtml", 0, "Reply 1").reply_to(root.id.clone());
+        book_comments.add_comment(reply1.clone()).unwrap();
+
+        let reply2 = create_paragraph_comment("chapter1.xhtml", 0, "Reply 2").reply_to(reply1.id.clone());
+        book_comments.add_comment(reply2.clone()).unwrap();
+
+        let root_replies = book_comments.replies(&root.id);
+        assert_eq!(root_replies.len(), 1);
+        assert_eq!(root_replies[0].id, reply1.id);
+
+        let reply1_replies = book_comments.replies(&reply1.id);
+        assert_eq!(reply1_replies.len(), 1);
+        assert_eq!(reply1_replies[0].id, reply2.id);
+    }
+
+    #[test]
+    fn test_reply_to_missing_parent_becomes_top_level() {
+        let (_temp_dir, book_path, comments_dir) = create_test_env();
+        let mut book_comments = BookComments::new(&book_path, Some(&comments_dir)).unwrap();
+
+        let orphan = create_paragraph_comment("chapter1.xhtml", 0, "Orphan reply")
+            .reply_to("nonexistent-id".to_string());
+        book_comments.add_comment(orphan.clone()).unwrap();
+
+        let stored = book_comments.get_comment_by_id(&orphan.id).unwrap();
+        assert!(stored.parent_id.is_none());
+        assert!(book_comments.ancestors(&orphan.id).is_empty());
+    }
 }
@@ -4,7 +4,9 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, StatefulWidget, Widget},
 };
+use std::cell::RefCell;
 use std::cmp::max;
+use unicode_width::UnicodeWidthStr;
 
 use crate::types::LinkInfo;
 
@@ -92,6 +94,111 @@ impl From<&str> for CellData {
     }
 }
 
+/// Per-column text alignment, as encoded by a Markdown pipe-table delimiter
+/// row (`:---`, `:--:`, `---:`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Selectable box-drawing border styles, as in helix-tui's `block::BorderType`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BorderType {
+    #[default]
+    Plain,
+    Rounded,
+    Double,
+    Thick,
+}
+
+impl BorderType {
+    /// Returns the glyph set used to draw borders and T-junctions for this style.
+    fn symbols(self) -> BorderSet {
+        match self {
+            BorderType::Plain => BorderSet {
+                top_left: '┌',
+                top_right: '┐',
+                bottom_left: '└',
+                bottom_right: '┘',
+                horizontal: '─',
+                vertical: '│',
+                horizontal_down: '┬',
+                horizontal_up: '┴',
+                vertical_right: '├',
+                vertical_left: '┤',
+                cross: '┼',
+            },
+            BorderType::Rounded => BorderSet {
+                top_left: '╭',
+                top_right: '╮',
+                bottom_left: '╰',
+                bottom_right: '╯',
+                horizontal: '─',
+                vertical: '│',
+                horizontal_down: '┬',
+                horizontal_up: '┴',
+                vertical_right: '├',
+                vertical_left: '┤',
+                cross: '┼',
+            },
+            BorderType::Double => BorderSet {
+                top_left: '╔',
+                top_right: '╗',
+                bottom_left: '╚',
+                bottom_right: '╝',
+                horizontal: '═',
+                vertical: '║',
+                horizontal_down: '╦',
+                horizontal_up: '╩',
+                vertical_right: '╠',
+                vertical_left: '╣',
+                cross: '╬',
+            },
+            BorderType::Thick => BorderSet {
+                top_left: '┏',
+                top_right: '┓',
+                bottom_left: '┗',
+                bottom_right: '┛',
+                horizontal: '━',
+                vertical: '┃',
+                horizontal_down: '┳',
+                horizontal_up: '┻',
+                vertical_right: '┣',
+                vertical_left: '┫',
+                cross: '╋',
+            },
+        }
+    }
+}
+
+/// A styled span paired with the URL it was generated from, if it came from
+/// an `InlineSpan::Link`. Carried through wrapping so `render_row` can still
+/// locate link text after line-breaking.
+#[derive(Debug, Clone)]
+struct LinkedSpan {
+    span: Span<'static>,
+    link_url: Option<String>,
+}
+
+/// Corner, edge, and T-junction glyphs for one border style.
+#[derive(Debug, Clone, Copy)]
+struct BorderSet {
+    top_left: char,
+    top_right: char,
+    bottom_left: char,
+    bottom_right: char,
+    horizontal: char,
+    vertical: char,
+    horizontal_down: char,
+    horizontal_up: char,
+    vertical_right: char,
+    vertical_left: char,
+    cross: char,
+}
+
 /// Configuration for table appearance
 #[derive(Debug, Clone)]
 pub struct TableConfig {
@@ -118,12 +225,25 @@ pub struct Table {
     rows: Vec<Vec<CellData>>,
     header: Option<Vec<CellData>>,
     constraints: Vec<Constraint>,
+    /// Per-column alignment; a column past the end of this list defaults to
+    /// `Alignment::Left`.
+    alignments: Vec<Alignment>,
     config: TableConfig,
     block: Option<Block<'static>>,
-    /// Store link information for click handling
-    links: Vec<LinkInfo>,
+    /// Each link's URL (stored in both `text` and `url`) and its absolute
+    /// (line, column) range, populated during rendering for click handling
+    /// and OSC 8 hyperlink emission.
+    links: RefCell<Vec<LinkInfo>>,
     /// Base line number where this table starts (for absolute positioning)
     base_line: usize,
+    /// Style patched onto the selected row's spans when rendered via
+    /// `StatefulWidget` with a `TableState::selected_row` set.
+    highlight_style: Style,
+    /// Box-drawing glyph style used for borders and separators.
+    border_type: BorderType,
+    /// When set, link text is wrapped in OSC 8 escape sequences so
+    /// supporting terminals render it as a real clickable hyperlink.
+    hyperlinks: bool,
 }
 
 impl Table {
@@ -137,10 +257,14 @@ impl Table {
             rows: cell_rows,
             header: None,
             constraints: Vec::new(),
+            alignments: Vec::new(),
             config: TableConfig::default(),
             block: None,
-            links: Vec::new(),
+            links: RefCell::new(Vec::new()),
             base_line: 0,
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            border_type: BorderType::default(),
+            hyperlinks: false,
         }
     }
 
@@ -150,13 +274,41 @@ impl Table {
             rows,
             header: None,
             constraints: Vec::new(),
+            alignments: Vec::new(),
             config: TableConfig::default(),
             block: None,
-            links: Vec::new(),
+            links: RefCell::new(Vec::new()),
             base_line: 0,
+            highlight_style: Style::default().add_modifier(Modifier::REVERSED),
+            border_type: BorderType::default(),
+            hyperlinks: false,
         }
     }
 
+    /// Sets the style patched onto the selected row when rendered via
+    /// `StatefulWidget` (defaults to reversed video, like ratatui's
+    /// `Table::highlight_style`).
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Sets the box-drawing glyph style used for borders and separators
+    /// (defaults to `BorderType::Plain`).
+    pub fn border_type(mut self, border_type: BorderType) -> Self {
+        self.border_type = border_type;
+        self
+    }
+
+    /// When enabled, link text is wrapped in OSC 8 escape sequences on
+    /// render so terminals that support it treat the text as a real
+    /// clickable hyperlink. Disabled by default; degrades to plain styled
+    /// text (cyan + underline) when off or unsupported.
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+
     pub fn header(mut self, header: Vec<String>) -> Self {
         let cells = header.into_iter().map(CellData::from).collect();
         self.header = Some(cells);
@@ -180,6 +332,13 @@ impl Table {
         self
     }
 
+    /// Sets per-column alignment, as parsed from a Markdown pipe-table
+    /// delimiter row. Columns past the end of `alignments` stay left-aligned.
+    pub fn alignments(mut self, alignments: Vec<Alignment>) -> Self {
+        self.alignments = alignments;
+        self
+    }
+
     pub fn config(mut self, config: TableConfig) -> Self {
         self.config = config;
         self
@@ -197,60 +356,239 @@ impl Table {
 
     /// Calculate column widths based on constraints and available space
     fn calculate_column_widths(&self, available_width: u16) -> Vec<u16> {
-        let num_cols = self.constraints.len();
-        if num_cols == 0 {
-            return Vec::new();
+        if self.constraints.is_empty() {
+            return self.auto_column_widths(available_width);
         }
 
+        let num_cols = self.constraints.len();
+
         // Account for borders: left border (1) + column separators (num_cols - 1) + right border (1)
         let border_width = 1 + (num_cols - 1) + 1;
         let content_width = available_width.saturating_sub(border_width as u16);
 
-        let mut widths = Vec::new();
-        let mut remaining_width = content_width;
-        let mut length_constraints = Vec::new();
-
-        // First pass: handle Length constraints
-        for constraint in &self.constraints {
-            match constraint {
-                Constraint::Length(len) => {
-                    let width = (*len).min(remaining_width);
-                    widths.push(width);
-                    remaining_width = remaining_width.saturating_sub(width);
-                    length_constraints.push(None);
+        let mut widths = vec![0u16; num_cols];
+        let mut fixed_total: u16 = 0;
+        let mut flexible: Vec<usize> = Vec::new();
+
+        // First pass: resolve Length/Percentage/Ratio directly against
+        // `content_width`; Min/Max are flexible and resolved in the second
+        // pass once we know how much space is left over.
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            let width = match constraint {
+                Constraint::Length(len) => Some(*len),
+                Constraint::Percentage(p) => {
+                    Some((*p as u32 * content_width as u32 / 100) as u16)
+                }
+                Constraint::Ratio(n, d) => Some(if *d == 0 {
+                    0
+                } else {
+                    (*n as u64 * content_width as u64 / *d as u64) as u16
+                }),
+                Constraint::Min(_) | Constraint::Max(_) => None,
+                // Any other constraint kind (e.g. `Fill`) shares the
+                // remaining space like an unbounded `Min(0)`.
+                _ => None,
+            };
+
+            match width {
+                Some(w) => {
+                    let w = w.min(content_width.saturating_sub(fixed_total));
+                    widths[i] = w;
+                    fixed_total += w;
+                }
+                None => flexible.push(i),
+            }
+        }
+
+        // Second pass: distribute what's left evenly among Min/Max columns,
+        // then clamp each to its bound.
+        let remaining = content_width.saturating_sub(fixed_total);
+        if !flexible.is_empty() {
+            let share = remaining / flexible.len() as u16;
+            let mut extra = remaining % flexible.len() as u16;
+
+            for &i in &flexible {
+                let mut w = share;
+                if extra > 0 {
+                    w += 1;
+                    extra -= 1;
+                }
+                widths[i] = match &self.constraints[i] {
+                    Constraint::Min(m) => w.max(*m),
+                    Constraint::Max(m) => w.min(*m),
+                    _ => w,
+                };
+            }
+
+            // Hand any width freed up by `Max` clamps (or left over from
+            // integer rounding) to the last flexible column, so the total
+            // still accounts for all of `content_width`.
+            let distributed: u16 = flexible.iter().map(|&i| widths[i]).sum();
+            let leftover = remaining.saturating_sub(distributed);
+            if leftover > 0 {
+                if let Some(&last) = flexible.last() {
+                    widths[last] += leftover;
+                }
+            }
+        }
+
+        widths
+    }
+
+    /// Derives column widths from cell content when no explicit
+    /// `Constraint`s were set, mirroring nu-table/tabled's fit-to-content
+    /// layout: each column's desired width is its widest full line (after
+    /// splitting on hard breaks), clamped down to at least its widest
+    /// unbreakable word and up to a sane cap. If the total fits, desired
+    /// widths are used as-is and any leftover space goes to the widest
+    /// columns; if not, columns shrink proportionally to their slack above
+    /// `max_word` so a column is only squeezed below its longest word (and
+    /// `render_row` has to hard-wrap it) when even the sum of every
+    /// column's longest word can't fit.
+    fn auto_column_widths(&self, available_width: u16) -> Vec<u16> {
+        const WIDTH_CAP: usize = 40;
+
+        let num_cols = self
+            .header
+            .as_ref()
+            .map(|h| h.len())
+            .unwrap_or(0)
+            .max(self.rows.iter().map(|r| r.len()).max().unwrap_or(0));
+        if num_cols == 0 {
+            return Vec::new();
+        }
+
+        let mut max_word = vec![1usize; num_cols];
+        let mut max_line = vec![1usize; num_cols];
+
+        let measure_row = |row: &[CellData], max_word: &mut [usize], max_line: &mut [usize]| {
+            for (col, cell) in row.iter().enumerate() {
+                if cell.colspan > 1 || col >= max_word.len() {
+                    continue;
                 }
-                _ => {
-                    widths.push(0);
-                    length_constraints.push(Some(constraint));
+                for line in Self::cell_plain_lines(&cell.content) {
+                    max_line[col] = max_line[col].max(UnicodeWidthStr::width(line.as_str()));
+                    for word in line.split_whitespace() {
+                        max_word[col] = max_word[col].max(UnicodeWidthStr::width(word));
+                    }
                 }
             }
+        };
+
+        if let Some(ref header) = self.header {
+            measure_row(header, &mut max_word, &mut max_line);
+        }
+        for row in &self.rows {
+            measure_row(row, &mut max_word, &mut max_line);
         }
 
-        // Second pass: distribute remaining width among percentage/ratio constraints
-        let flexible_count = length_constraints.iter().filter(|c| c.is_some()).count();
-        if flexible_count > 0 && remaining_width > 0 {
-            let width_per_flexible = remaining_width / flexible_count as u16;
-            let mut extra = remaining_width % flexible_count as u16;
+        let desired: Vec<usize> = (0..num_cols)
+            .map(|i| max_line[i].min(WIDTH_CAP).max(max_word[i]))
+            .collect();
 
-            for (i, constraint_opt) in length_constraints.iter().enumerate() {
-                if constraint_opt.is_some() {
-                    let mut width = width_per_flexible;
+        let border_width = 1 + (num_cols - 1) + 1;
+        let content_width = available_width.saturating_sub(border_width as u16) as usize;
+
+        let total_desired: usize = desired.iter().sum();
+        if total_desired <= content_width {
+            let mut widths: Vec<u16> = desired.iter().map(|&w| w as u16).collect();
+            let leftover = content_width - total_desired;
+            Self::distribute_leftover(&mut widths, &desired, leftover);
+            return widths;
+        }
+
+        let total_max_word: usize = max_word.iter().sum();
+        if total_max_word >= content_width {
+            // Not even the widest words fit; split the space evenly and let
+            // `render_row` hard-wrap mid-word as a last resort.
+            let base = content_width / num_cols;
+            let mut extra = content_width % num_cols;
+            return (0..num_cols)
+                .map(|_| {
+                    let mut w = base;
                     if extra > 0 {
-                        width += 1;
+                        w += 1;
                         extra -= 1;
                     }
-                    widths[i] = width;
+                    w as u16
+                })
+                .collect();
+        }
+
+        // Shrink columns proportionally to their slack above `max_word` so
+        // no column is squeezed below its longest word.
+        let total_slack: usize = (0..num_cols).map(|i| desired[i] - max_word[i]).sum();
+        let available_slack = content_width - total_max_word;
+        let mut widths: Vec<u16> = (0..num_cols)
+            .map(|i| {
+                let slack = desired[i] - max_word[i];
+                let shrunk_slack = if total_slack > 0 {
+                    slack * available_slack / total_slack
+                } else {
+                    0
+                };
+                (max_word[i] + shrunk_slack) as u16
+            })
+            .collect();
+
+        let used: usize = widths.iter().map(|&w| w as usize).sum();
+        let leftover = content_width.saturating_sub(used);
+        Self::distribute_leftover(&mut widths, &desired, leftover);
+
+        widths
+    }
+
+    /// Hands out `leftover` columns of width one at a time to the widest
+    /// columns first (by `desired` width), breaking ties by column index.
+    fn distribute_leftover(widths: &mut [u16], desired: &[usize], leftover: usize) {
+        if leftover == 0 {
+            return;
+        }
+        let mut order: Vec<usize> = (0..widths.len()).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse(desired[i]));
+        for i in 0..leftover {
+            widths[order[i % order.len()]] += 1;
+        }
+    }
+
+    /// Flattens a cell's inline content into plain-text lines, splitting on
+    /// hard breaks and discarding styling. Used only to measure natural
+    /// column widths for auto-layout.
+    fn cell_plain_lines(content: &[InlineSpan]) -> Vec<String> {
+        fn walk(inlines: &[InlineSpan], lines: &mut Vec<String>) {
+            for inline in inlines {
+                match inline {
+                    InlineSpan::Text { text, .. } => {
+                        lines.last_mut().expect("lines always has an entry").push_str(text);
+                    }
+                    InlineSpan::Link { text, .. } => walk(text, lines),
+                    InlineSpan::SoftBreak => {
+                        lines
+                            .last_mut()
+                            .expect("lines always has an entry")
+                            .push(' ');
+                    }
+                    InlineSpan::HardBreak => lines.push(String::new()),
                 }
             }
         }
 
-        widths
+        let mut lines = vec![String::new()];
+        walk(content, &mut lines);
+        lines
     }
 
     /// Render top border with proper Unicode box-drawing characters
     /// If colspans is provided, skip column separators within colspan regions
     fn render_top_border(&self, widths: &[u16], colspans: Option<&[u32]>) -> Line<'static> {
-        self.render_horizontal_border(widths, colspans, '┌', '┬', '┐')
+        let glyphs = self.border_type.symbols();
+        self.render_horizontal_border(
+            widths,
+            colspans,
+            glyphs.top_left,
+            glyphs.horizontal_down,
+            glyphs.top_right,
+        )
     }
 
     /// Render a horizontal border with colspan support
@@ -266,6 +604,8 @@ impl Table {
             return Line::from("");
         }
 
+        let horizontal = self.border_type.symbols().horizontal;
+
         // Build a set of grid column indices where we should NOT draw a separator
         // These are columns that are "inside" a colspan (not the first column of a cell)
         let mut skip_separator_after: std::collections::HashSet<usize> =
@@ -286,10 +626,10 @@ impl Table {
         line.push(left);
 
         for (i, &width) in widths.iter().enumerate() {
-            line.push_str(&"─".repeat(width as usize));
+            line.push_str(&horizontal.to_string().repeat(width as usize));
             if i < widths.len() - 1 {
                 if skip_separator_after.contains(&i) {
-                    line.push('─'); // Continue the horizontal line
+                    line.push(horizontal); // Continue the horizontal line
                 } else {
                     line.push(middle);
                 }
@@ -341,25 +681,26 @@ impl Table {
             }
         }
 
+        let glyphs = self.border_type.symbols();
         let mut line = String::new();
-        line.push('├');
+        line.push(glyphs.vertical_right);
 
         for (i, &width) in widths.iter().enumerate() {
-            line.push_str(&"─".repeat(width as usize));
+            line.push_str(&glyphs.horizontal.to_string().repeat(width as usize));
             if i < widths.len() - 1 {
                 let skip_a = skip_above.contains(&i);
                 let skip_b = skip_below.contains(&i);
                 let ch = match (skip_a, skip_b) {
-                    (true, true) => '─',   // Both rows span over this column
-                    (true, false) => '┬',  // Row above spans, row below has separator
-                    (false, true) => '┴',  // Row below spans, row above has separator
-                    (false, false) => '┼', // Both rows have a separator here
+                    (true, true) => glyphs.horizontal, // Both rows span over this column
+                    (true, false) => glyphs.horizontal_down, // Row above spans, row below has separator
+                    (false, true) => glyphs.horizontal_up, // Row below spans, row above has separator
+                    (false, false) => glyphs.cross,    // Both rows have a separator here
                 };
                 line.push(ch);
             }
         }
 
-        line.push('┤');
+        line.push(glyphs.vertical_left);
         Line::from(Span::styled(
             line,
             Style::default().fg(self.config.border_color),
@@ -368,7 +709,14 @@ impl Table {
 
     /// Render bottom border
     fn render_bottom_border(&self, widths: &[u16], colspans: Option<&[u32]>) -> Line<'static> {
-        self.render_horizontal_border(widths, colspans, '└', '┴', '┘')
+        let glyphs = self.border_type.symbols();
+        self.render_horizontal_border(
+            widths,
+            colspans,
+            glyphs.bottom_left,
+            glyphs.horizontal_up,
+            glyphs.bottom_right,
+        )
     }
 
     fn style_for_inline(&self, base_color: Color, inline_style: InlineStyle) -> Style {
@@ -396,29 +744,32 @@ impl Table {
         &self,
         inlines: &[InlineSpan],
         base_color: Color,
-        link_style: bool,
-    ) -> Vec<Span<'static>> {
+        link_url: Option<&str>,
+    ) -> Vec<LinkedSpan> {
         let mut spans = Vec::new();
 
         for inline in inlines {
             match inline {
                 InlineSpan::Text { text, style } => {
                     let mut span_style = self.style_for_inline(base_color, *style);
-                    if link_style {
+                    if link_url.is_some() {
                         span_style = span_style
                             .fg(Color::Cyan)
                             .add_modifier(Modifier::UNDERLINED);
                     }
-                    spans.push(Span::styled(text.clone(), span_style));
+                    spans.push(LinkedSpan {
+                        span: Span::styled(text.clone(), span_style),
+                        link_url: link_url.map(str::to_string),
+                    });
                 }
-                InlineSpan::Link { text, .. } => {
-                    spans.extend(self.inline_to_spans_flat(text, base_color, true));
+                InlineSpan::Link { text, url } => {
+                    spans.extend(self.inline_to_spans_flat(text, base_color, Some(url)));
                 }
                 InlineSpan::SoftBreak | InlineSpan::HardBreak => {
-                    spans.push(Span::styled(
-                        " ".to_string(),
-                        Style::default().fg(base_color),
-                    ));
+                    spans.push(LinkedSpan {
+                        span: Span::styled(" ".to_string(), Style::default().fg(base_color)),
+                        link_url: link_url.map(str::to_string),
+                    });
                 }
             }
         }
@@ -426,11 +777,7 @@ impl Table {
         spans
     }
 
-    fn inline_to_lines(
-        &self,
-        inlines: &[InlineSpan],
-        base_color: Color,
-    ) -> Vec<Vec<Span<'static>>> {
+    fn inline_to_lines(&self, inlines: &[InlineSpan], base_color: Color) -> Vec<Vec<LinkedSpan>> {
         let mut lines = Vec::new();
         let mut current_line = Vec::new();
 
@@ -441,16 +788,16 @@ impl Table {
                     current_line = Vec::new();
                 }
                 InlineSpan::SoftBreak => {
-                    current_line.push(Span::styled(
-                        " ".to_string(),
-                        Style::default().fg(base_color),
-                    ));
+                    current_line.push(LinkedSpan {
+                        span: Span::styled(" ".to_string(), Style::default().fg(base_color)),
+                        link_url: None,
+                    });
                 }
                 InlineSpan::Text { .. } | InlineSpan::Link { .. } => {
                     current_line.extend(self.inline_to_spans_flat(
                         std::slice::from_ref(inline),
                         base_color,
-                        false,
+                        None,
                     ));
                 }
             }
@@ -461,28 +808,28 @@ impl Table {
         }
 
         if lines.is_empty() {
-            lines.push(vec![Span::styled(
-                String::new(),
-                Style::default().fg(base_color),
-            )]);
+            lines.push(vec![LinkedSpan {
+                span: Span::styled(String::new(), Style::default().fg(base_color)),
+                link_url: None,
+            }]);
         }
 
         lines
     }
 
-    /// Wrap spans while preserving formatting
+    /// Wrap spans while preserving formatting and link URLs
     fn wrap_spans_with_formatting(
         &self,
-        spans: &[Span<'static>],
+        spans: &[LinkedSpan],
         width: usize,
         base_color: Color,
-    ) -> Vec<Vec<Span<'static>>> {
+    ) -> Vec<Vec<LinkedSpan>> {
         let mut result = Vec::new();
         let mut current_line = Vec::new();
         let mut current_width = 0;
 
         for span in spans {
-            let span_content = span.content.as_ref();
+            let span_content = span.span.content.as_ref();
             let span_width = span_content.chars().count();
 
             if current_width + span_width <= width {
@@ -497,7 +844,10 @@ impl Table {
                 while start < chars.len() {
                     let end = (start + width).min(chars.len());
                     let chunk: String = chars[start..end].iter().collect();
-                    current_line.push(Span::styled(chunk, span.style));
+                    current_line.push(LinkedSpan {
+                        span: Span::styled(chunk, span.span.style),
+                        link_url: span.link_url.clone(),
+                    });
 
                     if !current_line.is_empty() {
                         result.push(current_line.clone());
@@ -527,7 +877,10 @@ impl Table {
                         let chunk: String = chars[start..end].iter().collect();
 
                         let chunk_display_width = chunk.chars().count();
-                        current_line.push(Span::styled(chunk, span.style));
+                        current_line.push(LinkedSpan {
+                            span: Span::styled(chunk, span.span.style),
+                            link_url: span.link_url.clone(),
+                        });
 
                         if start + width < chars.len() {
                             result.push(current_line.clone());
@@ -548,19 +901,28 @@ impl Table {
         }
 
         if result.is_empty() {
-            result.push(vec![Span::styled(
-                String::new(),
-                Style::default().fg(base_color),
-            )]);
+            result.push(vec![LinkedSpan {
+                span: Span::styled(String::new(), Style::default().fg(base_color)),
+                link_url: None,
+            }]);
         }
 
         result
     }
 
-    /// Render a data row with proper cell formatting, wrapping, and colspan support
-    fn render_row(&self, row: &[CellData], widths: &[u16], is_header: bool) -> Vec<Line<'static>> {
+    /// Render a data row with proper cell formatting, wrapping, and colspan
+    /// support. Also returns, for each rendered output line (by index into
+    /// the returned `Vec<Line>`), the `(start_col, end_col, url)` ranges of
+    /// any link text on that line, for hyperlink emission.
+    fn render_row(
+        &self,
+        row: &[CellData],
+        widths: &[u16],
+        is_header: bool,
+        highlight: bool,
+    ) -> (Vec<Line<'static>>, Vec<(usize, u16, u16, String)>) {
         if widths.is_empty() || row.is_empty() {
-            return vec![Line::from("")];
+            return (vec![Line::from("")], Vec::new());
         }
 
         let text_color = if is_header {
@@ -572,6 +934,7 @@ impl Table {
         // Calculate effective width for each cell (accounting for colspan)
         // Also track which grid columns each cell occupies
         let mut cell_widths: Vec<usize> = Vec::new();
+        let mut cell_grid_cols: Vec<usize> = Vec::new();
         let mut grid_col = 0;
 
         for cell in row {
@@ -589,11 +952,12 @@ impl Table {
             }
 
             cell_widths.push(total_width);
+            cell_grid_cols.push(grid_col);
             grid_col += colspan;
         }
 
         // Wrap each cell content and find the maximum height
-        let mut wrapped_cells: Vec<Vec<Vec<Span<'static>>>> = Vec::new();
+        let mut wrapped_cells: Vec<Vec<Vec<LinkedSpan>>> = Vec::new();
         let mut max_height = 1;
 
         for (cell_idx, cell) in row.iter().enumerate() {
@@ -607,8 +971,10 @@ impl Table {
             let mut all_wrapped_lines = Vec::new();
 
             for line_spans in base_lines {
-                let display_width: usize =
-                    line_spans.iter().map(|s| s.content.chars().count()).sum();
+                let display_width: usize = line_spans
+                    .iter()
+                    .map(|s| s.span.content.chars().count())
+                    .sum();
                 if display_width <= width {
                     all_wrapped_lines.push(line_spans);
                 } else {
@@ -621,41 +987,75 @@ impl Table {
             }
 
             if all_wrapped_lines.is_empty() {
-                all_wrapped_lines.push(vec![Span::styled(
-                    String::new(),
-                    Style::default().fg(text_color),
-                )]);
+                all_wrapped_lines.push(vec![LinkedSpan {
+                    span: Span::styled(String::new(), Style::default().fg(text_color)),
+                    link_url: None,
+                }]);
             }
 
             max_height = max(max_height, all_wrapped_lines.len());
             wrapped_cells.push(all_wrapped_lines);
         }
 
+        let vertical = self.border_type.symbols().vertical;
+
         // Render each line of the row
         let mut lines = Vec::new();
+        let mut link_ranges: Vec<(usize, u16, u16, String)> = Vec::new();
         for line_idx in 0..max_height {
-            let mut line_spans = Vec::new();
+            let mut line_spans: Vec<Span<'static>> = Vec::new();
+            let mut col: u16 = 0;
 
             // Left border
             line_spans.push(Span::styled(
-                "│".to_string(),
+                vertical.to_string(),
                 Style::default().fg(self.config.border_color),
             ));
+            col += 1;
 
             for (cell_idx, cell_lines) in wrapped_cells.iter().enumerate() {
                 let width = cell_widths[cell_idx];
                 let cell_spans = cell_lines.get(line_idx).cloned().unwrap_or_default();
-                let spans_width: usize = cell_spans.iter().map(|s| s.content.chars().count()).sum();
+                let spans_width: usize = cell_spans
+                    .iter()
+                    .map(|s| s.span.content.chars().count())
+                    .sum();
 
                 if spans_width <= width {
+                    let alignment = self
+                        .alignments
+                        .get(cell_grid_cols[cell_idx])
+                        .copied()
+                        .unwrap_or_default();
+                    let slack = width - spans_width;
+                    let (left_pad, right_pad) = match alignment {
+                        Alignment::Left => (0, slack),
+                        Alignment::Right => (slack, 0),
+                        // Extra odd space goes to the right.
+                        Alignment::Center => (slack / 2, slack - slack / 2),
+                    };
+
+                    if left_pad > 0 {
+                        line_spans.push(Span::styled(
+                            " ".repeat(left_pad),
+                            Style::default().fg(text_color),
+                        ));
+                        col += left_pad as u16;
+                    }
                     for span in cell_spans {
-                        line_spans.push(span);
+                        let span_width = span.span.content.chars().count() as u16;
+                        if let Some(url) = span.link_url {
+                            link_ranges.push((line_idx, col, col + span_width, url));
+                        }
+                        line_spans.push(span.span);
+                        col += span_width;
                     }
-                    if spans_width < width {
+                    if right_pad > 0 {
                         line_spans.push(Span::styled(
-                            " ".repeat(width - spans_width),
+                            " ".repeat(right_pad),
                             Style::default().fg(text_color),
                         ));
+                        col += right_pad as u16;
                     }
                 } else {
                     let mut remaining_width = width;
@@ -663,15 +1063,33 @@ impl Table {
                         if remaining_width == 0 {
                             break;
                         }
-                        let span_display_width = span.content.chars().count();
+                        let span_display_width = span.span.content.chars().count();
                         if span_display_width <= remaining_width {
-                            line_spans.push(span);
+                            if let Some(url) = span.link_url {
+                                link_ranges.push((
+                                    line_idx,
+                                    col,
+                                    col + span_display_width as u16,
+                                    url,
+                                ));
+                            }
+                            line_spans.push(span.span);
+                            col += span_display_width as u16;
                             remaining_width -= span_display_width;
                         } else if remaining_width > 0 {
                             let truncated_content: String =
-                                span.content.chars().take(remaining_width).collect();
+                                span.span.content.chars().take(remaining_width).collect();
                             let truncated_width = truncated_content.chars().count();
-                            line_spans.push(Span::styled(truncated_content, span.style));
+                            if let Some(url) = span.link_url {
+                                link_ranges.push((
+                                    line_idx,
+                                    col,
+                                    col + truncated_width as u16,
+                                    url,
+                                ));
+                            }
+                            line_spans.push(Span::styled(truncated_content, span.span.style));
+                            col += truncated_width as u16;
                             remaining_width -= truncated_width;
                         }
                     }
@@ -680,22 +1098,30 @@ impl Table {
                 // Column separator - only add if this is not the last cell
                 if cell_idx < wrapped_cells.len() - 1 {
                     line_spans.push(Span::styled(
-                        "│".to_string(),
+                        vertical.to_string(),
                         Style::default().fg(self.config.border_color),
                     ));
+                    col += 1;
                 }
             }
 
             // Right border
             line_spans.push(Span::styled(
-                "│".to_string(),
+                vertical.to_string(),
                 Style::default().fg(self.config.border_color),
             ));
 
+            if highlight {
+                line_spans = line_spans
+                    .into_iter()
+                    .map(|span| Span::styled(span.content, span.style.patch(self.highlight_style)))
+                    .collect();
+            }
+
             lines.push(Line::from(line_spans));
         }
 
-        lines
+        (lines, link_ranges)
     }
 
     /// Render the table into a vector of lines for integration with Paragraph widget
@@ -726,8 +1152,22 @@ impl Table {
 
     /// Render all table lines without any offset or limit
     fn render_all_lines(&self, available_width: u16) -> Vec<Line<'static>> {
+        self.render_all_lines_with_selection(available_width, None)
+    }
+
+    /// Render all table lines, highlighting `selected_row` (a data row
+    /// index, not counting the header) with `highlight_style` if set. Also
+    /// refreshes `self.links` with each link's absolute (line, column)
+    /// range, for click handling and OSC 8 hyperlink emission.
+    fn render_all_lines_with_selection(
+        &self,
+        available_width: u16,
+        selected_row: Option<usize>,
+    ) -> Vec<Line<'static>> {
         let widths = self.calculate_column_widths(available_width);
         let mut lines = Vec::new();
+        let mut links = self.links.borrow_mut();
+        links.clear();
 
         // Top border - use header colspans if header exists, otherwise first row
         let header_colspans = self.header.as_ref().map(|h| {
@@ -750,7 +1190,8 @@ impl Table {
 
         // Header if present
         if let Some(ref header) = self.header {
-            let header_lines = self.render_row(header, &widths, true);
+            let (header_lines, header_links) = self.render_row(header, &widths, true, false);
+            Self::record_links(&mut links, lines.len(), &header_links);
             lines.extend(header_lines);
 
             // Middle border between header and first data row
@@ -762,8 +1203,10 @@ impl Table {
         }
 
         // Data rows
-        for row in &self.rows {
-            let row_lines = self.render_row(row, &widths, false);
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            let (row_lines, row_links) =
+                self.render_row(row, &widths, false, selected_row == Some(row_idx));
+            Self::record_links(&mut links, lines.len(), &row_links);
             lines.extend(row_lines);
         }
 
@@ -775,18 +1218,114 @@ impl Table {
         });
         lines.push(self.render_bottom_border(&widths, bottom_colspans.as_deref()));
 
+        drop(links);
         lines
     }
 
-    /// Get all links in this table
-    pub fn get_links(&self) -> &Vec<LinkInfo> {
-        &self.links
+    /// Appends `ranges` (relative to the row that was just rendered) to
+    /// `links` as absolute-position `LinkInfo` entries, using `base_line` as
+    /// the row's starting line within the full table render.
+    fn record_links(
+        links: &mut Vec<LinkInfo>,
+        base_line: usize,
+        ranges: &[(usize, u16, u16, String)],
+    ) {
+        for (rel_line, start_col, end_col, url) in ranges {
+            links.push(LinkInfo {
+                text: url.clone(),
+                url: url.clone(),
+                line: base_line + rel_line,
+                start_col: *start_col as usize,
+                end_col: *end_col as usize,
+            });
+        }
+    }
+
+    /// The `[start, end)` line range occupied by data row `row_index` within
+    /// `render_all_lines`'s output (a row can span multiple wrapped lines),
+    /// so a selected row index can be translated into a scroll offset.
+    fn data_row_line_range(&self, available_width: u16, row_index: usize) -> Option<(usize, usize)> {
+        if row_index >= self.rows.len() {
+            return None;
+        }
+
+        let widths = self.calculate_column_widths(available_width);
+        let mut line = 1; // top border
+
+        if let Some(ref header) = self.header {
+            line += self.render_row(header, &widths, true, false).0.len();
+            line += 1; // middle border
+        }
+
+        for row in &self.rows[..row_index] {
+            line += self.render_row(row, &widths, false, false).0.len();
+        }
+
+        let row_height = self
+            .render_row(&self.rows[row_index], &widths, false, false)
+            .0
+            .len();
+        Some((line, line + row_height))
+    }
+
+    /// Get all links in this table, with their absolute (line, column)
+    /// ranges as of the most recent render.
+    pub fn get_links(&self) -> Vec<LinkInfo> {
+        self.links.borrow().clone()
+    }
+
+    /// Wraps the buffer cells under each link's column range in OSC 8
+    /// hyperlink escapes (`ESC ]8;;URL ESC \` ... `ESC ]8;; ESC \`), so
+    /// terminals that support it render the text as a clickable link. The
+    /// escapes are spliced into the symbol of the first and last cell of the
+    /// range rather than `Span` content, since `Buffer` assigns one cell per
+    /// grapheme and raw escapes would otherwise be counted as visible width.
+    /// `line_offset` is the number of leading rendered lines already
+    /// scrolled past (0 for `Widget::render`, the active scroll offset for
+    /// `StatefulWidget::render`).
+    fn apply_hyperlinks(
+        buf: &mut ratatui::buffer::Buffer,
+        area: Rect,
+        line_offset: usize,
+        links: &[LinkInfo],
+    ) {
+        for link in links {
+            if link.line < line_offset {
+                continue;
+            }
+            let rel_line = link.line - line_offset;
+            if rel_line >= area.height as usize {
+                continue;
+            }
+            let y = area.y + rel_line as u16;
+
+            let start_col = (link.start_col as u16).min(area.width);
+            let end_col = (link.end_col as u16).min(area.width);
+            if start_col >= end_col {
+                continue;
+            }
+            let x0 = area.x + start_col;
+            let x1 = area.x + end_col;
+
+            let prologue = format!("\x1b]8;;{}\x1b\\", link.url);
+            if let Some(cell) = buf.cell_mut((x0, y)) {
+                let existing = cell.symbol().to_string();
+                cell.set_symbol(&format!("{prologue}{existing}"));
+            }
+
+            let epilogue = "\x1b]8;;\x1b\\";
+            if let Some(cell) = buf.cell_mut((x1 - 1, y)) {
+                let existing = cell.symbol().to_string();
+                cell.set_symbol(&format!("{existing}{epilogue}"));
+            }
+        }
     }
 }
 
 impl Widget for Table {
     fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer) {
         let lines = self.render_to_lines(area.width);
+        let hyperlinks = self.hyperlinks.then(|| self.get_links());
 
         // Use Paragraph to render the table lines
         let paragraph = ratatui::widgets::Paragraph::new(ratatui::text::Text::from(lines));
@@ -796,6 +1335,10 @@ impl Widget for Table {
         } else {
             paragraph.render(area, buf);
         }
+
+        if let Some(links) = hyperlinks {
+            Self::apply_hyperlinks(buf, area, 0, &links);
+        }
     }
 }
 
@@ -808,10 +1351,48 @@ pub struct TableState {
 impl StatefulWidget for Table {
     type State = TableState;
 
-    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer, _state: &mut Self::State) {
-        // For now, stateful rendering is the same as stateless
-        // Future enhancements could include row selection highlighting
-        Widget::render(self, area, buf);
+    fn render(self, area: Rect, buf: &mut ratatui::buffer::Buffer, state: &mut Self::State) {
+        let available_width = area.width;
+        let visible_height = area.height as usize;
+        let all_lines = self.render_all_lines_with_selection(available_width, state.selected_row);
+        let total_lines = all_lines.len();
+
+        let line_offset = state
+            .selected_row
+            .and_then(|row| self.data_row_line_range(available_width, row))
+            .map(|(start, end)| {
+                // Scroll just enough to bring the selected row fully into
+                // view, preferring to keep its top edge visible when it's
+                // taller than the available area.
+                let offset = if end <= visible_height {
+                    0
+                } else {
+                    end.saturating_sub(visible_height)
+                };
+                offset
+                    .min(start)
+                    .min(total_lines.saturating_sub(visible_height))
+            })
+            .unwrap_or(0);
+
+        let end_index = if visible_height == 0 {
+            total_lines
+        } else {
+            (line_offset + visible_height).min(total_lines)
+        };
+        let lines = all_lines[line_offset.min(total_lines)..end_index].to_vec();
+        let hyperlinks = self.hyperlinks.then(|| self.get_links());
+
+        let paragraph = ratatui::widgets::Paragraph::new(ratatui::text::Text::from(lines));
+        if let Some(block) = self.block {
+            paragraph.block(block).render(area, buf);
+        } else {
+            paragraph.render(area, buf);
+        }
+
+        if let Some(links) = hyperlinks {
+            Self::apply_hyperlinks(buf, area, line_offset, &links);
+        }
     }
 }
 
@@ -876,6 +1457,61 @@ mod tests {
         assert_eq!(widths[2], 5);
     }
 
+    #[test]
+    fn test_column_width_calculation_mixed_constraints() {
+        let table = Table::new(vec![]).constraints(vec![
+            Constraint::Length(10),
+            Constraint::Percentage(50),
+            Constraint::Min(5),
+        ]);
+
+        // Available width: 40, borders: 1 + 2 + 1 = 4, content: 36
+        // Length(10) -> 10; Percentage(50) -> 18; remaining 8 goes to Min(5)
+        let widths = table.calculate_column_widths(40);
+
+        assert_eq!(widths.len(), 3);
+        assert_eq!(widths[0], 10);
+        assert_eq!(widths[1], 18);
+        assert_eq!(widths[2], 8);
+        assert_eq!(widths.iter().sum::<u16>(), 36);
+    }
+
+    #[test]
+    fn test_column_width_calculation_max_clamps_and_overflows_to_last() {
+        let table = Table::new(vec![]).constraints(vec![
+            Constraint::Max(5),
+            Constraint::Ratio(1, 2),
+            Constraint::Min(0),
+        ]);
+
+        // content: 36; Ratio(1, 2) -> 18; remaining 18 split between
+        // Max(5) and Min(0); Max(5) clamps down, its freed width
+        // lands on the last flexible column (Min(0)).
+        let widths = table.calculate_column_widths(40);
+
+        assert_eq!(widths.len(), 3);
+        assert_eq!(widths[0], 5);
+        assert_eq!(widths[1], 18);
+        assert_eq!(widths[2], 13);
+        assert_eq!(widths.iter().sum::<u16>(), 36);
+    }
+
+    #[test]
+    fn test_column_width_calculation_overflow() {
+        let table = Table::new(vec![])
+            .constraints(vec![Constraint::Length(50), Constraint::Min(10)]);
+
+        // content: available_width(20) - borders(3) = 17; Length(50) is
+        // clamped to the remaining content width, leaving no space to share;
+        // the Min(10) column is still raised to its minimum, so the total
+        // overflows the content width.
+        let widths = table.calculate_column_widths(20);
+
+        assert_eq!(widths.len(), 2);
+        assert_eq!(widths[0], 17);
+        assert_eq!(widths[1], 10);
+    }
+
     #[test]
     fn test_render_to_lines() {
         let rows = vec![
@@ -0,0 +1,166 @@
+use crate::markdown::{Block, Document, Node};
+use std::collections::HashMap;
+
+/// One heading's location in the book's cross-chapter outline. A chapter's
+/// actual scroll offset for a heading isn't known until that chapter is
+/// rendered (line wrapping depends on terminal width), so - like internal
+/// links elsewhere in the reader - entries carry the chapter index plus an
+/// anchor id, resolved to a line via `get_anchor_position`/
+/// `store_pending_anchor_scroll` once the target chapter is loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadingOutlineEntry {
+    pub level: u8,
+    pub text: String,
+    /// Hierarchical section number ("1", "1.2", "1.2.3", ...). A level with
+    /// no heading seen yet (e.g. the book opens at h3 with no h1/h2) counts
+    /// as zero rather than being omitted, so "1.2.3" always has as many
+    /// components as the heading's own level.
+    pub number: String,
+    pub chapter: usize,
+    pub anchor_id: String,
+}
+
+/// Assigns hierarchical section numbers to a flat stream of headings in
+/// document order. Counters persist across `push` calls regardless of
+/// chapter boundaries, so numbering stays continuous across the whole book
+/// rather than restarting every chapter.
+#[derive(Debug, Default)]
+pub struct HeadingOutlineBuilder {
+    counters: [u32; 6],
+}
+
+impl HeadingOutlineBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers one heading at `level` (1-6, out-of-range values clamped)
+    /// and returns its section number.
+    pub fn push(&mut self, level: u8) -> String {
+        let idx = (level.clamp(1, 6) - 1) as usize;
+        self.counters[idx] += 1;
+        for counter in &mut self.counters[idx + 1..] {
+            *counter = 0;
+        }
+        self.counters[..=idx]
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(".")
+    }
+}
+
+/// Slugifies `text` the same way the live renderer derives a heading anchor
+/// (lowercase, keep alphanumerics/spaces/hyphens, join words with `-`), then
+/// disambiguates it against every slug handed out so far via `seen` by
+/// appending `-1`, `-2`, ... on collision.
+fn unique_anchor_id(text: &str, seen: &mut HashMap<String, u32>) -> String {
+    let base = text
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace() || *c == '-')
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-");
+    match seen.get_mut(&base) {
+        None => {
+            seen.insert(base.clone(), 0);
+            base
+        }
+        Some(count) => {
+            *count += 1;
+            format!("{base}-{count}")
+        }
+    }
+}
+
+/// Walks one chapter's parsed `Document` in document order, recursing into
+/// blocks that nest child nodes (quotes, epub blocks, footnote
+/// definitions, list items), numbering each heading found via `builder`
+/// and appending it to `out`. Anchor ids are scoped to this one chapter,
+/// matching how the live renderer resets its own slug table per chapter.
+pub fn collect_chapter_headings(
+    doc: &Document,
+    chapter: usize,
+    builder: &mut HeadingOutlineBuilder,
+    out: &mut Vec<HeadingOutlineEntry>,
+) {
+    let mut seen_anchors = HashMap::new();
+    for node in &doc.blocks {
+        collect_from_node(node, chapter, builder, &mut seen_anchors, out);
+    }
+}
+
+fn collect_from_node(
+    node: &Node,
+    chapter: usize,
+    builder: &mut HeadingOutlineBuilder,
+    seen_anchors: &mut HashMap<String, u32>,
+    out: &mut Vec<HeadingOutlineEntry>,
+) {
+    match &node.block {
+        Block::Heading { level, content } => {
+            let text = content.to_plain_string();
+            let number = builder.push(level.as_u8());
+            let anchor_id = unique_anchor_id(&text, seen_anchors);
+            out.push(HeadingOutlineEntry {
+                level: level.as_u8(),
+                text,
+                number,
+                chapter,
+                anchor_id,
+            });
+        }
+        Block::Quote { content }
+        | Block::FootnoteDefinition { content, .. }
+        | Block::EpubBlock { content, .. } => {
+            for child in content {
+                collect_from_node(child, chapter, builder, seen_anchors, out);
+            }
+        }
+        Block::List { items, .. } => {
+            for item in items {
+                for child in &item.content {
+                    collect_from_node(child, chapter, builder, seen_anchors, out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_siblings_at_the_same_level_in_sequence() {
+        let mut builder = HeadingOutlineBuilder::new();
+        assert_eq!(builder.push(1), "1");
+        assert_eq!(builder.push(2), "1.1");
+        assert_eq!(builder.push(2), "1.2");
+        assert_eq!(builder.push(1), "2");
+    }
+
+    #[test]
+    fn missing_ancestors_count_as_zero() {
+        let mut builder = HeadingOutlineBuilder::new();
+        // Document starts at h3 with no h1/h2 at all yet.
+        assert_eq!(builder.push(3), "0.0.1");
+        assert_eq!(builder.push(3), "0.0.2");
+        // Once an h1 shows up, deeper counters reset under it.
+        assert_eq!(builder.push(1), "1");
+        assert_eq!(builder.push(3), "1.0.1");
+    }
+
+    #[test]
+    fn numbering_stays_continuous_across_chapter_boundaries() {
+        let mut builder = HeadingOutlineBuilder::new();
+        assert_eq!(builder.push(1), "1");
+        assert_eq!(builder.push(2), "1.1");
+        // A later chapter's first heading keeps counting from where the
+        // previous chapter left off, rather than restarting at "1".
+        assert_eq!(builder.push(1), "2");
+    }
+}
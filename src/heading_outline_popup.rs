@@ -0,0 +1,125 @@
+use crate::heading_outline::HeadingOutlineEntry;
+use crate::theme::Base16Palette;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState},
+};
+
+/// What `App` should do after a heading-outline popup key event.
+pub enum HeadingOutlineAction {
+    JumpTo { chapter: usize, anchor_id: String },
+    Close,
+}
+
+/// Lists the book's cross-chapter heading outline (see
+/// `heading_outline::collect_chapter_headings`) so the user can jump
+/// straight to any h1-h6, the way a PDF reader's bookmark pane works.
+pub struct HeadingOutlinePopup {
+    entries: Vec<HeadingOutlineEntry>,
+    state: ListState,
+}
+
+impl HeadingOutlinePopup {
+    pub fn new(entries: Vec<HeadingOutlineEntry>) -> Self {
+        let mut state = ListState::default();
+        if !entries.is_empty() {
+            state.select(Some(0));
+        }
+        Self { entries, state }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.entries.is_empty() {
+            return;
+        }
+        let len = self.entries.len() as i32;
+        let current = self.state.selected().unwrap_or(0) as i32;
+        self.state
+            .select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<HeadingOutlineAction> {
+        match key.code {
+            KeyCode::Esc => Some(HeadingOutlineAction::Close),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                None
+            }
+            KeyCode::Enter => self
+                .state
+                .selected()
+                .and_then(|i| self.entries.get(i))
+                .map(|entry| HeadingOutlineAction::JumpTo {
+                    chapter: entry.chapter,
+                    anchor_id: entry.anchor_id.clone(),
+                }),
+            _ => None,
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, palette: &Base16Palette) {
+        let popup_area = centered_rect(60, 80, area);
+        f.render_widget(Clear, popup_area);
+
+        let items: Vec<ListItem> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let indent = "  ".repeat((entry.level as usize).saturating_sub(1));
+                ListItem::new(Line::from(vec![
+                    Span::raw(indent),
+                    Span::styled(
+                        format!("{} ", entry.number),
+                        Style::default().fg(palette.base_03),
+                    ),
+                    Span::styled(&entry.text, Style::default().fg(palette.base_05)),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(" Heading Outline ")
+                    .borders(Borders::ALL)
+                    .style(Style::default().bg(palette.base_00).fg(palette.base_05)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(palette.base_02)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("» ");
+
+        f.render_stateful_widget(list, popup_area, &mut self.state);
+    }
+}
+
+/// Centers a `percent_x` x `percent_y` rect within `r`.
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
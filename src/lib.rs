@@ -1,14 +1,20 @@
 // Export modules for use in tests
 pub mod book_manager;
+pub mod bookmark;
 pub mod bookmarks;
+pub mod clipboard;
+pub mod command_palette;
 pub mod comments;
 pub use inputs::event_source;
 pub mod components;
+pub mod heading_outline;
+pub mod heading_outline_popup;
 pub mod images;
 pub mod inputs;
 pub mod jump_list;
 pub mod main_app;
 pub mod markdown;
+pub mod named_bookmarks_popup;
 pub mod widget;
 pub use components::mathml_renderer;
 pub use widget::book_search;
@@ -18,7 +24,10 @@ pub use widget::navigation_panel::{book_list, table_of_contents};
 pub use widget::reading_history;
 pub use widget::text_reader as markdown_text_reader;
 pub mod panic_handler;
+#[cfg(feature = "pdf")]
+pub mod pdf;
 pub mod parsing;
+pub mod scheduler;
 pub mod search;
 pub mod search_engine;
 pub mod system_command;
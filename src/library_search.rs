@@ -0,0 +1,316 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Small stop-word set dropped before indexing/querying, so scores reflect
+/// the distinctive words in a query rather than ubiquitous function words.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in",
+    "into", "is", "it", "no", "not", "of", "on", "or", "such", "that", "the",
+    "their", "then", "there", "these", "they", "this", "to", "was", "will",
+    "with",
+];
+
+/// One line of a chapter to index, tagged with the scroll offset that
+/// would bring it into view - the same line-index addressing the reader's
+/// own `scroll_to_line` uses, so a result can be jumped to directly.
+pub struct IndexedLine<'a> {
+    pub scroll_offset: usize,
+    pub text: &'a str,
+}
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.unicode_words()
+        .map(|w| w.to_lowercase())
+        .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Posting {
+    doc_id: usize,
+    term_freq: u32,
+    scroll_offset: usize,
+    snippet: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedDoc {
+    book_path: String,
+    chapter: usize,
+    /// Set to `false` by `begin_book` when a book is about to be
+    /// re-indexed. Stale docs are left in place rather than compacted out
+    /// (which would mean shifting every later doc's id) and are simply
+    /// excluded from `N` and from search results.
+    valid: bool,
+}
+
+/// Inverted index over every book's chapter text: term -> postings of
+/// `(book_path, chapter, scroll_offset, snippet)`, scored with TF-IDF at
+/// query time. Indexing is meant to happen lazily, one chapter at a time,
+/// as the reader actually visits it (see `begin_book`/`index_chapter`),
+/// and the whole index can be persisted (`save_to_file`/`load_from_file`)
+/// and checked against each book's on-disk mtime (`needs_reindex`) so
+/// reopening the app doesn't re-parse unchanged EPUBs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LibrarySearchIndex {
+    docs: Vec<IndexedDoc>,
+    postings: HashMap<String, Vec<Posting>>,
+    book_mtimes: HashMap<String, u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub book_path: String,
+    pub chapter: usize,
+    pub scroll_offset: usize,
+    pub snippet: String,
+    pub score: f64,
+}
+
+impl LibrarySearchIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn load_or_default(file_path: &str) -> Self {
+        Self::load_from_file(file_path).unwrap_or_else(|e| {
+            log::error!("Failed to load search index from {}: {}", file_path, e);
+            Self::new()
+        })
+    }
+
+    pub fn load_from_file(file_path: &str) -> anyhow::Result<Self> {
+        let path = Path::new(file_path);
+        if path.exists() {
+            let content = fs::read_to_string(path)?;
+            Ok(serde_json::from_str(&content)?)
+        } else {
+            Ok(Self::new())
+        }
+    }
+
+    pub fn save_to_file(&self, file_path: &str) -> anyhow::Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(file_path, content)?;
+        Ok(())
+    }
+
+    /// Whether `book_path`'s on-disk mtime no longer matches what this
+    /// index was last built from (or the book isn't indexed at all).
+    pub fn needs_reindex(&self, book_path: &str, mtime: u64) -> bool {
+        self.book_mtimes.get(book_path) != Some(&mtime)
+    }
+
+    /// Marks every doc previously indexed for `book_path` as stale and
+    /// records its new mtime. Call once per book before re-`index_chapter`-ing
+    /// it; the old postings stop contributing to `N`/search results from
+    /// this call on.
+    pub fn begin_book(&mut self, book_path: &str, mtime: u64) {
+        for doc in &mut self.docs {
+            if doc.book_path == book_path {
+                doc.valid = false;
+            }
+        }
+        self.book_mtimes.insert(book_path.to_string(), mtime);
+    }
+
+    /// Indexes one chapter's lines for `book_path`. Call `begin_book` once
+    /// per book first so any previous indexing of it is invalidated.
+    pub fn index_chapter(&mut self, book_path: &str, chapter: usize, lines: &[IndexedLine]) {
+        let doc_id = self.docs.len();
+        self.docs.push(IndexedDoc {
+            book_path: book_path.to_string(),
+            chapter,
+            valid: true,
+        });
+
+        // term -> (frequency in this chapter, scroll offset of its first
+        // occurrence, snippet of the line it first occurred on).
+        let mut term_stats: HashMap<String, (u32, usize, String)> = HashMap::new();
+        for line in lines {
+            for term in tokenize(line.text) {
+                term_stats
+                    .entry(term)
+                    .and_modify(|(freq, _, _)| *freq += 1)
+                    .or_insert_with(|| (1, line.scroll_offset, truncate_snippet(line.text)));
+            }
+        }
+
+        for (term, (term_freq, scroll_offset, snippet)) in term_stats {
+            self.postings.entry(term).or_default().push(Posting {
+                doc_id,
+                term_freq,
+                scroll_offset,
+                snippet,
+            });
+        }
+    }
+
+    /// Scores every indexed chapter against `query`'s terms with
+    /// `tf * ln(N / df)` summed over query terms (`N` = chapters currently
+    /// valid), and returns the top `limit` by score, highest first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let query_terms: Vec<String> = tokenize(query).collect();
+        let valid_doc_count = self.docs.iter().filter(|d| d.valid).count();
+        if query_terms.is_empty() || valid_doc_count == 0 {
+            return Vec::new();
+        }
+        let n = valid_doc_count as f64;
+
+        struct Accum {
+            total_score: f64,
+            best_term_score: f64,
+            scroll_offset: usize,
+            snippet: String,
+        }
+
+        let mut scores: HashMap<usize, Accum> = HashMap::new();
+        for term in &query_terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+            let valid_postings: Vec<&Posting> = postings
+                .iter()
+                .filter(|p| self.docs[p.doc_id].valid)
+                .collect();
+            let df = valid_postings.len() as f64;
+            if df == 0.0 {
+                continue;
+            }
+            let idf = (n / df).ln();
+            for posting in valid_postings {
+                let term_score = posting.term_freq as f64 * idf;
+                scores
+                    .entry(posting.doc_id)
+                    .and_modify(|acc| {
+                        acc.total_score += term_score;
+                        if term_score > acc.best_term_score {
+                            acc.best_term_score = term_score;
+                            acc.scroll_offset = posting.scroll_offset;
+                            acc.snippet = posting.snippet.clone();
+                        }
+                    })
+                    .or_insert(Accum {
+                        total_score: term_score,
+                        best_term_score: term_score,
+                        scroll_offset: posting.scroll_offset,
+                        snippet: posting.snippet.clone(),
+                    });
+            }
+        }
+
+        let mut results: Vec<SearchResult> = scores
+            .into_iter()
+            .map(|(doc_id, acc)| {
+                let doc = &self.docs[doc_id];
+                SearchResult {
+                    book_path: doc.book_path.clone(),
+                    chapter: doc.chapter,
+                    scroll_offset: acc.scroll_offset,
+                    snippet: acc.snippet,
+                    score: acc.total_score,
+                }
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(limit);
+        results
+    }
+}
+
+fn truncate_snippet(text: &str) -> String {
+    const MAX_CHARS: usize = 160;
+    let trimmed = text.trim();
+    if trimmed.chars().count() <= MAX_CHARS {
+        trimmed.to_string()
+    } else {
+        let truncated: String = trimmed.chars().take(MAX_CHARS).collect();
+        format!("{truncated}\u{2026}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(texts: &[&str]) -> Vec<(usize, String)> {
+        texts
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (i, t.to_string()))
+            .collect()
+    }
+
+    fn index_book(idx: &mut LibrarySearchIndex, path: &str, mtime: u64, chapters: &[Vec<&str>]) {
+        idx.begin_book(path, mtime);
+        for (chapter, texts) in chapters.iter().enumerate() {
+            let owned = lines(texts);
+            let indexed: Vec<IndexedLine> = owned
+                .iter()
+                .map(|(offset, text)| IndexedLine {
+                    scroll_offset: *offset,
+                    text,
+                })
+                .collect();
+            idx.index_chapter(path, chapter, &indexed);
+        }
+    }
+
+    #[test]
+    fn finds_the_chapter_containing_the_query_term() {
+        let mut idx = LibrarySearchIndex::new();
+        index_book(
+            &mut idx,
+            "moby-dick.epub",
+            1,
+            &[vec!["Call me Ishmael.", "The whale was enormous."]],
+        );
+
+        let results = idx.search("whale", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].book_path, "moby-dick.epub");
+        assert_eq!(results[0].chapter, 0);
+        assert_eq!(results[0].scroll_offset, 1);
+    }
+
+    #[test]
+    fn ranks_a_rarer_term_higher_than_a_common_one_across_the_library() {
+        let mut idx = LibrarySearchIndex::new();
+        index_book(&mut idx, "a.epub", 1, &[vec!["dragons and castles"]]);
+        index_book(&mut idx, "b.epub", 1, &[vec!["castles and moats"]]);
+        index_book(&mut idx, "c.epub", 1, &[vec!["more castles here"]]);
+
+        // "dragons" appears in only one of three chapters (higher idf) than
+        // "castles", which is in all three (idf = ln(3/3) = 0).
+        let results = idx.search("dragons", 10);
+        assert_eq!(results.len(), 1);
+        assert!(results[0].score > 0.0);
+
+        let results = idx.search("castles", 10);
+        assert!(results.iter().all(|r| r.score == 0.0));
+    }
+
+    #[test]
+    fn reindexing_a_book_drops_its_stale_postings() {
+        let mut idx = LibrarySearchIndex::new();
+        index_book(&mut idx, "book.epub", 1, &[vec!["a story about pirates"]]);
+        assert_eq!(idx.search("pirates", 10).len(), 1);
+
+        assert!(!idx.needs_reindex("book.epub", 1));
+        assert!(idx.needs_reindex("book.epub", 2));
+
+        index_book(&mut idx, "book.epub", 2, &[vec!["a story about wizards"]]);
+        assert!(idx.search("pirates", 10).is_empty());
+        assert_eq!(idx.search("wizards", 10).len(), 1);
+    }
+
+    #[test]
+    fn stop_words_are_not_indexed() {
+        let mut idx = LibrarySearchIndex::new();
+        index_book(&mut idx, "book.epub", 1, &[vec!["the and of"]]);
+        assert!(idx.search("the", 10).is_empty());
+    }
+}
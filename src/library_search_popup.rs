@@ -0,0 +1,181 @@
+use crate::library_search::SearchResult;
+use crate::theme::Base16Palette;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// What `App` should do after a library-search popup key event.
+pub enum LibrarySearchAction {
+    /// Re-run the search for the popup's current query against
+    /// `LibrarySearchIndex::search` and feed the results back via
+    /// `set_results` (the popup has no index access of its own).
+    QueryChanged(String),
+    JumpTo {
+        book_path: String,
+        chapter: usize,
+        scroll_offset: usize,
+    },
+    Close,
+}
+
+/// Typed-query popup over the whole library's search index, styled like
+/// `command_palette::CommandPalette`: a query line plus a live-updated,
+/// ranked list of results with snippets.
+pub struct LibrarySearchPopup {
+    query: String,
+    results: Vec<SearchResult>,
+    state: ListState,
+}
+
+impl LibrarySearchPopup {
+    pub fn new() -> Self {
+        Self {
+            query: String::new(),
+            results: Vec::new(),
+            state: ListState::default(),
+        }
+    }
+
+    /// Replaces the currently displayed results (called by `App` after it
+    /// re-runs `LibrarySearchIndex::search` for the latest query).
+    pub fn set_results(&mut self, results: Vec<SearchResult>) {
+        self.results = results;
+        if self.results.is_empty() {
+            self.state.select(None);
+        } else {
+            self.state.select(Some(0));
+        }
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.results.is_empty() {
+            return;
+        }
+        let len = self.results.len() as i32;
+        let current = self.state.selected().unwrap_or(0) as i32;
+        self.state
+            .select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<LibrarySearchAction> {
+        match key.code {
+            KeyCode::Esc => Some(LibrarySearchAction::Close),
+            KeyCode::Down => {
+                self.move_selection(1);
+                None
+            }
+            KeyCode::Up => {
+                self.move_selection(-1);
+                None
+            }
+            KeyCode::Enter => self
+                .state
+                .selected()
+                .and_then(|i| self.results.get(i))
+                .map(|result| LibrarySearchAction::JumpTo {
+                    book_path: result.book_path.clone(),
+                    chapter: result.chapter,
+                    scroll_offset: result.scroll_offset,
+                }),
+            KeyCode::Backspace => {
+                self.query.pop();
+                Some(LibrarySearchAction::QueryChanged(self.query.clone()))
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                Some(LibrarySearchAction::QueryChanged(self.query.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, palette: &Base16Palette) {
+        let popup_area = centered_rect(70, 80, area);
+        f.render_widget(Clear, popup_area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(popup_area);
+
+        let query_line = Paragraph::new(format!("> {}", self.query)).block(
+            Block::default()
+                .title(" Search Library ")
+                .borders(Borders::ALL)
+                .style(Style::default().bg(palette.base_00).fg(palette.base_05)),
+        );
+        f.render_widget(query_line, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .results
+            .iter()
+            .map(|result| {
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled(
+                            result.book_path.clone(),
+                            Style::default()
+                                .fg(palette.base_05)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(" [ch. {}]", result.chapter + 1),
+                            Style::default().fg(palette.base_03),
+                        ),
+                    ]),
+                    Line::from(Span::styled(
+                        result.snippet.clone(),
+                        Style::default().fg(palette.base_04),
+                    )),
+                ])
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .style(Style::default().bg(palette.base_00).fg(palette.base_05)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(palette.base_02)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("» ");
+
+        f.render_stateful_widget(list, chunks[1], &mut self.state);
+    }
+}
+
+impl Default for LibrarySearchPopup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Centers a `percent_x` x `percent_y` rect within `r`.
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
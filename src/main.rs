@@ -10,20 +10,30 @@ use log::{error, info};
 use ratatui::{Terminal, backend::CrosstermBackend};
 use simplelog::{ConfigBuilder, LevelFilter, WriteLogger};
 
+mod article_import;
 mod book_list;
 mod book_manager;
 mod book_stat;
 mod bookmark;
+mod clipboard;
+mod command_palette;
 mod event_source;
+mod heading_outline;
+mod heading_outline_popup;
 mod images;
+mod library_search;
+mod library_search_popup;
 mod main_app;
 mod markdown;
 mod markdown_text_reader;
+mod marks;
 mod mathml_renderer;
+mod named_bookmarks_popup;
 mod navigation_panel;
 mod panic_handler;
 mod parsing;
 mod reading_history;
+mod scheduler;
 mod simple_fake_books;
 mod system_command;
 mod table;
@@ -32,7 +42,9 @@ mod text_reader;
 mod text_reader_trait;
 mod text_selection;
 mod theme;
+mod toc;
 mod toc_parser;
+mod url_import_popup;
 
 #[cfg(test)]
 mod test_utils;
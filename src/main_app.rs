@@ -1,25 +1,36 @@
 use crate::book_manager::BookManager;
 use crate::book_search::{BookSearch, BookSearchAction};
 use crate::book_stat::{BookStat, BookStatAction};
+use crate::bookmark::Bookmarks as BookmarkStore;
 use crate::bookmarks::Bookmarks;
+use crate::clipboard::{ClipboardProvider, SystemClipboard};
+use crate::command_palette::{CommandId, CommandPalette, CommandPaletteAction};
 use crate::comments::BookComments;
 use crate::event_source::EventSource;
+use crate::heading_outline::{HeadingOutlineBuilder, collect_chapter_headings};
+use crate::heading_outline_popup::{HeadingOutlineAction, HeadingOutlinePopup};
 use crate::images::book_images::BookImages;
 use crate::images::image_popup::ImagePopup;
 use crate::images::image_storage::ImageStorage;
 use crate::inputs::{ClickType, KeySeq, MouseTracker, map_keys_to_input};
 use crate::jump_list::{JumpList, JumpLocation};
 use crate::markdown_text_reader::MarkdownTextReader;
+use crate::named_bookmarks_popup::{
+    NamedBookmarkLabelAction, NamedBookmarkLabelPopup, NamedBookmarkLabelTarget,
+    NamedBookmarksAction, NamedBookmarksPopup,
+};
 use crate::navigation_panel::{CurrentBookInfo, NavigationPanel};
 use crate::parsing::text_generator::TextGenerator;
 use crate::parsing::toc_parser::TocParser;
-use crate::reading_history::ReadingHistory;
+use crate::reading_history::{ReadingHistory, ReadingHistorySortBy, ReadingHistorySortDirection};
+use crate::scheduler::{ScheduledEvent, ScheduledEventKind, Scheduler};
 use crate::search::{SearchMode, SearchablePanel};
 use crate::search_engine::SearchEngine;
 use crate::system_command::{RealSystemCommandExecutor, SystemCommandExecutor};
 use crate::table_of_contents::TocItem;
-use crate::theme::OCEANIC_NEXT;
+use crate::text_reader_trait::{ExportFormat, TextReaderTrait};
 use crate::types::LinkInfo;
+use crate::url_import_popup::{UrlImportAction, UrlImportPopup};
 use image::GenericImageView;
 use log::warn;
 
@@ -41,7 +52,7 @@ use ratatui::{
     Terminal,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Paragraph, Tabs},
 };
 
 struct EpubBook {
@@ -62,6 +73,21 @@ impl EpubBook {
     }
 }
 
+/// One open book in the tab bar (`gt`/`gT` to cycle, `Space x` to close, `t`
+/// in the navigation panel to open a new one). Only the currently active
+/// tab's book is actually loaded into `current_book`/`text_reader`; the
+/// rest just remember the chapter and scroll offset to resume at when
+/// switched back to - a lighter weight per-tab state than a full second
+/// `MarkdownTextReader`, since tabs are about cross-referencing position
+/// across books, not independent selections/vi-cursors in the background.
+#[derive(Debug, Clone)]
+struct ReadingTab {
+    path: String,
+    display_name: String,
+    chapter_index: usize,
+    scroll_offset: usize,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AppAction {
     Quit,
@@ -80,12 +106,106 @@ pub struct App {
     mouse_tracker: MouseTracker,
     key_sequence: KeySeq,
     reading_history: Option<ReadingHistory>,
+    /// Sort mode/direction remembered across closing and reopening the
+    /// reading-history popup (`ReadingHistory` itself only holds the items
+    /// for the currently-open popup instance).
+    reading_history_sort_by: ReadingHistorySortBy,
+    reading_history_sort_direction: ReadingHistorySortDirection,
     image_popup: Option<ImagePopup>,
     terminal_size: Rect,
     profiler: Arc<Mutex<Option<pprof::ProfilerGuard<'static>>>>,
     book_stat: BookStat,
     jump_list: JumpList,
     book_search: Option<BookSearch>,
+    /// Set while `n`/`N` is waiting on a background search scan of a
+    /// lazily-loaded adjacent chapter. `land_last` says which end of that
+    /// chapter's matches to land on once they arrive (the end nearest the
+    /// side we entered the chapter from: the first match when hopping
+    /// forward, the last when hopping backward); `hops_left` bounds how
+    /// many more empty chapters we'll hop through before giving up, so a
+    /// query with no matches anywhere doesn't spin through the whole book.
+    pending_search_landing: Option<PendingSearchLanding>,
+
+    /// Timer queue driving frame-rate-independent timed events (continuous
+    /// auto-scroll today). Polled once per main-loop iteration, independent
+    /// of whether that iteration redraws. See [`Scheduler`].
+    scheduler: Scheduler,
+
+    /// OS clipboard backend, swappable for a `MockClipboard` in tests so
+    /// copy actions can be asserted on without touching the real system
+    /// clipboard. See [`ClipboardProvider`].
+    clipboard: Box<dyn ClipboardProvider>,
+
+    /// Carries the fractional remainder between scroll events so a run of
+    /// sub-cell deltas advances the viewport smoothly. See
+    /// [`AccumulatedScroll`].
+    accumulated_scroll: AccumulatedScroll,
+
+    /// Active command palette overlay (`:` or `Ctrl-P`), if open. See
+    /// [`CommandPalette`].
+    command_palette: Option<CommandPalette>,
+
+    /// Active "import article from URL" overlay, if open. See
+    /// [`UrlImportPopup`].
+    url_import_popup: Option<UrlImportPopup>,
+
+    /// Named-bookmark store for the currently open library, separate from
+    /// `bookmarks` (the implicit last-read-position tracker). See
+    /// [`BookmarkStore`].
+    bookmark_store: BookmarkStore,
+
+    /// Active named-bookmarks listing overlay (`B`), if open. See
+    /// [`NamedBookmarksPopup`].
+    named_bookmarks_popup: Option<NamedBookmarksPopup>,
+
+    /// Active label-entry overlay for adding (`b`) or renaming (`r` inside
+    /// the listing overlay) a named bookmark, if open. See
+    /// [`NamedBookmarkLabelPopup`].
+    named_bookmark_label_popup: Option<NamedBookmarkLabelPopup>,
+
+    /// Active cross-chapter heading outline overlay (`H`), if open. See
+    /// [`HeadingOutlinePopup`].
+    heading_outline_popup: Option<HeadingOutlinePopup>,
+
+    /// Open books in the tab bar, in tab order. The active tab's book and
+    /// content live in `current_book`/`text_reader` as usual; the rest only
+    /// keep enough to resume where they were. See [`ReadingTab`].
+    tabs: Vec<ReadingTab>,
+
+    /// Index into `tabs` of the tab currently shown in `current_book`/
+    /// `text_reader`, or `None` before any book has been opened.
+    active_tab: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PendingSearchLanding {
+    land_last: bool,
+    hops_left: usize,
+}
+
+/// Fractional-cell scroll accumulator, so a scroll source that reports
+/// sub-cell deltas (a high-resolution trackpad) advances the viewport
+/// smoothly instead of snapping a full cell per event. `x` is tracked for
+/// symmetry with `y` but currently has no consumer: content always wraps
+/// to the terminal width rather than scrolling horizontally, so
+/// `ScrollLeft`/`ScrollRight` are still ignored upstream. See
+/// [`AccumulatedScroll::accumulate_y`].
+#[derive(Debug, Clone, Copy, Default)]
+struct AccumulatedScroll {
+    x: f64,
+    y: f64,
+}
+
+impl AccumulatedScroll {
+    /// Adds `delta` fractional cells to the vertical accumulator and
+    /// returns the whole number of cells that have now accumulated,
+    /// leaving the remainder for the next call.
+    fn accumulate_y(&mut self, delta: f64) -> i32 {
+        self.y += delta;
+        let whole = self.y.trunc();
+        self.y -= whole;
+        whole as i32
+    }
 }
 
 pub trait VimNavMotions {
@@ -117,6 +237,11 @@ pub enum PopupWindow {
     BookStats,
     ImagePopup,
     BookSearch,
+    CommandPalette,
+    UrlImport,
+    NamedBookmarks,
+    NamedBookmarkLabel,
+    HeadingOutline,
 }
 
 impl Default for App {
@@ -204,6 +329,20 @@ impl App {
         matches!(self.focused_panel, FocusedPanel::Popup(_))
     }
 
+    /// The currently selected text in the content panel, if any.
+    pub fn get_selected_text(&self) -> Option<String> {
+        self.text_reader.get_selected_text()
+    }
+
+    /// Copies the current selection to the clipboard via the injected
+    /// `ClipboardProvider` (the real OS clipboard outside of tests), so
+    /// `SimulatedEventSource`-driven tests can assert on it through a
+    /// `MockClipboard` instead of touching the system clipboard.
+    fn copy_selected_text_to_clipboard(&mut self) -> Result<(), String> {
+        let selected_text = self.get_selected_text().ok_or("No text selected")?;
+        self.clipboard.set_text(selected_text)
+    }
+
     #[cfg(any(test, feature = "test-utils"))]
     pub fn new_with_mock_system_executor(
         book_directory: Option<&str>,
@@ -219,6 +358,11 @@ impl App {
         )
     }
 
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn set_clipboard_provider(&mut self, clipboard: Box<dyn ClipboardProvider>) {
+        self.clipboard = clipboard;
+    }
+
     pub fn new_with_config(
         book_directory: Option<&str>,
         bookmark_file: Option<&str>,
@@ -246,6 +390,7 @@ impl App {
         let navigation_panel = NavigationPanel::new(&book_manager);
         let text_reader = MarkdownTextReader::new();
         let bookmarks = Bookmarks::load_or_ephemeral(bookmark_file);
+        let bookmark_store = BookmarkStore::load_or_ephemeral(bookmark_file);
 
         let image_storage = Arc::new(ImageStorage::new_in_project_temp().unwrap_or_else(|e| {
             error!("Failed to initialize image storage: {e}. Using fallback.");
@@ -275,12 +420,26 @@ impl App {
             mouse_tracker: MouseTracker::new(),
             key_sequence: KeySeq::new(),
             reading_history: None,
+            reading_history_sort_by: ReadingHistorySortBy::LastRead,
+            reading_history_sort_direction: ReadingHistorySortDirection::Descending,
             image_popup: None,
             terminal_size,
             profiler: Arc::new(Mutex::new(None)),
             book_stat: BookStat::new(),
             jump_list: JumpList::new(20),
             book_search: None,
+            pending_search_landing: None,
+            scheduler: Scheduler::new(),
+            clipboard: Box::new(SystemClipboard),
+            accumulated_scroll: AccumulatedScroll::default(),
+            command_palette: None,
+            url_import_popup: None,
+            bookmark_store,
+            named_bookmarks_popup: None,
+            named_bookmark_label_popup: None,
+            heading_outline_popup: None,
+            tabs: Vec::new(),
+            active_tab: None,
         };
 
         if auto_load_recent
@@ -345,9 +504,132 @@ impl App {
             .position(|book| book.path == path)
             .ok_or_else(|| anyhow::anyhow!("Book not found in manager: {}", path))?;
 
+        self.open_book_in_tab(book_index)
+    }
+
+    /// Opens `book_index` in the tab bar: switches to its tab if it's
+    /// already open, restoring the chapter/scroll offset it was left at, or
+    /// creates a new tab for it otherwise. This is the normal way of opening
+    /// a book now that `App` can have several open at once; see
+    /// `open_book_in_new_tab` for always forcing a fresh tab.
+    fn open_book_in_tab(&mut self, book_index: usize) -> Result<()> {
+        let path = self
+            .book_manager
+            .get_book_info(book_index)
+            .ok_or_else(|| anyhow::anyhow!("Invalid book index: {}", book_index))?
+            .path
+            .clone();
+
+        match self.tabs.iter().position(|tab| tab.path == path) {
+            Some(existing) => self.switch_to_tab(existing),
+            None => self.open_book_in_new_tab(book_index),
+        }
+    }
+
+    /// Opens `book_index` in a brand new tab, even if it's already open in
+    /// another one - for the explicit "open in a new tab" keybinding, as
+    /// opposed to `open_book_in_tab`'s create-or-switch.
+    fn open_book_in_new_tab(&mut self, book_index: usize) -> Result<()> {
+        self.snapshot_active_tab();
+        let book_info = self
+            .book_manager
+            .get_book_info(book_index)
+            .ok_or_else(|| anyhow::anyhow!("Invalid book index: {}", book_index))?;
+        self.tabs.push(ReadingTab {
+            path: book_info.path.clone(),
+            display_name: book_info.display_name.clone(),
+            chapter_index: 0,
+            scroll_offset: 0,
+        });
+        self.active_tab = Some(self.tabs.len() - 1);
         self.open_book_for_reading(book_index)
     }
 
+    /// Switches to an already-open tab, restoring the chapter and scroll
+    /// offset it was left at.
+    fn switch_to_tab(&mut self, index: usize) -> Result<()> {
+        self.snapshot_active_tab();
+        let Some(tab) = self.tabs.get(index).cloned() else {
+            anyhow::bail!("Invalid tab index: {}", index);
+        };
+        let book_index = self
+            .book_manager
+            .books
+            .iter()
+            .position(|book| book.path == tab.path)
+            .ok_or_else(|| anyhow::anyhow!("Book no longer available: {}", tab.path))?;
+
+        self.active_tab = Some(index);
+        self.open_book_for_reading(book_index)?;
+        if self
+            .current_book
+            .as_ref()
+            .is_some_and(|book| book.current_chapter() != tab.chapter_index)
+        {
+            self.navigate_to_chapter(tab.chapter_index)?;
+        }
+        self.text_reader.restore_scroll_position(tab.scroll_offset);
+        Ok(())
+    }
+
+    /// Saves the active tab's current chapter and scroll offset back into
+    /// `self.tabs`, so switching away from it doesn't lose its position.
+    fn snapshot_active_tab(&mut self) {
+        if let (Some(active), Some(book)) = (self.active_tab, &self.current_book) {
+            let chapter_index = book.current_chapter();
+            let scroll_offset = self.text_reader.get_scroll_offset();
+            if let Some(tab) = self.tabs.get_mut(active) {
+                tab.chapter_index = chapter_index;
+                tab.scroll_offset = scroll_offset;
+            }
+        }
+    }
+
+    /// Cycles to the next tab (`gt`), wrapping past the last one.
+    fn next_tab(&mut self) {
+        if self.tabs.len() < 2 {
+            return;
+        }
+        let next = self.active_tab.map(|i| (i + 1) % self.tabs.len()).unwrap_or(0);
+        if let Err(e) = self.switch_to_tab(next) {
+            error!("Failed to switch to next tab: {e}");
+        }
+    }
+
+    /// Cycles to the previous tab (`gT`), wrapping past the first one.
+    fn previous_tab(&mut self) {
+        if self.tabs.len() < 2 {
+            return;
+        }
+        let len = self.tabs.len();
+        let previous = self.active_tab.map(|i| (i + len - 1) % len).unwrap_or(0);
+        if let Err(e) = self.switch_to_tab(previous) {
+            error!("Failed to switch to previous tab: {e}");
+        }
+    }
+
+    /// Closes the active tab (`Space x`). If others remain, switches to the
+    /// one that took its place in the list (the tab to its right, or the
+    /// new last tab if it was rightmost); if it was the only tab, leaves the
+    /// reader showing that book, same as before tabs existed.
+    fn close_current_tab(&mut self) {
+        let Some(active) = self.active_tab else {
+            return;
+        };
+        if active >= self.tabs.len() {
+            return;
+        }
+        self.tabs.remove(active);
+        if self.tabs.is_empty() {
+            self.active_tab = None;
+            return;
+        }
+        let next = active.min(self.tabs.len() - 1);
+        if let Err(e) = self.switch_to_tab(next) {
+            error!("Failed to switch tab after closing: {e}");
+        }
+    }
+
     /// Navigate to a specific chapter - ensures all state is properly updated
     pub fn navigate_to_chapter(&mut self, chapter_index: usize) -> Result<()> {
         if let Some(doc) = &mut self.current_book {
@@ -387,6 +669,123 @@ impl App {
         }
     }
 
+    /// Step to the next (`forward = true`) or previous search match,
+    /// reusing whichever direction `/`/`?` opened the search with (same
+    /// convention as the text reader's own `next_match`/`previous_match`).
+    /// If the current chapter has no more matches, lazily loads the
+    /// adjacent chapter and re-runs the query there, landing on that
+    /// chapter's first match (hopping forward) or last match (hopping
+    /// backward) once the background scan completes
+    /// (see `resolve_pending_search_landing`).
+    fn advance_search_match(&mut self, forward: bool) {
+        if forward {
+            self.text_reader.next_match();
+        } else {
+            self.text_reader.previous_match();
+        }
+        if self.text_reader.get_search_state().matches.is_empty() {
+            let query_direction_forward = match self.text_reader.get_search_state().direction {
+                crate::search::Direction::Forward => forward,
+                crate::search::Direction::Backward => !forward,
+            };
+            let total_chapters = self
+                .current_book
+                .as_ref()
+                .map(|book| book.total_chapters())
+                .unwrap_or(0);
+            self.hop_chapter_for_search(query_direction_forward, total_chapters);
+        }
+    }
+
+    /// Navigates one chapter in `forward`'s direction and re-issues the
+    /// active search query there, arming `pending_search_landing` so
+    /// `resolve_pending_search_landing` can land on the right end of the
+    /// new chapter's matches (or hop again) once the scan finishes.
+    fn hop_chapter_for_search(&mut self, forward: bool, hops_left: usize) {
+        if hops_left == 0 {
+            return;
+        }
+        let query = self.text_reader.get_search_state().query.clone();
+        if query.is_empty() {
+            return;
+        }
+        let direction = if forward {
+            ChapterDirection::Next
+        } else {
+            ChapterDirection::Previous
+        };
+        if self.navigate_chapter_relative(direction).is_err() {
+            // Reached the start/end of the book; nothing left to hop to.
+            return;
+        }
+        self.text_reader.update_search_query(&query);
+        self.pending_search_landing = Some(PendingSearchLanding {
+            land_last: !forward,
+            hops_left: hops_left - 1,
+        });
+    }
+
+    /// Called once a background search scan completes. If a cross-chapter
+    /// search hop is pending, lands on the matching edge of the freshly
+    /// loaded chapter, or hops again if that chapter had no matches either.
+    fn resolve_pending_search_landing(&mut self) {
+        let Some(pending) = self.pending_search_landing.take() else {
+            return;
+        };
+        if self.text_reader.land_on_chapter_search_edge(pending.land_last) {
+            return;
+        }
+        self.hop_chapter_for_search(!pending.land_last, pending.hops_left);
+    }
+
+    /// How often a ticking `AutoScrollTick` fires while a drag is held
+    /// outside the content area.
+    const AUTO_SCROLL_TICK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
+    /// Keeps the scheduled `AutoScrollTick` in sync with whether the text
+    /// reader currently wants to be auto-scrolling, after a drag event. Call
+    /// after every `handle_mouse_drag`.
+    fn sync_auto_scroll_schedule(&mut self) {
+        let wants_scroll = self.text_reader.is_auto_scrolling();
+        let is_scheduled = self
+            .scheduler
+            .is_scheduled(ScheduledEventKind::AutoScrollTick);
+
+        if wants_scroll && !is_scheduled {
+            self.scheduler.schedule(
+                Self::AUTO_SCROLL_TICK_INTERVAL,
+                ScheduledEvent {
+                    kind: ScheduledEventKind::AutoScrollTick,
+                    repeat: Some(Self::AUTO_SCROLL_TICK_INTERVAL),
+                },
+            );
+        } else if !wants_scroll && is_scheduled {
+            self.scheduler.unschedule(ScheduledEventKind::AutoScrollTick);
+        }
+    }
+
+    /// Drains due timer events (see [`Scheduler`]) and applies them. Returns
+    /// `true` if anything changed that warrants a redraw - called from the
+    /// main loop every iteration, independent of whether that iteration
+    /// redraws, so continuous auto-scroll is frame-rate-independent.
+    fn poll_scheduler(&mut self) -> bool {
+        let mut needs_redraw = false;
+        for event in self.scheduler.poll() {
+            match event.kind {
+                ScheduledEventKind::AutoScrollTick => {
+                    if self.text_reader.update_auto_scroll() {
+                        self.save_bookmark();
+                        needs_redraw = true;
+                    }
+                    if !self.text_reader.is_auto_scrolling() {
+                        self.scheduler.unschedule(ScheduledEventKind::AutoScrollTick);
+                    }
+                }
+            }
+        }
+        needs_redraw
+    }
+
     pub fn switch_to_book_list_mode(&mut self) {
         self.navigation_panel.switch_to_book_mode();
         self.focused_panel = FocusedPanel::Main(MainPanel::NavigationList);
@@ -811,6 +1210,10 @@ impl App {
                                 mouse_event.row,
                                 nav_area,
                             );
+                            if click_type == ClickType::Single {
+                                self.navigation_panel
+                                    .handle_book_drag_start(mouse_event.row, nav_area);
+                            }
                         }
                         ClickType::Double => {
                             if self.navigation_panel.handle_mouse_click(
@@ -845,8 +1248,13 @@ impl App {
                             {
                                 self.handle_image_click(&image_src, self.terminal_size);
                             } else {
-                                self.text_reader
-                                    .handle_mouse_down(mouse_event.column, mouse_event.row);
+                                let block_selection =
+                                    mouse_event.modifiers.contains(crossterm::event::KeyModifiers::ALT);
+                                self.text_reader.handle_mouse_down(
+                                    mouse_event.column,
+                                    mouse_event.row,
+                                    block_selection,
+                                );
                             }
                         }
                         ClickType::Double => {
@@ -878,6 +1286,12 @@ impl App {
                             error!("Failed to handle link click: {e}");
                         }
                     }
+                    self.scheduler.unschedule(ScheduledEventKind::AutoScrollTick);
+                } else if let Some((from, to)) = self.navigation_panel.handle_book_drag_end() {
+                    self.book_manager.reorder(from, to);
+                    if self.navigation_panel.current_book_index == Some(from) {
+                        self.navigation_panel.current_book_index = Some(to);
+                    }
                 }
             }
             MouseEventKind::Drag(MouseButton::Left) => {
@@ -897,6 +1311,26 @@ impl App {
                     if self.text_reader.get_scroll_offset() != old_scroll_offset {
                         self.save_bookmark();
                     }
+                    self.sync_auto_scroll_schedule();
+                } else {
+                    let nav_area = self.get_navigation_panel_area();
+                    self.navigation_panel
+                        .handle_book_drag_move(mouse_event.row, nav_area);
+                }
+            }
+            MouseEventKind::Moved => {
+                if matches!(
+                    self.focused_panel,
+                    FocusedPanel::Popup(PopupWindow::ImagePopup)
+                ) {
+                    return;
+                }
+
+                let nav_panel_width = self.nav_panel_width();
+                if mouse_event.column >= nav_panel_width {
+                    let area = self.text_reader.get_last_content_area().unwrap_or_default();
+                    self.text_reader
+                        .handle_mouse_move(mouse_event.column, mouse_event.row, area);
                 }
             }
             _ => {
@@ -1160,6 +1594,13 @@ impl App {
 
     /// Apply scroll events (positive for down, negative for up)
     fn apply_scroll(&mut self, scroll_amount: i32, column: u16) {
+        // Run every scroll notch through the fractional accumulator before
+        // acting on it, so a scroll source that reports sub-cell deltas
+        // (unlike crossterm's whole-notch `MouseEventKind::Scroll*` today)
+        // advances the viewport by whole cells only once enough has
+        // accumulated, rather than snapping one per event.
+        let scroll_amount = self.accumulated_scroll.accumulate_y(scroll_amount as f64);
+
         if scroll_amount == 0 {
             return;
         }
@@ -1233,6 +1674,41 @@ impl App {
         self.text_reader.get_scroll_offset()
     }
 
+    /// Export the current selection (or whole chapter, if nothing is
+    /// selected) to a file under the user's documents directory, for
+    /// clipping passages out of a book into notes.
+    fn export_panel_content(&mut self, format: ExportFormat) {
+        let Some(book) = &self.current_book else {
+            error!("No EPUB file currently loaded");
+            return;
+        };
+
+        let stem = std::path::Path::new(&book.file)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("book");
+        let extension = match format {
+            ExportFormat::Markdown => "md",
+            ExportFormat::PlainText => "txt",
+        };
+        let filename = format!("{}-ch{}.{}", stem, book.current_chapter() + 1, extension);
+
+        let export_dir = dirs::document_dir()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(std::env::temp_dir)
+            .join("bookokrat-exports");
+        if let Err(e) = std::fs::create_dir_all(&export_dir) {
+            error!("Failed to create export directory {}: {e}", export_dir.display());
+            return;
+        }
+
+        let path = export_dir.join(filename);
+        match self.text_reader.export_to_path(&path, format) {
+            Ok(()) => info!("Exported content to {}", path.display()),
+            Err(e) => error!("Export failed: {e}"),
+        }
+    }
+
     fn jump_to_location(&mut self, location: JumpLocation) -> Result<()> {
         if self.current_book.as_ref().map(|x| &x.file) != Some(&location.epub_path) {
             self.load_epub(&location.epub_path, true)?;
@@ -1267,6 +1743,36 @@ impl App {
         }
     }
 
+    /// Handle `'{char}` - jump to the mark set by `m{char}`. Pushes the
+    /// pre-jump position onto the jump list first, like a link click, so
+    /// Ctrl-O can return to it.
+    fn jump_to_mark(&mut self, mark_char: char) {
+        if let Some(book) = &self.current_book {
+            let current_location = JumpLocation {
+                epub_path: book.file.clone(),
+                chapter_index: book.current_chapter(),
+                node_index: self.text_reader.get_current_node_index(),
+            };
+            self.jump_list.push(current_location);
+        }
+
+        if !self.text_reader.jump_to_mark(mark_char) {
+            return;
+        }
+
+        if let Some((chapter_file, node_index)) = self.text_reader.take_pending_mark_navigation()
+        {
+            if self
+                .navigate_to_chapter_by_file(&chapter_file, None)
+                .unwrap_or(false)
+            {
+                self.text_reader.restore_to_node_index(node_index);
+            }
+        }
+
+        self.save_bookmark();
+    }
+
     /// Calculate the navigation panel width based on stored terminal width
     fn nav_panel_width(&self) -> u16 {
         // 30% of terminal width, minimum 20 columns
@@ -1363,14 +1869,13 @@ impl App {
     }
 
     pub fn draw(&mut self, f: &mut ratatui::Frame, fps_counter: &FPSCounter) {
-        let auto_scroll_updated = self.text_reader.update_auto_scroll();
-        if auto_scroll_updated {
-            self.save_bookmark();
-        }
+        // Auto-scroll ticks are driven by `poll_scheduler` from the main
+        // loop now, independent of redraw cadence - see `Scheduler`.
 
         self.terminal_size = f.area();
 
-        let background_block = Block::default().style(Style::default().bg(OCEANIC_NEXT.base_00));
+        let background_block =
+            Block::default().style(Style::default().bg(crate::theme::current_theme().base_00));
         f.render_widget(background_block, f.area());
 
         let chunks = Layout::default()
@@ -1387,21 +1892,32 @@ impl App {
             f,
             main_chunks[0],
             self.is_main_panel(MainPanel::NavigationList),
-            &OCEANIC_NEXT,
+            crate::theme::current_theme(),
             &self.book_manager,
         );
 
+        let content_area = if self.tabs.len() > 1 {
+            let content_chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(main_chunks[1]);
+            self.render_tab_bar(f, content_chunks[0]);
+            content_chunks[1]
+        } else {
+            main_chunks[1]
+        };
+
         if let Some(ref book) = self.current_book {
             self.text_reader.render(
                 f,
-                main_chunks[1],
+                content_area,
                 book.current_chapter(),
                 book.total_chapters(),
-                &OCEANIC_NEXT,
+                crate::theme::current_theme(),
                 self.is_main_panel(MainPanel::Content),
             );
         } else {
-            self.render_default_content(f, main_chunks[1], "Select a file to view its content");
+            self.render_default_content(f, content_area, "Select a file to view its content");
         }
 
         self.render_help_bar(f, chunks[1], fps_counter);
@@ -1446,7 +1962,7 @@ impl App {
             f.render_widget(dim_block, f.area());
 
             if let Some(ref mut book_search) = self.book_search {
-                book_search.render(f, f.area(), &OCEANIC_NEXT);
+                book_search.render(f, f.area(), crate::theme::current_theme());
             }
         }
 
@@ -1463,28 +1979,133 @@ impl App {
 
             self.book_stat.render(f, f.area());
         }
+
+        if matches!(
+            self.focused_panel,
+            FocusedPanel::Popup(PopupWindow::CommandPalette)
+        ) {
+            let dim_block = Block::default().style(
+                Style::default()
+                    .bg(Color::Rgb(10, 10, 10))
+                    .add_modifier(Modifier::DIM),
+            );
+            f.render_widget(dim_block, f.area());
+
+            if let Some(ref palette) = self.command_palette {
+                palette.render(f, f.area(), crate::theme::current_theme());
+            }
+        }
+
+        if matches!(
+            self.focused_panel,
+            FocusedPanel::Popup(PopupWindow::UrlImport)
+        ) {
+            let dim_block = Block::default().style(
+                Style::default()
+                    .bg(Color::Rgb(10, 10, 10))
+                    .add_modifier(Modifier::DIM),
+            );
+            f.render_widget(dim_block, f.area());
+
+            if let Some(ref popup) = self.url_import_popup {
+                popup.render(f, f.area(), crate::theme::current_theme());
+            }
+        }
+
+        if matches!(
+            self.focused_panel,
+            FocusedPanel::Popup(PopupWindow::NamedBookmarks)
+        ) {
+            let dim_block = Block::default().style(
+                Style::default()
+                    .bg(Color::Rgb(10, 10, 10))
+                    .add_modifier(Modifier::DIM),
+            );
+            f.render_widget(dim_block, f.area());
+
+            if let Some(ref mut popup) = self.named_bookmarks_popup {
+                popup.render(f, f.area(), crate::theme::current_theme());
+            }
+        }
+
+        if matches!(
+            self.focused_panel,
+            FocusedPanel::Popup(PopupWindow::NamedBookmarkLabel)
+        ) {
+            let dim_block = Block::default().style(
+                Style::default()
+                    .bg(Color::Rgb(10, 10, 10))
+                    .add_modifier(Modifier::DIM),
+            );
+            f.render_widget(dim_block, f.area());
+
+            if let Some(ref popup) = self.named_bookmark_label_popup {
+                popup.render(f, f.area(), crate::theme::current_theme());
+            }
+        }
+
+        if matches!(
+            self.focused_panel,
+            FocusedPanel::Popup(PopupWindow::HeadingOutline)
+        ) {
+            let dim_block = Block::default().style(
+                Style::default()
+                    .bg(Color::Rgb(10, 10, 10))
+                    .add_modifier(Modifier::DIM),
+            );
+            f.render_widget(dim_block, f.area());
+
+            if let Some(ref mut popup) = self.heading_outline_popup {
+                popup.render(f, f.area(), crate::theme::current_theme());
+            }
+        }
     }
 
     fn render_default_content(&self, f: &mut ratatui::Frame, area: Rect, content: &str) {
         // Use focus-aware colors instead of hardcoded false
         let (text_color, border_color, _bg_color) =
-            OCEANIC_NEXT.get_panel_colors(self.is_main_panel(MainPanel::Content));
+            crate::theme::current_theme().get_panel_colors(self.is_main_panel(MainPanel::Content));
 
         let content_border = Block::default()
             .borders(Borders::ALL)
             .title("Content")
             .border_style(Style::default().fg(border_color))
-            .style(Style::default().bg(OCEANIC_NEXT.base_00));
+            .style(Style::default().bg(crate::theme::current_theme().base_00));
 
         let paragraph = Paragraph::new(content)
             .block(content_border)
-            .style(Style::default().fg(text_color).bg(OCEANIC_NEXT.base_00));
+            .style(Style::default().fg(text_color).bg(crate::theme::current_theme().base_00));
 
         f.render_widget(paragraph, area);
     }
 
+    /// Renders the tab bar along the top of the content area, one title per
+    /// open book, with the active tab highlighted. Only shown once a second
+    /// tab exists, so single-book reading looks exactly like it did before
+    /// tabs.
+    fn render_tab_bar(&self, f: &mut ratatui::Frame, area: Rect) {
+        let palette = crate::theme::current_theme();
+        let titles: Vec<&str> = self
+            .tabs
+            .iter()
+            .map(|tab| tab.display_name.as_str())
+            .collect();
+
+        let tabs = Tabs::new(titles)
+            .style(Style::default().fg(palette.base_04).bg(palette.base_00))
+            .highlight_style(
+                Style::default()
+                    .fg(palette.base_06)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .select(self.active_tab.unwrap_or(0))
+            .divider(" ");
+
+        f.render_widget(tabs, area);
+    }
+
     fn render_help_bar(&self, f: &mut ratatui::Frame, area: Rect, fps_counter: &FPSCounter) {
-        let (_, _, border_color, _, _) = OCEANIC_NEXT.get_interface_colors(false);
+        let (_, _, border_color, _, _) = crate::theme::current_theme().get_interface_colors(false);
 
         let help_content = if self.is_in_search_mode() {
             let search_state = if self.navigation_panel.is_searching() {
@@ -1541,12 +2162,12 @@ impl App {
             Block::default()
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(border_color))
-                .style(Style::default().bg(OCEANIC_NEXT.base_00)),
+                .style(Style::default().bg(crate::theme::current_theme().base_00)),
         )
         .style(
             Style::default()
-                .fg(OCEANIC_NEXT.base_03)
-                .bg(OCEANIC_NEXT.base_00),
+                .fg(crate::theme::current_theme().base_03)
+                .bg(crate::theme::current_theme().base_00),
         );
 
         f.render_widget(help, area);
@@ -1564,6 +2185,18 @@ impl App {
                 self.key_sequence.clear();
                 true
             }
+            "gt" => {
+                // Handle 'gt' to cycle to the next reading tab
+                self.next_tab();
+                self.key_sequence.clear();
+                true
+            }
+            "gT" => {
+                // Handle 'gT' to cycle to the previous reading tab
+                self.previous_tab();
+                self.key_sequence.clear();
+                true
+            }
             " s" => {
                 // Handle Space->s to show raw HTML source
                 if self.is_main_panel(MainPanel::Content) && self.current_book.is_some() {
@@ -1636,12 +2269,72 @@ impl App {
                 self.key_sequence.clear();
                 true
             }
+            " e" => {
+                // Handle Space->e to export the selection (or whole chapter)
+                // to a Markdown file, for clipping passages out into notes.
+                if self.is_main_panel(MainPanel::Content) {
+                    self.export_panel_content(ExportFormat::Markdown);
+                }
+                self.key_sequence.clear();
+                true
+            }
+            " E" => {
+                // Handle Space->E for the same export, as plain text.
+                if self.is_main_panel(MainPanel::Content) {
+                    self.export_panel_content(ExportFormat::PlainText);
+                }
+                self.key_sequence.clear();
+                true
+            }
             " o" => {
                 // Handle Space->o to open current EPUB with system viewer
                 self.open_with_system_viewer();
                 self.key_sequence.clear();
                 true
             }
+            " t" => {
+                // Handle Space->t to open the fuzzy TOC/heading picker
+                if self.is_main_panel(MainPanel::Content) && self.current_book.is_some() {
+                    self.text_reader.open_toc_picker();
+                }
+                self.key_sequence.clear();
+                true
+            }
+            " l" => {
+                // Handle Space->l to cycle keyboard focus to the next
+                // on-screen/chapter link
+                if self.is_main_panel(MainPanel::Content) {
+                    self.text_reader.focus_next_link();
+                }
+                self.key_sequence.clear();
+                true
+            }
+            " L" => {
+                // Handle Space->L for the previous link, mirroring Space->l
+                if self.is_main_panel(MainPanel::Content) {
+                    self.text_reader.focus_previous_link();
+                }
+                self.key_sequence.clear();
+                true
+            }
+            " y" => {
+                // Handle Space->y to toggle syntax highlighting in code blocks
+                if self.is_main_panel(MainPanel::Content) {
+                    self.text_reader.toggle_syntax_highlighting();
+                }
+                self.key_sequence.clear();
+                true
+            }
+            " u" => {
+                // Handle Space->u to open the URL hint overlay (Alacritty-
+                // style: label every visible link/bare URL, then type a
+                // label to open it)
+                if self.is_main_panel(MainPanel::Content) {
+                    self.text_reader.enter_hint_mode();
+                }
+                self.key_sequence.clear();
+                true
+            }
             " h" => {
                 // Handle Space->h to toggle reading history
                 if matches!(
@@ -1652,13 +2345,37 @@ impl App {
                     self.focused_panel = FocusedPanel::Main(MainPanel::Content);
                     self.reading_history = None;
                 } else {
-                    // Open history
-                    self.reading_history = Some(ReadingHistory::new(&self.bookmarks));
+                    // Open history, resuming whatever sort the user last picked
+                    self.reading_history = Some(ReadingHistory::new(
+                        &self.bookmarks,
+                        self.reading_history_sort_by,
+                        self.reading_history_sort_direction,
+                    ));
                     self.focused_panel = FocusedPanel::Popup(PopupWindow::ReadingHistory);
                 }
                 self.key_sequence.clear();
                 true
             }
+            " x" => {
+                // Handle Space->x to close the active reading tab
+                self.close_current_tab();
+                self.key_sequence.clear();
+                true
+            }
+            _ if sequence.len() == 2 && sequence.starts_with('m') => {
+                // Handle m{char} to set a named mark at the current position
+                let mark_char = sequence.chars().nth(1).unwrap();
+                self.text_reader.set_mark(mark_char);
+                self.key_sequence.clear();
+                true
+            }
+            _ if sequence.len() == 2 && sequence.starts_with('\'') => {
+                // Handle '{char} to jump back to a named mark
+                let mark_char = sequence.chars().nth(1).unwrap();
+                self.jump_to_mark(mark_char);
+                self.key_sequence.clear();
+                true
+            }
             _ if sequence.len() >= 2 => {
                 // Unknown sequence of 2+ chars, reset
                 self.key_sequence.clear();
@@ -1679,6 +2396,153 @@ impl App {
     ) -> Option<AppAction> {
         use crossterm::event::{KeyCode, KeyModifiers};
 
+        // If the command palette overlay is open, route all input to it
+        if self.focused_panel == FocusedPanel::Popup(PopupWindow::CommandPalette) {
+            let action = if let Some(ref mut palette) = self.command_palette {
+                palette.handle_key_event(key)
+            } else {
+                None
+            };
+
+            if let Some(action) = action {
+                self.command_palette = None;
+                self.focused_panel = FocusedPanel::Main(MainPanel::Content);
+                if let CommandPaletteAction::Run(command_id) = action {
+                    self.run_command(command_id);
+                }
+            }
+            return None;
+        }
+
+        // If the URL-import overlay is open, route all input to it
+        if self.focused_panel == FocusedPanel::Popup(PopupWindow::UrlImport) {
+            let action = if let Some(ref mut popup) = self.url_import_popup {
+                popup.handle_key_event(key)
+            } else {
+                None
+            };
+
+            if let Some(action) = action {
+                self.url_import_popup = None;
+                self.focused_panel = FocusedPanel::Main(MainPanel::Content);
+                if let UrlImportAction::Submit(url) = action {
+                    if let Err(e) = self.book_manager.import_article_from_url(&url) {
+                        error!("Failed to import article from {url}: {e}");
+                    }
+                }
+            }
+            return None;
+        }
+
+        // If the named-bookmarks listing overlay is open, route all input
+        // to it
+        if self.focused_panel == FocusedPanel::Popup(PopupWindow::NamedBookmarks) {
+            let action = if let Some(ref mut popup) = self.named_bookmarks_popup {
+                popup.handle_key_event(key)
+            } else {
+                None
+            };
+
+            if let Some(action) = action {
+                match action {
+                    NamedBookmarksAction::JumpTo {
+                        chapter,
+                        scroll_offset,
+                    } => {
+                        self.named_bookmarks_popup = None;
+                        self.focused_panel = FocusedPanel::Main(MainPanel::Content);
+                        self.jump_to_named_bookmark(chapter, scroll_offset);
+                    }
+                    NamedBookmarksAction::Delete { index } => {
+                        self.delete_named_bookmark(index);
+                        if let Some(ref mut popup) = self.named_bookmarks_popup {
+                            popup.remove_selected();
+                        }
+                    }
+                    NamedBookmarksAction::Rename { index } => {
+                        let initial_label = self
+                            .named_bookmarks_popup
+                            .as_ref()
+                            .and_then(|popup| popup.label_at(index))
+                            .unwrap_or_default()
+                            .to_string();
+                        self.named_bookmark_label_popup = Some(NamedBookmarkLabelPopup::new(
+                            NamedBookmarkLabelTarget::Rename { index },
+                            initial_label,
+                        ));
+                        self.focused_panel = FocusedPanel::Popup(PopupWindow::NamedBookmarkLabel);
+                    }
+                    NamedBookmarksAction::Close => {
+                        self.named_bookmarks_popup = None;
+                        self.focused_panel = FocusedPanel::Main(MainPanel::Content);
+                    }
+                }
+            }
+            return None;
+        }
+
+        // If the bookmark label-entry overlay is open, route all input to it
+        if self.focused_panel == FocusedPanel::Popup(PopupWindow::NamedBookmarkLabel) {
+            let action = if let Some(ref mut popup) = self.named_bookmark_label_popup {
+                popup.handle_key_event(key)
+            } else {
+                None
+            };
+
+            if let Some(action) = action {
+                let target = self
+                    .named_bookmark_label_popup
+                    .take()
+                    .map(|popup| match popup.target() {
+                        NamedBookmarkLabelTarget::Add {
+                            chapter,
+                            scroll_offset,
+                        } => NamedBookmarkLabelTarget::Add {
+                            chapter: *chapter,
+                            scroll_offset: *scroll_offset,
+                        },
+                        NamedBookmarkLabelTarget::Rename { index } => {
+                            NamedBookmarkLabelTarget::Rename { index: *index }
+                        }
+                    });
+                self.focused_panel = FocusedPanel::Main(MainPanel::Content);
+
+                if let (NamedBookmarkLabelAction::Submit(label), Some(target)) = (action, target)
+                {
+                    match target {
+                        NamedBookmarkLabelTarget::Add {
+                            chapter,
+                            scroll_offset,
+                        } => {
+                            self.add_named_bookmark_at(label, chapter, scroll_offset);
+                        }
+                        NamedBookmarkLabelTarget::Rename { index } => {
+                            self.rename_named_bookmark(index, label);
+                        }
+                    }
+                }
+            }
+            return None;
+        }
+
+        // If the heading outline overlay is open, route all input to it
+        if self.focused_panel == FocusedPanel::Popup(PopupWindow::HeadingOutline) {
+            let action = if let Some(ref mut popup) = self.heading_outline_popup {
+                popup.handle_key_event(key)
+            } else {
+                None
+            };
+
+            if let Some(action) = action {
+                self.heading_outline_popup = None;
+                self.focused_panel = FocusedPanel::Main(MainPanel::Content);
+                if let HeadingOutlineAction::JumpTo { chapter, anchor_id } = action {
+                    self.jump_to_heading_outline_entry(chapter, anchor_id);
+                }
+            }
+            return None;
+        }
+
         // If comment input is active, route all input to the text area
         if self.text_reader.is_comment_input_active() {
             if let Some(input) = map_keys_to_input(key) {
@@ -1688,6 +2552,23 @@ impl App {
             }
         }
 
+        // If the TOC picker overlay is open, route all input to it
+        if self.text_reader.is_toc_picker_active() {
+            match key.code {
+                KeyCode::Char(c) => self.text_reader.toc_picker_input_char(c),
+                KeyCode::Backspace => self.text_reader.toc_picker_backspace(),
+                KeyCode::Up => self.text_reader.toc_picker_move_selection(-1),
+                KeyCode::Down => self.text_reader.toc_picker_move_selection(1),
+                KeyCode::Enter => {
+                    self.text_reader.confirm_toc_picker();
+                    self.save_bookmark();
+                }
+                KeyCode::Esc => self.text_reader.close_toc_picker(),
+                _ => {}
+            }
+            return None;
+        }
+
         // If image popup is shown, close it on any key press
         if matches!(
             self.focused_panel,
@@ -1769,6 +2650,10 @@ impl App {
                             let _ = self.open_book_for_reading(book_index);
                         }
                     }
+                    ReadingHistoryAction::SortChanged { sort_by, direction } => {
+                        self.reading_history_sort_by = sort_by;
+                        self.reading_history_sort_direction = direction;
+                    }
                 }
             }
             return None;
@@ -1776,6 +2661,11 @@ impl App {
 
         if self.is_search_input_mode() {
             match key.code {
+                KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    if self.text_reader.is_searching() {
+                        self.text_reader.toggle_search_literal_mode();
+                    }
+                }
                 KeyCode::Char(c) => self.handle_search_input(c),
                 KeyCode::Backspace => self.handle_search_backspace(),
                 KeyCode::Esc => self.cancel_current_search(),
@@ -1806,7 +2696,10 @@ impl App {
                         bypass = true;
                     }
                     NavigationPanelAction::SelectBook { book_index } => {
-                        let _ = self.open_book_for_reading(book_index);
+                        let _ = self.open_book_in_tab(book_index);
+                    }
+                    NavigationPanelAction::SelectBookInNewTab { book_index } => {
+                        let _ = self.open_book_in_new_tab(book_index);
                     }
                     NavigationPanelAction::SwitchToBookList => {
                         self.switch_to_book_list_mode();
@@ -1838,12 +2731,64 @@ impl App {
             }
         }
 
+        // If the keyboard vi-mode cursor is active, route motions and
+        // commands to it before any other content-panel binding gets a
+        // chance at the key.
+        if self.is_main_panel(MainPanel::Content) && self.text_reader.is_vi_cursor_active() {
+            match key.code {
+                KeyCode::Esc => self.text_reader.exit_vi_cursor(),
+                KeyCode::Char(c @ ('d' | 'u')) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    if let Some(visible_height) = screen_height {
+                        self.text_reader.handle_vi_cursor_ctrl(c, visible_height);
+                    }
+                }
+                KeyCode::Enter => {
+                    if let Some((line, col)) = self.text_reader.vi_cursor_position()
+                        && let Some(link) = self.text_reader.get_link_at_position(line, col)
+                    {
+                        let link = link.clone();
+                        if let Err(e) = self.handle_link_click(&link) {
+                            error!("Failed to handle link click: {e}");
+                        }
+                    }
+                }
+                KeyCode::Char(c) => {
+                    self.text_reader.handle_vi_cursor_key(c);
+                }
+                _ => {}
+            }
+            return None;
+        }
+
+        // If the URL hint overlay is open (Space->u), route character keys
+        // to label matching before any other content-panel binding gets a
+        // chance at the key.
+        if self.is_main_panel(MainPanel::Content) && self.text_reader.is_hint_mode_active() {
+            match key.code {
+                KeyCode::Esc => self.text_reader.exit_hint_mode(),
+                KeyCode::Char(c) => {
+                    if let Some(link) = self.text_reader.handle_hint_key(c) {
+                        if let Err(e) = self.handle_link_click(&link) {
+                            error!("Failed to handle link click: {e}");
+                        }
+                    }
+                }
+                _ => {}
+            }
+            return None;
+        }
+
         match key.code {
             KeyCode::Char('/') => {
                 if self.is_main_panel(MainPanel::Content) {
                     self.text_reader.start_search();
                 }
             }
+            KeyCode::Char('?') => {
+                if self.is_main_panel(MainPanel::Content) {
+                    self.text_reader.start_search_backward();
+                }
+            }
             KeyCode::Char('n') if self.is_in_search_mode() => {
                 if self.navigation_panel.is_searching() {
                     let search_state = self.navigation_panel.get_search_state();
@@ -1853,7 +2798,7 @@ impl App {
                 } else if self.text_reader.is_searching() {
                     let search_state = self.text_reader.get_search_state();
                     if search_state.mode == SearchMode::NavigationMode {
-                        self.text_reader.next_match();
+                        self.advance_search_match(true);
                     } else {
                         self.handle_search_input('n');
                     }
@@ -1868,7 +2813,7 @@ impl App {
                 } else if self.text_reader.is_searching() {
                     let search_state = self.text_reader.get_search_state();
                     if search_state.mode == SearchMode::NavigationMode {
-                        self.text_reader.previous_match();
+                        self.advance_search_match(false);
                     } else {
                         self.handle_search_input('N');
                     }
@@ -1879,6 +2824,10 @@ impl App {
             KeyCode::Char('s') => if self.handle_key_sequence('s') {},
             KeyCode::Char(' ') => if !self.handle_key_sequence(' ') {},
             KeyCode::Char('g') => if !self.handle_key_sequence('g') {},
+            KeyCode::Char('t') => if !self.handle_key_sequence('t') {},
+            KeyCode::Char('T') => if !self.handle_key_sequence('T') {},
+            KeyCode::Char('m') => if !self.handle_key_sequence('m') {},
+            KeyCode::Char('\'') => if !self.handle_key_sequence('\'') {},
 
             KeyCode::Char('d') if !key.modifiers.contains(KeyModifiers::CONTROL) => {
                 if self.handle_key_sequence('d') {
@@ -1910,6 +2859,11 @@ impl App {
             KeyCode::Char('l') => {
                 let _ = self.navigate_chapter_relative(ChapterDirection::Next);
             }
+            KeyCode::Char('v') => {
+                if self.is_main_panel(MainPanel::Content) {
+                    self.text_reader.enter_vi_cursor();
+                }
+            }
             KeyCode::Char('i') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                 self.jump_forward();
             }
@@ -1919,9 +2873,38 @@ impl App {
                 } else if !self.handle_key_sequence('o') {
                 }
             }
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.open_command_palette();
+            }
+            KeyCode::Char(':') => {
+                self.open_command_palette();
+            }
+            KeyCode::Char('U') => {
+                self.open_url_import_popup();
+            }
+            KeyCode::Char('B') => {
+                self.open_named_bookmarks_popup();
+            }
+            KeyCode::Char('b') => {
+                self.open_named_bookmark_add_popup();
+            }
+            KeyCode::Char('H') => {
+                self.open_heading_outline_popup();
+            }
             KeyCode::Char('p') => {
                 self.toggle_profiling();
             }
+            KeyCode::Enter => {
+                // Follow the link focused via Space->l/Space->L, the
+                // keyboard equivalent of left-clicking a hovered link.
+                if self.is_main_panel(MainPanel::Content) {
+                    if let Some(url) = self.text_reader.hovered_link_url().map(str::to_string) {
+                        if let Err(e) = self.handle_link_click(&LinkInfo::from_url(url)) {
+                            error!("Failed to handle link click: {e}");
+                        }
+                    }
+                }
+            }
             KeyCode::Tab => {
                 if !self.has_active_popup() {
                     self.focused_panel = match self.focused_panel {
@@ -1959,13 +2942,24 @@ impl App {
                     debug!("Started comment input mode");
                 }
             }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if let Err(e) = self.copy_selected_text_to_clipboard() {
+                    error!("Copy failed: {e}");
+                }
+            }
             KeyCode::Char('c') => {
                 if !self.handle_key_sequence('c') {
-                    if let Err(e) = self.text_reader.copy_selection_to_clipboard() {
+                    if let Err(e) = self.copy_selected_text_to_clipboard() {
                         error!("Copy failed: {e}");
                     }
                 }
             }
+            // Alacritty-style copy-selection binding, alongside the existing 'c'.
+            KeyCode::Char('y') => {
+                if let Err(e) = self.copy_selected_text_to_clipboard() {
+                    error!("Copy failed: {e}");
+                }
+            }
             KeyCode::Char('q') => {
                 self.save_bookmark_with_throttle(true);
                 return Some(AppAction::Quit);
@@ -2116,6 +3110,214 @@ impl App {
         self.book_search = Some(BookSearch::new(search_engine));
     }
 
+    /// Opens the command palette (`:` or `Ctrl-P`), a searchable list of
+    /// every reader action that dispatches through `run_command` to the
+    /// same handlers their keybindings already call.
+    fn open_command_palette(&mut self) {
+        self.command_palette = Some(CommandPalette::new());
+        self.focused_panel = FocusedPanel::Popup(PopupWindow::CommandPalette);
+    }
+
+    /// Opens the "import article from URL" overlay, where a submitted URL
+    /// is fetched, readability-extracted, and written into the library as
+    /// a new EPUB via `BookManager::import_article_from_url`.
+    fn open_url_import_popup(&mut self) {
+        self.url_import_popup = Some(UrlImportPopup::new());
+        self.focused_panel = FocusedPanel::Popup(PopupWindow::UrlImport);
+    }
+
+    /// Opens the named-bookmarks listing (`B`) for the current book, letting
+    /// the user jump to (`Enter`), rename (`r`), or delete (`d`) one. No-op
+    /// if no book is open.
+    fn open_named_bookmarks_popup(&mut self) {
+        let Some(book) = &self.current_book else {
+            return;
+        };
+        let items = self.bookmark_store.named_bookmarks(&book.file).to_vec();
+        self.named_bookmarks_popup = Some(NamedBookmarksPopup::new(book.file.clone(), items));
+        self.focused_panel = FocusedPanel::Popup(PopupWindow::NamedBookmarks);
+    }
+
+    /// Opens the label-entry overlay (`b`) to add a new named bookmark at
+    /// the current reading position. No-op if no book is open.
+    fn open_named_bookmark_add_popup(&mut self) {
+        let Some(book) = &self.current_book else {
+            return;
+        };
+        let target = NamedBookmarkLabelTarget::Add {
+            chapter: book.current_chapter(),
+            scroll_offset: self.text_reader.get_current_node_index(),
+        };
+        self.named_bookmark_label_popup =
+            Some(NamedBookmarkLabelPopup::new(target, String::new()));
+        self.focused_panel = FocusedPanel::Popup(PopupWindow::NamedBookmarkLabel);
+    }
+
+    /// Adds a named bookmark labeled `label` at `chapter`/`scroll_offset`
+    /// for the current book. No-op if no book is open.
+    fn add_named_bookmark_at(&mut self, label: String, chapter: usize, scroll_offset: usize) {
+        let Some(book) = &self.current_book else {
+            return;
+        };
+        self.bookmark_store
+            .add_named_bookmark(&book.file, label, chapter, scroll_offset);
+    }
+
+    /// Adds a named bookmark labeled `label` at the current reading
+    /// position for the current book. Returns the new bookmark's index
+    /// within that book's list, or `None` if no book is open.
+    pub fn add_named_bookmark(&mut self, label: String) -> Option<usize> {
+        let book = self.current_book.as_ref()?;
+        Some(self.bookmark_store.add_named_bookmark(
+            &book.file,
+            label,
+            book.current_chapter(),
+            self.text_reader.get_current_node_index(),
+        ))
+    }
+
+    /// Renames the named bookmark at `index` for the current book. Returns
+    /// `false` if no book is open or `index` doesn't exist.
+    pub fn rename_named_bookmark(&mut self, index: usize, new_label: String) -> bool {
+        let Some(book) = &self.current_book else {
+            return false;
+        };
+        self.bookmark_store
+            .rename_named_bookmark(&book.file, index, new_label)
+    }
+
+    /// Deletes the named bookmark at `index` for the current book. Returns
+    /// `false` if no book is open or `index` doesn't exist.
+    pub fn delete_named_bookmark(&mut self, index: usize) -> bool {
+        let Some(book) = &self.current_book else {
+            return false;
+        };
+        self.bookmark_store.delete_named_bookmark(&book.file, index)
+    }
+
+    /// Jumps the current book to a named bookmark's chapter and scroll
+    /// position, navigating chapters first if needed.
+    fn jump_to_named_bookmark(&mut self, chapter: usize, scroll_offset: usize) {
+        if self.current_book.as_ref().map(|book| book.current_chapter()) != Some(chapter)
+            && self.navigate_to_chapter(chapter).is_err()
+        {
+            return;
+        }
+        self.text_reader.restore_to_node_index(scroll_offset);
+    }
+
+    /// Opens the cross-chapter heading outline (`H`) for the current book.
+    /// Entries carry a chapter index plus an anchor id rather than a raw
+    /// scroll offset - like `LinkType::InternalAnchor` elsewhere in the
+    /// reader, a chapter's line offsets aren't known until it's actually
+    /// rendered, since wrapping depends on terminal width. Parses every
+    /// chapter's HTML through the same `HtmlToMarkdownConverter` pipeline
+    /// `update_content` uses, so the outline matches what the reader would
+    /// show. No-op if no book is open.
+    fn open_heading_outline_popup(&mut self) {
+        use crate::parsing::html_to_markdown::HtmlToMarkdownConverter;
+
+        let Some(book) = &mut self.current_book else {
+            return;
+        };
+        let original_chapter = book.current_chapter();
+        let mut builder = HeadingOutlineBuilder::new();
+        let mut entries = Vec::new();
+
+        for chapter in 0..book.total_chapters() {
+            if !book.epub.set_current_page(chapter) {
+                continue;
+            }
+            if let Some((raw_html, _mime)) = book.epub.get_current_str() {
+                let mut converter = HtmlToMarkdownConverter::new();
+                let doc = converter.convert(&raw_html);
+                collect_chapter_headings(&doc, chapter, &mut builder, &mut entries);
+            }
+        }
+        book.epub.set_current_page(original_chapter);
+
+        self.heading_outline_popup = Some(HeadingOutlinePopup::new(entries));
+        self.focused_panel = FocusedPanel::Popup(PopupWindow::HeadingOutline);
+    }
+
+    /// Jumps to a heading outline entry: navigates to `chapter` if not
+    /// already there, then scrolls to `anchor_id`, either immediately (same
+    /// chapter) or once the target chapter finishes loading (cross-chapter),
+    /// mirroring `navigate_to_chapter_by_file`'s handling of
+    /// `LinkType::InternalAnchor`/`InternalChapter` link clicks.
+    fn jump_to_heading_outline_entry(&mut self, chapter: usize, anchor_id: String) {
+        let same_chapter = self
+            .current_book
+            .as_ref()
+            .map(|book| book.current_chapter())
+            == Some(chapter);
+
+        if !same_chapter {
+            if self.navigate_to_chapter(chapter).is_err() {
+                return;
+            }
+            self.text_reader.store_pending_anchor_scroll(anchor_id);
+        } else {
+            let _ = self.scroll_to_anchor(&anchor_id);
+        }
+    }
+
+    /// Runs the command selected in the palette, calling exactly the
+    /// handler the equivalent keybinding would.
+    fn run_command(&mut self, command_id: CommandId) {
+        match command_id {
+            CommandId::ToggleToc => {
+                if self.text_reader.is_toc_picker_active() {
+                    self.text_reader.close_toc_picker();
+                } else if self.current_book.is_some() {
+                    self.text_reader.open_toc_picker();
+                }
+            }
+            CommandId::ToggleViCursorMode => {
+                if self.text_reader.is_vi_cursor_active() {
+                    self.text_reader.exit_vi_cursor();
+                } else {
+                    self.text_reader.enter_vi_cursor();
+                }
+            }
+            CommandId::GoToChapter => {
+                if self.current_book.is_some() {
+                    self.open_book_search(true);
+                }
+            }
+            CommandId::CopySelection => {
+                if let Err(e) = self.copy_selected_text_to_clipboard() {
+                    error!("Failed to copy selection to clipboard: {e}");
+                }
+            }
+            CommandId::ReloadBook => {
+                if let Some(ref book) = self.current_book {
+                    let path = book.file.clone();
+                    if let Err(e) = self.load_epub(&path, true) {
+                        error!("Failed to reload book: {e}");
+                    }
+                }
+            }
+            CommandId::JumpToReadingPosition => {
+                if let Some(ref book) = self.current_book {
+                    let path = book.file.clone();
+                    if let Err(e) = self.load_epub(&path, false) {
+                        error!("Failed to jump to reading position: {e}");
+                    }
+                }
+            }
+            CommandId::CycleTheme => {
+                let themes = crate::theme::ThemeId::all();
+                let current = themes
+                    .iter()
+                    .position(|&t| t == crate::theme::current_theme_id())
+                    .unwrap_or(0);
+                let next = themes[(current + 1) % themes.len()];
+                crate::theme::set_theme(next);
+            }
+        }
+    }
+
     fn open_book_search(&mut self, clear_input: bool) {
         if let Some(ref mut book_search) = self.book_search {
             book_search.open(clear_input);
@@ -2209,6 +3411,13 @@ pub fn run_app_with_event_source<B: ratatui::backend::Backend>(
 
         let mut needs_redraw = events_processed > 0;
 
+        // Drain due scheduler events (continuous auto-scroll today) every
+        // iteration, independent of the tick-rate-gated block below, so
+        // they fire on wall-clock time rather than redraw cadence.
+        if app.poll_scheduler() {
+            needs_redraw = true;
+        }
+
         if first_render {
             needs_redraw = true;
             first_render = false;
@@ -2217,6 +3426,10 @@ pub fn run_app_with_event_source<B: ratatui::backend::Backend>(
         if last_tick.elapsed() >= tick_rate {
             let highlight_changed = app.text_reader.update_highlight(); // Update highlight state
             let images_loaded = app.text_reader.check_for_loaded_images();
+            let search_scan_updated = app.text_reader.poll_search_scan();
+            if search_scan_updated {
+                app.resolve_pending_search_landing();
+            }
             if images_loaded {
                 needs_redraw = true;
                 debug!("Images loaded, forcing redraw");
@@ -2225,6 +3438,10 @@ pub fn run_app_with_event_source<B: ratatui::backend::Backend>(
                 needs_redraw = true;
                 debug!("Highlight expired, forcing redraw");
             }
+            if search_scan_updated {
+                needs_redraw = true;
+                debug!("Search scan produced new matches, forcing redraw");
+            }
             last_tick = std::time::Instant::now();
         }
 
@@ -2254,4 +3471,31 @@ pub fn run_app_with_event_source<B: ratatui::backend::Backend>(
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::AccumulatedScroll;
+
+    #[test]
+    fn accumulates_whole_notch_per_call() {
+        let mut acc = AccumulatedScroll::default();
+        assert_eq!(acc.accumulate_y(1.0), 1);
+        assert_eq!(acc.accumulate_y(-1.0), -1);
+    }
+
+    #[test]
+    fn fractional_deltas_advance_one_cell_once_enough_accumulate() {
+        let mut acc = AccumulatedScroll::default();
+        assert_eq!(acc.accumulate_y(0.3), 0);
+        assert_eq!(acc.accumulate_y(0.3), 0);
+        assert_eq!(acc.accumulate_y(0.3), 0);
+        // Four 0.3 deltas push the accumulator past 1.0 for the first time.
+        assert_eq!(acc.accumulate_y(0.3), 1);
+    }
+
+    #[test]
+    fn remainder_carries_forward_across_calls() {
+        let mut acc = AccumulatedScroll::default();
+        assert_eq!(acc.accumulate_y(2.5), 2);
+        // 0.5 carried over, plus another 0.5 crosses the next whole cell.
+        assert_eq!(acc.accumulate_y(0.5), 1);
+    }
+}
@@ -45,6 +45,10 @@ pub enum Block {
         content: Vec<Node>,
     },
     ThematicBreak,
+    FootnoteDefinition {
+        tag: String,
+        content: Vec<Node>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -84,6 +88,9 @@ pub enum Inline {
     },
     LineBreak,
     SoftBreak,
+    FootnoteReference {
+        tag: String,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -330,6 +337,28 @@ impl Text {
     pub fn iter_mut(&mut self) -> std::slice::IterMut<TextOrInline> {
         self.0.iter_mut()
     }
+
+    /// Flattens this run of text/inline nodes into plain text: links and
+    /// images contribute their visible text, soft breaks become a space,
+    /// hard breaks a newline, and footnote references contribute nothing.
+    /// Used wherever a heading or label needs to be shown/slugified without
+    /// its inline structure (the in-book heading outline, anchor slugs).
+    pub fn to_plain_string(&self) -> String {
+        let mut result = String::new();
+        for item in self.iter() {
+            match item {
+                TextOrInline::Text(text_node) => result.push_str(&text_node.content),
+                TextOrInline::Inline(Inline::Link { text, .. }) => {
+                    result.push_str(&text.to_plain_string())
+                }
+                TextOrInline::Inline(Inline::Image { alt_text, .. }) => result.push_str(alt_text),
+                TextOrInline::Inline(Inline::FootnoteReference { .. }) => {}
+                TextOrInline::Inline(Inline::LineBreak) => result.push('\n'),
+                TextOrInline::Inline(Inline::SoftBreak) => result.push(' '),
+            }
+        }
+        result
+    }
 }
 
 // IntoIterator for Text
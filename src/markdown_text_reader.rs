@@ -7,11 +7,17 @@ use crate::markdown::{
     Block as MarkdownBlock, Document, HeadingLevel, Inline, Node, Style, Text as MarkdownText,
     TextOrInline,
 };
-use crate::search::{SearchMode, SearchState, SearchablePanel, find_matches_in_text};
-use crate::table::{Table as CustomTable, TableConfig};
-use crate::text_reader_trait::{LinkInfo, TextReaderTrait};
+use crate::marks::MarkPosition;
+use crate::parsing::markdown_renderer::MarkdownRenderer;
+use crate::search::{
+    Direction, MAX_SEARCH_LINES, SearchMatch, SearchMode, SearchState, SearchablePanel,
+    find_fuzzy_matches_in_text, find_regex_matches_in_text,
+};
+use crate::table::{Alignment as TableCellAlignment, Table as CustomTable, TableConfig};
+use crate::text_reader_trait::{ExportFormat, LinkInfo, TextReaderTrait};
 use crate::text_selection::TextSelection;
 use crate::theme::Base16Palette;
+use crate::toc::{TocBuilder, TocEntry};
 use image::{DynamicImage, GenericImageView};
 use log::{debug, info, warn};
 use ratatui::{
@@ -24,16 +30,26 @@ use ratatui::{
 use ratatui_image::protocol::StatefulProtocol;
 use ratatui_image::{Resize, StatefulImage, picker::Picker};
 use std::cell::RefCell;
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
 use std::time::{Duration, Instant};
+use syntect::parsing::{ParseState, ScopeStack, SyntaxSet};
+use syntect::util::LinesWithEndings;
 use tui_textarea::{Input, Key, TextArea};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// Pre-processed rendering structure
 struct RenderedContent {
     lines: Vec<RenderedLine>,
     total_height: usize,
     generation: u64, // For cache validation
+    /// Hierarchical outline of the chapter's headings, built by `TocBuilder`
+    /// while the chapter is rendered. Source for the heading picker
+    /// (`open_toc_picker`) and any future jump-to-section panel.
+    toc: Vec<TocEntry>,
 }
 
 #[derive(Clone)]
@@ -44,6 +60,14 @@ struct RenderedLine {
     link_nodes: Vec<LinkInfo>,   // Links that are visible on this line
     node_anchor: Option<String>, // Anchor/id from the Node if present
     node_index: Option<usize>,   // Index of the node in the document this line belongs to
+    /// Which wrapped segment of `node_index` this line is (0 for the first
+    /// line a node produced, 1 for its second wrapped line, ...). Combined
+    /// with `char_offset`, lets scroll restoration target the exact wrapped
+    /// line the user was on rather than just the node's first line.
+    portion: usize,
+    /// Cumulative grapheme-cluster count of this node's raw text preceding
+    /// this line, i.e. where this wrapped segment starts within the node.
+    char_offset: usize,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -60,6 +84,14 @@ enum LineType {
         kind: crate::markdown::ListKind,
         indent: usize,
     },
+    TaskListItem {
+        /// Document index of the `List` node this item belongs to.
+        node_index: Option<usize>,
+        /// Position of this item within that list's `items`.
+        item_index: usize,
+        checked: bool,
+        indent: usize,
+    },
     TableRow {
         is_header: bool,
     },
@@ -68,6 +100,9 @@ enum LineType {
     },
     HorizontalRule,
     Empty,
+    /// A virtual line injected by [`VirtualAnnotation`] - not part of the
+    /// document, so its `raw_text` is always empty.
+    Annotation,
     Comment {
         chapter_href: String,
         paragraph_index: usize,
@@ -75,6 +110,687 @@ enum LineType {
     },
 }
 
+/// Controls whether external links get wrapped in an OSC 8 hyperlink escape
+/// sequence (`\x1b]8;;URL\x1b\text\x1b]8;;\x1b\`) so terminals can make them
+/// clickable, instead of only the plain underlined styling. The feature is
+/// opt-in (`Disabled` by default): `Auto` turns it on but still probes
+/// `$TERM`/`$TERM_PROGRAM` so a dumb terminal still gets the plain
+/// underlined fallback, while `Enabled` forces the sequence regardless of
+/// what's detected.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Osc8Mode {
+    #[default]
+    Disabled,
+    Auto,
+    Enabled,
+}
+
+/// Semantic slot into a [`Base16Palette`], letting a [`RenderTheme`] pick
+/// which base16 color fills a given role (heading, link, quote, ...)
+/// without hardcoding the palette field name at each render call site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteRole {
+    Base00,
+    Base01,
+    Base02,
+    Base03,
+    Base04,
+    Base05,
+    Base06,
+    Base07,
+    Base08,
+    Base09,
+    Base0a,
+    Base0b,
+    Base0c,
+    Base0d,
+    Base0e,
+    Base0f,
+}
+
+impl PaletteRole {
+    fn resolve(self, palette: &Base16Palette) -> Color {
+        match self {
+            PaletteRole::Base00 => palette.base_00,
+            PaletteRole::Base01 => palette.base_01,
+            PaletteRole::Base02 => palette.base_02,
+            PaletteRole::Base03 => palette.base_03,
+            PaletteRole::Base04 => palette.base_04,
+            PaletteRole::Base05 => palette.base_05,
+            PaletteRole::Base06 => palette.base_06,
+            PaletteRole::Base07 => palette.base_07,
+            PaletteRole::Base08 => palette.base_08,
+            PaletteRole::Base09 => palette.base_09,
+            PaletteRole::Base0a => palette.base_0a,
+            PaletteRole::Base0b => palette.base_0b,
+            PaletteRole::Base0c => palette.base_0c,
+            PaletteRole::Base0d => palette.base_0d,
+            PaletteRole::Base0e => palette.base_0e,
+            PaletteRole::Base0f => palette.base_0f,
+        }
+    }
+}
+
+/// Drawing characters a [`RenderTheme`] uses to decorate a document, kept
+/// separate from [`RenderRoles`] so a theme can swap glyphs (e.g. for an
+/// ASCII-only terminal) without also having to restate every color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeCharacters {
+    /// Rule character repeated under an H1, e.g. `'═'`.
+    pub heading_rule_h1: char,
+    /// Rule character repeated under an H2, e.g. `'─'`.
+    pub heading_rule_h2: char,
+    /// Whether H1 content is upper-cased before wrapping.
+    pub uppercase_h1: bool,
+    /// Prefix put in front of each wrapped comment line
+    /// (`render_comment_as_quote`); block quotes keep a fixed `> ` prefix.
+    pub quote_prefix: &'static str,
+    /// Bullet put in front of a plain (non-task) unordered list item.
+    pub bullet: &'static str,
+    /// Glyph for a checked task list item.
+    pub checkbox_checked: &'static str,
+    /// Glyph for an unchecked task list item.
+    pub checkbox_unchecked: &'static str,
+    /// `format!`-style template for an inline image's placeholder text;
+    /// `{}` is replaced with the image's alt text.
+    pub image_placeholder: &'static str,
+}
+
+/// Semantic color/modifier role assignments a [`RenderTheme`] uses to style
+/// a document, resolved against whichever [`Base16Palette`] is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderRoles {
+    pub heading_focused: PaletteRole,
+    pub heading_unfocused: PaletteRole,
+    pub quote: PaletteRole,
+    pub link_external: PaletteRole,
+    pub link_internal_chapter: PaletteRole,
+    pub link_internal_anchor: PaletteRole,
+    /// Color used for any link type once the reader loses focus.
+    pub link_unfocused: PaletteRole,
+    pub checkbox_checked: PaletteRole,
+    pub checkbox_unchecked: PaletteRole,
+}
+
+/// Pluggable set of drawing characters and palette roles used throughout
+/// `render_heading`, `render_comment_as_quote`, `render_list` and
+/// `render_text_or_inline`, so readers on limited terminals can fall back to
+/// ASCII decorations and anyone can recolor link/heading roles without
+/// recompiling. Mirrors miette's `GraphicalTheme`/`ThemeCharacters` split.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderTheme {
+    pub characters: ThemeCharacters,
+    pub roles: RenderRoles,
+}
+
+impl RenderTheme {
+    /// Unicode-rich default: box-drawing rule characters, a bullet dot and
+    /// Unicode checkbox glyphs.
+    pub fn unicode() -> Self {
+        Self {
+            characters: ThemeCharacters {
+                heading_rule_h1: '═',
+                heading_rule_h2: '─',
+                uppercase_h1: true,
+                quote_prefix: "> ",
+                bullet: "• ",
+                checkbox_checked: "\u{2611} ",
+                checkbox_unchecked: "\u{2610} ",
+                image_placeholder: "[image: {}]",
+            },
+            roles: RenderRoles {
+                heading_focused: PaletteRole::Base0a,
+                heading_unfocused: PaletteRole::Base03,
+                quote: PaletteRole::Base0e,
+                link_external: PaletteRole::Base0c,
+                link_internal_chapter: PaletteRole::Base0b,
+                link_internal_anchor: PaletteRole::Base0a,
+                link_unfocused: PaletteRole::Base03,
+                checkbox_checked: PaletteRole::Base0b,
+                checkbox_unchecked: PaletteRole::Base03,
+            },
+        }
+    }
+
+    /// ASCII-only fallback for terminals that mangle box-drawing
+    /// characters; same roles as [`Self::unicode`], plain-ASCII glyphs.
+    pub fn ascii() -> Self {
+        Self {
+            characters: ThemeCharacters {
+                heading_rule_h1: '=',
+                heading_rule_h2: '-',
+                uppercase_h1: true,
+                quote_prefix: "> ",
+                bullet: "* ",
+                checkbox_checked: "[x] ",
+                checkbox_unchecked: "[ ] ",
+                image_placeholder: "[image: {}]",
+            },
+            roles: Self::unicode().roles,
+        }
+    }
+
+    /// Looks up a built-in theme by name, for loading the user's configured
+    /// choice (e.g. a `render_theme = "ascii"` config entry). Returns
+    /// `None` for an unrecognized name so the caller can fall back to
+    /// [`Self::default`] rather than silently guessing.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "unicode" => Some(Self::unicode()),
+            "ascii" => Some(Self::ascii()),
+            _ => None,
+        }
+    }
+
+    fn heading_decoration(&self, level: HeadingLevel, width: usize) -> Option<String> {
+        match level {
+            HeadingLevel::H1 => Some(self.characters.heading_rule_h1.to_string().repeat(width)),
+            HeadingLevel::H2 => Some(self.characters.heading_rule_h2.to_string().repeat(width)),
+            _ => None,
+        }
+    }
+
+    fn heading_modifier(&self, level: HeadingLevel) -> Modifier {
+        match level {
+            HeadingLevel::H3 | HeadingLevel::H4 => Modifier::BOLD | Modifier::UNDERLINED,
+            _ => Modifier::BOLD,
+        }
+    }
+
+    fn heading_color(&self, palette: &Base16Palette, is_focused: bool) -> Color {
+        if is_focused {
+            self.roles.heading_focused.resolve(palette)
+        } else {
+            self.roles.heading_unfocused.resolve(palette)
+        }
+    }
+
+    fn quote_color(&self, palette: &Base16Palette) -> Color {
+        self.roles.quote.resolve(palette)
+    }
+
+    /// Resolves the `(color, modifier)` pair for a link, mirroring the
+    /// per-`LinkType` differentiation focused links got before theming:
+    /// external links underlined, internal-chapter links bold+underlined,
+    /// internal-anchor links italic+underlined. Unfocused links keep the
+    /// modifier but fall back to `roles.link_unfocused`.
+    fn link_style(
+        &self,
+        link_type: &Option<crate::markdown::LinkType>,
+        is_focused: bool,
+        palette: &Base16Palette,
+    ) -> (Color, Modifier) {
+        use crate::markdown::LinkType;
+
+        let modifier = match link_type {
+            Some(LinkType::InternalChapter) => Modifier::UNDERLINED | Modifier::BOLD,
+            Some(LinkType::InternalAnchor) => Modifier::UNDERLINED | Modifier::ITALIC,
+            Some(LinkType::External) | None => Modifier::UNDERLINED,
+        };
+
+        let color = if is_focused {
+            match link_type {
+                Some(LinkType::External) | None => self.roles.link_external.resolve(palette),
+                Some(LinkType::InternalChapter) => {
+                    self.roles.link_internal_chapter.resolve(palette)
+                }
+                Some(LinkType::InternalAnchor) => self.roles.link_internal_anchor.resolve(palette),
+            }
+        } else {
+            self.roles.link_unfocused.resolve(palette)
+        };
+
+        (color, modifier)
+    }
+
+    fn checkbox_glyph(&self, checked: bool, palette: &Base16Palette) -> (&'static str, Color) {
+        if checked {
+            (
+                self.characters.checkbox_checked,
+                self.roles.checkbox_checked.resolve(palette),
+            )
+        } else {
+            (
+                self.characters.checkbox_unchecked,
+                self.roles.checkbox_unchecked.resolve(palette),
+            )
+        }
+    }
+
+    fn image_placeholder_text(&self, alt_text: &str) -> String {
+        self.characters.image_placeholder.replace("{}", alt_text)
+    }
+}
+
+impl Default for RenderTheme {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
+
+/// Where a [`VirtualAnnotation`] attaches in the rendered line stream.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum AnnotationKey {
+    /// The first rendered line of the document node carrying this anchor id.
+    Anchor(String),
+    /// The rendered line index at which a document node started (before any
+    /// following annotations are injected), independent of whether that node
+    /// has an anchor.
+    Line(usize),
+}
+
+/// A line of content shown alongside the document but not part of it: it's
+/// injected into the rendered line stream (with its own `LineType::Annotation`)
+/// but `raw_text_lines` gets an empty string in its place, so it's invisible
+/// to `get_visible_text`, search, selection and copy - the same trick
+/// `render_image_placeholder` uses to keep line numbers in sync for rows that
+/// aren't real document text. Useful for footnote previews, per-section
+/// reading-time estimates, or definition tooltips shown inline.
+#[derive(Debug, Clone)]
+pub struct VirtualAnnotation {
+    pub text: String,
+    pub style: RatatuiStyle,
+}
+
+/// The link currently under the mouse cursor. `id` is the same
+/// `osc8_link_id` used to group a wrapped link's fragments for OSC 8
+/// hyperlinks, reused here to highlight every fragment of the hovered link
+/// together even when it spans multiple rendered lines.
+#[derive(Debug, Clone)]
+struct LinkHover {
+    id: u64,
+    url: String,
+}
+
+/// A reading position at wrap-segment granularity: which source node, which
+/// wrapped `portion` of that node (0 for its first rendered line), and the
+/// grapheme `char_offset` that portion starts at within the node's raw text.
+/// More precise than a bare `node_index`, this survives width changes (where
+/// the number of wrapped lines a node produces differs) because restoration
+/// walks to the line with the closest accumulated `char_offset` rather than
+/// just the node's first line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct NodePosition {
+    node_index: usize,
+    portion: usize,
+    char_offset: usize,
+}
+
+/// Submode of the keyboard vi-mode cursor (see `vi_cursor`). Only meaningful
+/// while `vi_cursor` is `Some`; `Normal` is just a point, `Visual`/
+/// `VisualLine` mirror the anchor/active-end selection already driven by
+/// mouse drag through `TextSelection`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViCursorMode {
+    Normal,
+    Visual,
+    VisualLine,
+}
+
+/// A single, non-compound vi-cursor motion/command recognized by
+/// `handle_vi_cursor_key`. Kept as data rather than matched on `char`
+/// directly so the keymap driving it (`ViKeymap`) can be remapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViMotion {
+    Left,
+    Right,
+    Down,
+    Up,
+    LineStart,
+    LineEnd,
+    WordForward,
+    WordEnd,
+    WordBackward,
+    ParagraphForward,
+    ParagraphBackward,
+    /// Bound to `g` by default. Pressing it twice in a row (tracked via
+    /// `vi_cursor_pending_g`) jumps to the top of the document, mirroring
+    /// vim's `gg`; a single press with no follow-up is a no-op.
+    PendingG,
+    DocumentEnd,
+    MatchingBracket,
+    EnterVisual,
+    EnterVisualLine,
+    Yank,
+}
+
+/// Remappable `char -> ViMotion` table for the keyboard vi-mode cursor.
+/// Starts out bound to vim's classic keys via `ViKeymap::default`; callers
+/// that want different bindings (e.g. an alternate keyboard layout) can
+/// `rebind` individual keys without touching `handle_vi_cursor_key`.
+#[derive(Debug, Clone)]
+struct ViKeymap {
+    bindings: std::collections::HashMap<char, ViMotion>,
+}
+
+impl Default for ViKeymap {
+    fn default() -> Self {
+        let mut bindings = std::collections::HashMap::new();
+        bindings.insert('h', ViMotion::Left);
+        bindings.insert('l', ViMotion::Right);
+        bindings.insert('j', ViMotion::Down);
+        bindings.insert('k', ViMotion::Up);
+        bindings.insert('0', ViMotion::LineStart);
+        bindings.insert('$', ViMotion::LineEnd);
+        bindings.insert('w', ViMotion::WordForward);
+        bindings.insert('e', ViMotion::WordEnd);
+        bindings.insert('b', ViMotion::WordBackward);
+        bindings.insert('}', ViMotion::ParagraphForward);
+        bindings.insert('{', ViMotion::ParagraphBackward);
+        bindings.insert('g', ViMotion::PendingG);
+        bindings.insert('G', ViMotion::DocumentEnd);
+        bindings.insert('%', ViMotion::MatchingBracket);
+        bindings.insert('v', ViMotion::EnterVisual);
+        bindings.insert('V', ViMotion::EnterVisualLine);
+        bindings.insert('y', ViMotion::Yank);
+        Self { bindings }
+    }
+}
+
+impl ViKeymap {
+    fn motion_for(&self, key: char) -> Option<ViMotion> {
+        self.bindings.get(&key).copied()
+    }
+
+    /// Rebinds `key` to whatever motion `like` currently triggers, e.g.
+    /// `rebind('k', 'w')` makes `k` do what `w` used to. Returns `false`
+    /// (and leaves the keymap untouched) if `like` isn't bound to anything.
+    fn rebind(&mut self, key: char, like: char) -> bool {
+        let Some(motion) = self.motion_for(like) else {
+            return false;
+        };
+        self.bindings.insert(key, motion);
+        true
+    }
+}
+
+/// Character class used by the `w`/`b`/`e` word motions to find run
+/// boundaries, matching vim's classic (non-WORD) word definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViCharClass {
+    Blank,
+    Word,
+    Punct,
+}
+
+fn vi_char_class(cluster: &str) -> ViCharClass {
+    match cluster.chars().next() {
+        Some(c) if c.is_whitespace() => ViCharClass::Blank,
+        Some(c) if c.is_alphanumeric() || c == '_' => ViCharClass::Word,
+        _ => ViCharClass::Punct,
+    }
+}
+
+/// Kind of marker drawn in the document-wide scrollbar gutter (see
+/// `ScrollbarMarkerComputer`). Ordered so that when two markers land on the
+/// same gutter row, merging keeps the more important one - the momentary
+/// "you are here" highlight wins over a current match, which wins over a
+/// plain match, which wins over a comment, which wins over a bare
+/// anchor/heading marker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum ScrollbarMarkerKind {
+    Anchor,
+    Comment,
+    Match,
+    CurrentMatch,
+    Highlight,
+}
+
+/// A single drawn cell in the scrollbar gutter, already mapped from
+/// document line space into gutter row space and merged with any other
+/// marker that landed on the same row.
+#[derive(Debug, Clone, Copy)]
+struct ScrollbarMarker {
+    row: usize,
+    kind: ScrollbarMarkerKind,
+}
+
+/// Everything that changes the gutter's marker set. A freshly computed
+/// signature is compared against the cached one so `render` never
+/// recomputes (or respawns a worker thread) on a scroll-only frame.
+#[derive(Debug, Clone, PartialEq)]
+struct ScrollbarMarkerSignature {
+    content_generation: u64,
+    query: String,
+    match_count: usize,
+    current_match_index: Option<usize>,
+    comment_count: usize,
+    highlight_line: Option<usize>,
+    gutter_height: usize,
+}
+
+/// Off-thread computation of the document-wide scrollbar gutter's marker
+/// set, shaped like `BackgroundImageLoader`'s start/check/cancel handles:
+/// a chapter's match list (and its heading/anchor positions) can be large,
+/// so building and row-merging it happens on a worker thread rather than
+/// blocking the render/scroll path.
+struct ScrollbarMarkerComputer {
+    signature: Option<ScrollbarMarkerSignature>,
+    markers: Vec<ScrollbarMarker>,
+    pending: Option<mpsc::Receiver<Vec<ScrollbarMarker>>>,
+}
+
+impl ScrollbarMarkerComputer {
+    fn new() -> Self {
+        Self {
+            signature: None,
+            markers: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// Spawns a recompute unless `signature` already matches the cached
+    /// one (a no-op on every scroll-only render). `anchor_lines` and
+    /// `match_lines` (`(line_idx, is_current_match)`) are document line
+    /// indices; the worker only does row-mapping/merging arithmetic, so it
+    /// needs no access to `self` and can run fully detached.
+    fn start_if_stale(
+        &mut self,
+        signature: ScrollbarMarkerSignature,
+        anchor_lines: Vec<usize>,
+        comment_lines: Vec<usize>,
+        match_lines: Vec<(usize, bool)>,
+        highlight_line: Option<usize>,
+        total_height: usize,
+    ) {
+        if self.signature.as_ref() == Some(&signature) {
+            return;
+        }
+        self.signature = Some(signature.clone());
+
+        let (tx, rx) = mpsc::channel();
+        self.pending = Some(rx);
+
+        thread::spawn(move || {
+            let markers = Self::compute_markers(
+                &anchor_lines,
+                &comment_lines,
+                &match_lines,
+                highlight_line,
+                total_height,
+                &signature,
+            );
+            let _ = tx.send(markers);
+        });
+    }
+
+    fn compute_markers(
+        anchor_lines: &[usize],
+        comment_lines: &[usize],
+        match_lines: &[(usize, bool)],
+        highlight_line: Option<usize>,
+        total_height: usize,
+        signature: &ScrollbarMarkerSignature,
+    ) -> Vec<ScrollbarMarker> {
+        let gutter_height = signature.gutter_height;
+        if total_height == 0 || gutter_height == 0 {
+            return Vec::new();
+        }
+
+        let row_of = |line_idx: usize| -> usize {
+            ((line_idx * gutter_height) / total_height).min(gutter_height - 1)
+        };
+
+        let mut by_row: HashMap<usize, ScrollbarMarkerKind> = HashMap::new();
+        let mut upsert = |row: usize, kind: ScrollbarMarkerKind| {
+            by_row
+                .entry(row)
+                .and_modify(|existing| {
+                    if kind > *existing {
+                        *existing = kind;
+                    }
+                })
+                .or_insert(kind);
+        };
+
+        for &line_idx in anchor_lines {
+            upsert(row_of(line_idx), ScrollbarMarkerKind::Anchor);
+        }
+        for &line_idx in comment_lines {
+            upsert(row_of(line_idx), ScrollbarMarkerKind::Comment);
+        }
+        for &(line_idx, is_current) in match_lines {
+            let kind = if is_current {
+                ScrollbarMarkerKind::CurrentMatch
+            } else {
+                ScrollbarMarkerKind::Match
+            };
+            upsert(row_of(line_idx), kind);
+        }
+        if let Some(line_idx) = highlight_line {
+            upsert(row_of(line_idx), ScrollbarMarkerKind::Highlight);
+        }
+
+        let mut markers: Vec<ScrollbarMarker> = by_row
+            .into_iter()
+            .map(|(row, kind)| ScrollbarMarker { row, kind })
+            .collect();
+        markers.sort_by_key(|m| m.row);
+        markers
+    }
+
+    /// Drains a finished background computation into the cache, if ready.
+    /// Cheap no-op when nothing is pending or the worker hasn't finished.
+    fn poll(&mut self) {
+        let Some(rx) = &self.pending else {
+            return;
+        };
+        if let Ok(markers) = rx.try_recv() {
+            self.markers = markers;
+            self.pending = None;
+        }
+    }
+
+    fn markers(&self) -> &[ScrollbarMarker] {
+        &self.markers
+    }
+
+    fn cancel(&mut self) {
+        self.pending = None;
+    }
+}
+
+/// A heading available for selection in the TOC picker (`open_toc_picker`).
+#[derive(Clone, Debug)]
+struct TocCandidate {
+    /// Heading title, used both for display and fuzzy filtering.
+    title: String,
+    /// Rendered line the heading starts at, for scrolling to it.
+    line: usize,
+}
+
+/// State for the overlay fuzzy picker over the document's headings, opened
+/// via `open_toc_picker` (`Space t`). Filtering uses the
+/// subsequence-match helper `find_fuzzy_matches_in_text`, the same one the
+/// command palette uses.
+struct TocPickerState {
+    candidates: Vec<TocCandidate>,
+    query: String,
+    matches: Vec<SearchMatch>,
+    selected: usize,
+    /// Preview lines per candidate index, so moving the selection doesn't
+    /// re-wrap/re-render the document to build the preview again.
+    preview_cache: HashMap<usize, Vec<Line<'static>>>,
+}
+
+impl TocPickerState {
+    fn new(candidates: Vec<TocCandidate>) -> Self {
+        let titles: Vec<String> = candidates.iter().map(|c| c.title.clone()).collect();
+        let matches = all_matches(&titles);
+        Self {
+            candidates,
+            query: String::new(),
+            matches,
+            selected: 0,
+            preview_cache: HashMap::new(),
+        }
+    }
+
+    fn titles(&self) -> Vec<String> {
+        self.candidates.iter().map(|c| c.title.clone()).collect()
+    }
+}
+
+/// One labelled target of the URL hint overlay (`Space u`), following
+/// Alacritty's hint mode: any link (external URL, internal chapter/anchor,
+/// or bare URL text) visible on screen, tagged with a short alphabetic label
+/// the user types to jump to it. Columns are grapheme-cluster indices,
+/// matching `LinkInfo`.
+#[derive(Debug, Clone)]
+struct UrlHint {
+    label: String,
+    link: LinkInfo,
+    line: usize,
+    start_col: usize,
+    end_col: usize,
+}
+
+/// Active URL hint overlay state, opened via `enter_hint_mode` (`Space u`).
+/// `input` accumulates the label characters typed so far until they match a
+/// hint exactly (open it) or stop being a prefix of any hint (reset).
+struct HintState {
+    hints: Vec<UrlHint>,
+    input: String,
+}
+
+/// Assigns minimal-collision labels the way Alacritty's hint mode does:
+/// single letters `a`..`z` while there are 26 or fewer targets, expanding to
+/// two-letter labels (`aa`, `ab`, ..., `az`, `ba`, ...) once there are more.
+fn generate_hint_labels(n: usize) -> Vec<String> {
+    const ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz";
+    let letters: Vec<char> = ALPHABET.chars().collect();
+
+    if n <= letters.len() {
+        return letters.iter().take(n).map(|c| c.to_string()).collect();
+    }
+
+    let mut labels = Vec::with_capacity(n);
+    'outer: for first in &letters {
+        for second in &letters {
+            labels.push(format!("{first}{second}"));
+            if labels.len() == n {
+                break 'outer;
+            }
+        }
+    }
+    labels
+}
+
+/// `SearchMatch`es for every candidate, in order, as if each matched with no
+/// highlighted ranges. Used as the "no filter typed yet" match list so the
+/// picker shows the full heading list before the user starts typing.
+fn all_matches(titles: &[String]) -> Vec<SearchMatch> {
+    titles
+        .iter()
+        .enumerate()
+        .map(|(index, _)| SearchMatch {
+            index,
+            score: 1.0,
+            highlight_ranges: Vec::new(),
+        })
+        .collect()
+}
+
 /// Span that may contain link information
 #[derive(Clone)]
 enum RichSpan {
@@ -105,6 +821,10 @@ pub enum ImageLoadState {
     Loading,
     Loaded {
         image: Arc<DynamicImage>,
+        /// `image` downscaled to its on-screen cell footprint, which is what
+        /// actually gets handed to the `StatefulProtocol`. Keeping both lets
+        /// us re-derive a different resize without re-decoding the source.
+        resized: Arc<DynamicImage>,
         protocol: StatefulProtocol,
     },
     Failed {
@@ -204,13 +924,22 @@ pub struct MarkdownTextReader {
     auto_scroll_active: bool,
     auto_scroll_speed: f32,
 
+    /// Screen rect the scrollbar gutter column was last drawn at, so a
+    /// mouse click can be tested against it before falling through to text
+    /// selection. See `render_scrollbar_gutter`/`handle_gutter_click`.
+    last_gutter_area: Option<Rect>,
+
     // Image handling
     image_picker: Option<Picker>,
     embedded_images: RefCell<HashMap<String, EmbeddedImage>>,
     background_loader: BackgroundImageLoader,
+    /// Downscaled images keyed by `(src, target_width, target_height)`, so a
+    /// re-render at the same cell footprint (the common case) reuses the
+    /// resized pixels instead of resizing the source again.
+    resized_image_cache: RefCell<HashMap<(String, u32, u32), Arc<DynamicImage>>>,
 
-    // Deferred node index to restore after rendering
-    pending_node_restore: Option<usize>,
+    // Deferred reading position to restore after rendering
+    pending_node_restore: Option<NodePosition>,
 
     // Raw HTML mode
     show_raw_html: bool,
@@ -225,18 +954,125 @@ pub struct MarkdownTextReader {
     /// Map of anchor IDs to their line positions in rendered content
     anchor_positions: HashMap<String, usize>,
 
+    /// Count of how many times each base slug has been handed out so far
+    /// this render pass, for `derive_unique_anchor_id`'s rustdoc-style
+    /// collision disambiguation (`examples`, `examples-1`, `examples-2`, ...).
+    used_anchor_slugs: HashMap<String, usize>,
+
+    /// Loaded once and reused across chapters so `render_code_block` doesn't
+    /// re-parse syntax definitions on every render.
+    syntax_set: SyntaxSet,
+
+    /// Whether `render_code_block` runs fenced code through `syntect` at
+    /// all. Toggleable, for readers who prefer plain monochrome code.
+    syntax_highlighting_enabled: bool,
+
+    /// Tokenized spans for a code block, keyed by `(language, content)` so
+    /// identical blocks (or a re-render triggered by resize/focus/cache
+    /// invalidation, which doesn't change a block's text) reuse the syntect
+    /// pass instead of re-tokenizing. Not reset by `cache_generation` bumps;
+    /// only grows, which is fine since book chapters are bounded in size.
+    code_highlight_cache: HashMap<(String, String), Vec<Vec<Span<'static>>>>,
+
+    /// Sequential display number assigned to each footnote definition's tag,
+    /// in document order, for the current render pass. Populated by a first
+    /// pass over `doc.blocks` before rendering so a reference can show its
+    /// number even if it appears before the matching definition.
+    footnote_numbers: HashMap<String, usize>,
+
+    /// Whether `LinkType::External` links get wrapped in OSC 8 escape
+    /// sequences so terminals can make them clickable. Off by default
+    /// (opt-in); see [`Osc8Mode`].
+    osc8_hyperlinks: Osc8Mode,
+
+    /// Drawing characters and palette roles used to render headings,
+    /// quotes, lists and links. See [`RenderTheme`].
+    render_theme: RenderTheme,
+
+    /// Off-thread-computed markers for the document-wide scrollbar gutter
+    /// (search matches and heading/anchor positions). See
+    /// [`ScrollbarMarkerComputer`].
+    scrollbar_markers: ScrollbarMarkerComputer,
+
+    /// Virtual annotation lines injected into the rendered stream, keyed by
+    /// the anchor or rendered-line-index they attach to. See
+    /// [`VirtualAnnotation`].
+    annotations: HashMap<AnnotationKey, Vec<VirtualAnnotation>>,
+
+    /// Link currently under the mouse cursor, resolved each `handle_mouse_move`
+    /// against the just-rendered frame's `link_nodes` (see
+    /// `apply_link_hover_highlighting`).
+    hovered_link: Option<LinkHover>,
+
+    /// Keyboard vi-mode cursor position as `(line, column)` into
+    /// `raw_text_lines`, or `None` when inactive. A post-wrap line index, so
+    /// it doubles as a `rendered_content.lines` index with no translation
+    /// needed - each wrapped sub-line already gets its own `raw_text_lines`
+    /// entry in lock-step. See `enter_vi_cursor`/`handle_vi_cursor_key`.
+    vi_cursor: Option<(usize, usize)>,
+
+    /// Submode of the active vi cursor; meaningful only while `vi_cursor`
+    /// is `Some`.
+    vi_cursor_mode: ViCursorMode,
+
+    /// Set by a bare `g` while the vi cursor is active, so the next key
+    /// completes the `gg` motion instead of being ignored.
+    vi_cursor_pending_g: bool,
+
+    /// Data-driven key -> motion table consulted by `handle_vi_cursor_key`,
+    /// so the vi-cursor bindings can be remapped without touching its
+    /// dispatch logic. See `ViKeymap::rebind`.
+    vi_keymap: ViKeymap,
+
     /// Current chapter filename (for resolving relative links)
     current_chapter_file: Option<String>,
 
     /// Search state for vim-like search
     search_state: SearchState,
 
+    /// Whether `update_search_query` treats the query as a literal string
+    /// (escaped before compiling) or a regex pattern. See
+    /// `toggle_search_literal_mode`.
+    search_literal_mode: bool,
+
+    /// Query text queued by `update_search_query`, along with when it last
+    /// changed, so rapid keystrokes debounce into a single background scan
+    /// rather than spawning one worker per character. Consumed by
+    /// `poll_search_scan`.
+    pending_search_query: Option<(String, Instant)>,
+
+    /// Bumped every time a new document-wide search scan is kicked off. A
+    /// scan result tagged with a stale epoch (superseded by a newer query
+    /// before it finished) is discarded rather than applied.
+    search_scan_epoch: u64,
+
+    /// Receiving end for the in-flight background search scan, if any. See
+    /// `start_search_scan`/`poll_search_scan`.
+    search_scan_rx: Option<mpsc::Receiver<(u64, Vec<SearchMatch>)>>,
+
     /// Pending anchor scroll after chapter navigation
     pending_anchor_scroll: Option<String>,
 
     /// Last active anchor for maintaining continuous highlighting
     last_active_anchor: Option<String>,
 
+    /// Named marks (`m{char}`/`'{char}`), keyed by the mark character.
+    marks: HashMap<char, MarkPosition>,
+
+    /// Cross-chapter mark jump awaiting the caller to switch chapters, as
+    /// `(chapter_file, node_index)`. Set by `jump_to_mark` when the mark
+    /// points outside the current chapter, since this reader doesn't own
+    /// chapter navigation itself.
+    pending_mark_navigation: Option<(String, usize)>,
+
+    /// Active TOC picker overlay, if `open_toc_picker` has been called and
+    /// not yet confirmed/closed.
+    toc_picker: Option<TocPickerState>,
+
+    /// Active URL hint overlay, if `enter_hint_mode` has been called and not
+    /// yet resolved/cancelled. See [`HintState`].
+    hint_state: Option<HintState>,
+
     /// Book comments to display alongside paragraphs
     book_comments: Option<Arc<Mutex<BookComments>>>,
 
@@ -249,6 +1085,10 @@ pub struct MarkdownTextReader {
     comment_textarea: Option<TextArea<'static>>,
     comment_target_node_index: Option<usize>, // The node index where comment will be attached
     comment_target_line: Option<usize>,       // The visual line where textarea should appear
+    /// Snippet of the paragraph text the comment will attach to, shown in the
+    /// editor's header so it stays legible even once `comment_target_line`
+    /// scrolls offscreen.
+    comment_target_preview: Option<String>,
 }
 
 /// Represents the active section being read
@@ -472,6 +1312,7 @@ impl MarkdownTextReader {
                 lines: Vec::new(),
                 total_height: 0,
                 generation: 0,
+                toc: Vec::new(),
             },
             scroll_offset: 0,
             content_length: 0,
@@ -489,27 +1330,52 @@ impl MarkdownTextReader {
             raw_text_lines: Vec::new(),
             last_content_area: None,
             last_inner_text_area: None,
+            last_gutter_area: None,
             auto_scroll_active: false,
             auto_scroll_speed: 1.0,
             image_picker,
             embedded_images: RefCell::new(HashMap::new()),
             background_loader: BackgroundImageLoader::new(),
+            resized_image_cache: RefCell::new(HashMap::new()),
             pending_node_restore: None,
             raw_html_content: None,
             show_raw_html: false,
             links: Vec::new(),
             embedded_tables: RefCell::new(Vec::new()),
             anchor_positions: HashMap::new(),
+            used_anchor_slugs: HashMap::new(),
+            syntax_set: SyntaxSet::load_defaults_newlines(),
+            syntax_highlighting_enabled: true,
+            code_highlight_cache: HashMap::new(),
+            footnote_numbers: HashMap::new(),
+            osc8_hyperlinks: Osc8Mode::default(),
+            render_theme: RenderTheme::default(),
+            scrollbar_markers: ScrollbarMarkerComputer::new(),
+            annotations: HashMap::new(),
+            hovered_link: None,
+            vi_cursor: None,
+            vi_cursor_mode: ViCursorMode::Normal,
+            vi_cursor_pending_g: false,
+            vi_keymap: ViKeymap::default(),
             current_chapter_file: None,
             search_state: SearchState::new(),
+            search_literal_mode: true,
+            pending_search_query: None,
+            search_scan_epoch: 0,
+            search_scan_rx: None,
             pending_anchor_scroll: None,
             last_active_anchor: None,
+            marks: HashMap::new(),
+            pending_mark_navigation: None,
+            toc_picker: None,
+            hint_state: None,
             book_comments: None,
             current_chapter_comments: HashMap::new(),
             comment_input_active: false,
             comment_textarea: None,
             comment_target_node_index: None,
             comment_target_line: None,
+            comment_target_preview: None,
         }
     }
 
@@ -549,10 +1415,27 @@ impl MarkdownTextReader {
                 let mut textarea = TextArea::default();
                 textarea.set_placeholder_text("Type your comment here...");
 
+                let preview = self.rendered_content.lines[visual_line..=last_line_of_node]
+                    .iter()
+                    .map(|line| line.raw_text.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let preview = preview.trim();
+                const PREVIEW_MAX_CHARS: usize = 80;
+                let preview = if preview.chars().count() > PREVIEW_MAX_CHARS {
+                    format!(
+                        "{}…",
+                        preview.chars().take(PREVIEW_MAX_CHARS).collect::<String>()
+                    )
+                } else {
+                    preview.to_string()
+                };
+
                 self.comment_input_active = true;
                 self.comment_textarea = Some(textarea);
                 self.comment_target_node_index = Some(node_idx);
                 self.comment_target_line = Some(last_line_of_node + 1);
+                self.comment_target_preview = Some(preview);
 
                 self.text_selection.clear_selection();
 
@@ -579,6 +1462,20 @@ impl MarkdownTextReader {
                 _ => {
                     // Pass the input to the textarea
                     textarea.input(input);
+
+                    // The editor itself stays docked and reachable regardless
+                    // of scroll position, but typing blind with the target
+                    // paragraph scrolled out of view is still disorienting -
+                    // bring it back into view so each keystroke keeps showing
+                    // the context being commented on.
+                    if let Some(target_line) = self.comment_target_line {
+                        if target_line < self.scroll_offset
+                            || target_line >= self.scroll_offset + self.visible_height
+                        {
+                            self.scroll_to_line(target_line);
+                        }
+                    }
+
                     return true;
                 }
             }
@@ -619,11 +1516,19 @@ impl MarkdownTextReader {
 
         self.rebuild_chapter_comments();
 
+        // Bring the target paragraph back into view now that the docked
+        // editor (which stayed reachable regardless of scroll position) is
+        // closing, so the user lands back where the comment was made.
+        if let Some(target_line) = self.comment_target_line {
+            self.scroll_to_line(target_line);
+        }
+
         // Clear comment input state AFTER rebuilding so the re-render doesn't try to show textarea
         self.comment_input_active = false;
         self.comment_textarea = None;
         self.comment_target_node_index = None;
         self.comment_target_line = None;
+        self.comment_target_preview = None;
 
         self.cache_generation += 1;
         debug!("Invalidated render cache");
@@ -705,11 +1610,28 @@ impl MarkdownTextReader {
         // Clear previous state before re-rendering
         self.raw_text_lines.clear();
         self.anchor_positions.clear();
+        self.used_anchor_slugs.clear();
+        let mut toc_builder = TocBuilder::new();
+
+        // First pass: number footnote definitions in document order, so a
+        // reference can show its number even when it appears before the
+        // definition it points to. Definitions are rendered together in the
+        // footnotes section below rather than inline at their own node, so
+        // their content is held onto here instead of being re-scanned later.
+        self.footnote_numbers.clear();
+        let mut footnote_defs: Vec<(String, Vec<Node>)> = Vec::new();
+        for node in &doc.blocks {
+            if let MarkdownBlock::FootnoteDefinition { tag, content } = &node.block {
+                self.footnote_numbers
+                    .insert(tag.clone(), footnote_defs.len() + 1);
+                footnote_defs.push((tag.clone(), content.clone()));
+            }
+        }
 
         // Iterate through all blocks in the document
         for (node_idx, node) in doc.blocks.iter().enumerate() {
             // Track anchors before rendering each block
-            self.extract_and_track_anchors_from_node(node, total_height);
+            self.extract_and_track_anchors_from_node(node, total_height, &mut toc_builder);
 
             self.render_node(
                 node,
@@ -723,6 +1645,26 @@ impl MarkdownTextReader {
             );
         }
 
+        // Footnotes section: only emitted when the chapter actually defined
+        // at least one footnote.
+        if !footnote_defs.is_empty() {
+            self.render_thematic_break(&mut lines, &mut total_height, width, palette, is_focused);
+
+            for (tag, content) in &footnote_defs {
+                let number = self.footnote_numbers.get(tag).copied().unwrap_or(0);
+                self.render_footnote_definition(
+                    tag,
+                    number,
+                    content,
+                    &mut lines,
+                    &mut total_height,
+                    width,
+                    palette,
+                    is_focused,
+                );
+            }
+        }
+
         // Collect all links from rendered lines
         self.links.clear();
         for rendered_line in &lines {
@@ -762,21 +1704,37 @@ impl MarkdownTextReader {
             lines,
             total_height,
             generation: self.cache_generation,
+            toc: toc_builder.into_entries(),
         }
     }
 
-    fn extract_and_track_anchors_from_node(&mut self, node: &Node, current_line: usize) {
+    fn extract_and_track_anchors_from_node(
+        &mut self,
+        node: &Node,
+        current_line: usize,
+        toc_builder: &mut TocBuilder,
+    ) {
         if let Some(html_id) = &node.id {
             self.anchor_positions.insert(html_id.clone(), current_line);
+            // Register the explicit id so a later generated slug can't clash
+            // with it, without itself consuming a disambiguation suffix.
+            self.used_anchor_slugs.entry(html_id.clone()).or_insert(0);
         }
 
         match &node.block {
-            MarkdownBlock::Heading { content, .. } => {
-                if node.id.is_none() {
-                    let heading_text = self.text_to_string(content);
-                    let anchor_id = self.generate_heading_anchor(&heading_text);
-                    self.anchor_positions.insert(anchor_id, current_line);
-                }
+            MarkdownBlock::Heading { content, level } => {
+                let heading_text = self.text_to_string(content);
+                let anchor_id = match &node.id {
+                    Some(html_id) => html_id.clone(),
+                    None => {
+                        let base = self.generate_heading_anchor(&heading_text);
+                        let generated = self.derive_unique_anchor_id(base);
+                        self.anchor_positions
+                            .insert(generated.clone(), current_line);
+                        generated
+                    }
+                };
+                toc_builder.push(*level as u8, heading_text, anchor_id, current_line);
                 self.extract_inline_anchors_from_text(content, current_line);
             }
             MarkdownBlock::Paragraph { content } => {
@@ -798,6 +1756,32 @@ impl MarkdownTextReader {
             .join("-")
     }
 
+    /// Disambiguates `base` against every slug handed out so far this render
+    /// pass (including explicit `node.id`s), rustdoc `derive_id`-style: the
+    /// first occurrence of a base is returned unchanged, and every
+    /// subsequent collision appends `-1`, `-2`, ... until the candidate
+    /// itself is free - not just incremented once, since `base-N` can
+    /// itself already be taken by an explicit `node.id` or an earlier
+    /// generated suffix. Runs in document order so generated anchors stay
+    /// stable across renders.
+    fn derive_unique_anchor_id(&mut self, base: String) -> String {
+        if !self.used_anchor_slugs.contains_key(&base) {
+            self.used_anchor_slugs.insert(base.clone(), 0);
+            return base;
+        }
+
+        let mut suffix = self.used_anchor_slugs.get(&base).copied().unwrap_or(0);
+        loop {
+            suffix += 1;
+            let candidate = format!("{base}-{suffix}");
+            if !self.used_anchor_slugs.contains_key(&candidate) {
+                self.used_anchor_slugs.insert(candidate.clone(), 0);
+                *self.used_anchor_slugs.get_mut(&base).unwrap() = suffix;
+                return candidate;
+            }
+        }
+    }
+
     /// Extract inline anchors from text content
     fn extract_inline_anchors_from_text(&mut self, text: &MarkdownText, current_line: usize) {
         for item in text.iter() {
@@ -805,6 +1789,10 @@ impl MarkdownTextReader {
                 TextOrInline::Inline(Inline::Anchor { id }) => {
                     self.anchor_positions.insert(id.clone(), current_line);
                 }
+                TextOrInline::Inline(Inline::FootnoteReference { tag }) => {
+                    self.anchor_positions
+                        .insert(format!("fnref-{tag}"), current_line);
+                }
                 _ => {}
             }
         }
@@ -878,6 +1866,7 @@ impl MarkdownTextReader {
                     palette,
                     is_focused,
                     indent,
+                    node_index,
                 );
             }
 
@@ -943,27 +1932,77 @@ impl MarkdownTextReader {
                     is_focused,
                 );
             }
+
+            FootnoteDefinition { .. } => {
+                // Collected up-front by `render_document_to_lines` and
+                // rendered together in the trailing footnotes section
+                // instead of inline at their definition site.
+            }
         }
 
         // If this node has an anchor ID and we created new lines, attach it to the first line
-        if let Some(anchor) = current_node_anchor {
+        if let Some(anchor) = &current_node_anchor {
             if initial_line_count < lines.len() {
                 if let Some(line) = lines.get_mut(initial_line_count) {
-                    line.node_anchor = Some(anchor);
+                    line.node_anchor = Some(anchor.clone());
                 }
             }
         }
 
-        // Set the node_index on the first line created for this node (if any)
+        // Set the node_index on every line created for this node, tagging each
+        // with its wrap `portion` (0 for the node's first line, 1 for its
+        // second wrapped line, ...) and the grapheme `char_offset` into the
+        // node's raw text where that portion starts. This lets scroll
+        // restoration target the exact wrapped line a reader was on, not just
+        // the start of the block.
         if let Some(idx) = node_index {
-            if start_lines_count < lines.len() {
-                if let Some(line) = lines.get_mut(start_lines_count) {
+            let mut char_offset = 0usize;
+            for (portion, line_idx) in (start_lines_count..lines.len()).enumerate() {
+                if let Some(line) = lines.get_mut(line_idx) {
                     line.node_index = Some(idx);
+                    line.portion = portion;
+                    line.char_offset = char_offset;
                     debug!(
-                        "Assigned node_index {} to line {} (line type: {:?})",
-                        idx, start_lines_count, line.line_type
+                        "Assigned node_index {} portion {} char_offset {} to line {} (line type: {:?})",
+                        idx, portion, char_offset, line_idx, line.line_type
                     );
                 }
+                char_offset += self
+                    .raw_text_lines
+                    .get(line_idx)
+                    .map(|l| l.graphemes(true).count())
+                    .unwrap_or(0);
+            }
+        }
+
+        // Inject any virtual annotations attached to this node's anchor or
+        // to the rendered line it started at. `raw_text_lines` gets an empty
+        // string for each, mirroring `render_image_placeholder`, so these
+        // rows stay invisible to search/selection/copy.
+        let mut annotation_keys = Vec::new();
+        if let Some(anchor) = &current_node_anchor {
+            annotation_keys.push(AnnotationKey::Anchor(anchor.clone()));
+        }
+        if start_lines_count < lines.len() {
+            annotation_keys.push(AnnotationKey::Line(start_lines_count));
+        }
+        for key in annotation_keys {
+            if let Some(annotations) = self.annotations.get(&key).cloned() {
+                for annotation in annotations {
+                    lines.push(RenderedLine {
+                        spans: vec![Span::styled(annotation.text, annotation.style)],
+                        raw_text: String::new(),
+                        line_type: LineType::Annotation,
+                        link_nodes: vec![],
+                        node_anchor: None,
+                        node_index: None,
+                    
+                    portion: 0,
+                    char_offset: 0,
+});
+                    self.raw_text_lines.push(String::new());
+                    *total_height += 1;
+                }
             }
         }
     }
@@ -988,6 +2027,9 @@ impl MarkdownTextReader {
                     Inline::Anchor { .. } => {
                         // Anchors don't contribute to text content
                     }
+                    Inline::FootnoteReference { .. } => {
+                        // Reference markers aren't part of the plain-text content
+                    }
                     Inline::LineBreak => {
                         result.push('\n');
                     }
@@ -1013,8 +2055,9 @@ impl MarkdownTextReader {
         // Convert heading content to string for wrapping
         let heading_text = self.text_to_string(content);
 
-        // Apply H1 uppercase transformation if needed
-        let display_text = if level == HeadingLevel::H1 {
+        // Apply H1 uppercase transformation if the theme calls for it
+        let display_text = if level == HeadingLevel::H1 && self.render_theme.characters.uppercase_h1
+        {
             heading_text.to_uppercase()
         } else {
             heading_text.clone()
@@ -1024,17 +2067,8 @@ impl MarkdownTextReader {
         let wrapped = textwrap::wrap(&display_text, width);
 
         // Style for headings
-        let heading_color = if is_focused {
-            palette.base_0a // Yellow
-        } else {
-            palette.base_03 // Dimmed
-        };
-
-        let modifiers = match level {
-            HeadingLevel::H3 => Modifier::BOLD | Modifier::UNDERLINED,
-            HeadingLevel::H4 => Modifier::BOLD | Modifier::UNDERLINED,
-            _ => Modifier::BOLD,
-        };
+        let heading_color = self.render_theme.heading_color(palette, is_focused);
+        let modifiers = self.render_theme.heading_modifier(level);
 
         // Add wrapped heading lines
         for wrapped_line in wrapped {
@@ -1055,20 +2089,17 @@ impl MarkdownTextReader {
                 link_nodes: vec![],
                 node_anchor: None,
                 node_index: None,
-            });
+            
+            portion: 0,
+            char_offset: 0,
+});
 
             self.raw_text_lines.push(wrapped_line.to_string());
             *total_height += 1;
         }
 
-        // Add decoration line for H1-H3
-        if matches!(level, HeadingLevel::H1 | HeadingLevel::H2) {
-            let decoration = match level {
-                HeadingLevel::H1 => "═".repeat(width), // Thick line
-                HeadingLevel::H2 => "─".repeat(width), // Double line
-                _ => unreachable!(),
-            };
-
+        // Add decoration line for H1-H2, if the theme defines one
+        if let Some(decoration) = self.render_theme.heading_decoration(level, width) {
             lines.push(RenderedLine {
                 spans: vec![Span::styled(
                     decoration.clone(),
@@ -1082,7 +2113,10 @@ impl MarkdownTextReader {
                 link_nodes: vec![],
                 node_anchor: None,
                 node_index: None,
-            });
+            
+            portion: 0,
+            char_offset: 0,
+});
 
             self.raw_text_lines.push(decoration);
             *total_height += 1;
@@ -1096,7 +2130,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -1112,12 +2149,13 @@ impl MarkdownTextReader {
         indent: usize,
     ) {
         debug!("rendering comments!");
+        let quote_color = self.render_theme.quote_color(palette);
         let comment_header = format!("Note // {}", comment.updated_at.format("%m-%d-%y %H:%M"));
 
         lines.push(RenderedLine {
             spans: vec![Span::styled(
                 comment_header.clone(),
-                RatatuiStyle::default().fg(palette.base_0e), // Purple text color
+                RatatuiStyle::default().fg(quote_color),
             )],
             raw_text: String::new(),
             line_type: LineType::Comment {
@@ -1128,11 +2166,14 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(comment_header);
         *total_height += 1;
 
-        let quote_prefix = "> ";
+        let quote_prefix = self.render_theme.characters.quote_prefix;
         let effective_width = width.saturating_sub(indent + quote_prefix.len());
 
         let wrapped_lines = textwrap::wrap(&comment.content, effective_width);
@@ -1142,7 +2183,7 @@ impl MarkdownTextReader {
             lines.push(RenderedLine {
                 spans: vec![Span::styled(
                     quoted_line.clone(),
-                    RatatuiStyle::default().fg(palette.base_0e), // Purple text color
+                    RatatuiStyle::default().fg(quote_color),
                 )],
                 raw_text: line.to_string(),
                 line_type: LineType::Comment {
@@ -1153,7 +2194,10 @@ impl MarkdownTextReader {
                 link_nodes: vec![],
                 node_anchor: None,
                 node_index: None,
-            });
+            
+            portion: 0,
+            char_offset: 0,
+});
             self.raw_text_lines.push(quoted_line);
             *total_height += 1;
         }
@@ -1170,7 +2214,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -1239,7 +2286,10 @@ impl MarkdownTextReader {
                 link_nodes: vec![],
                 node_anchor: None,
                 node_index: None,
-            });
+            
+            portion: 0,
+            char_offset: 0,
+});
             self.raw_text_lines.push(String::new());
             *total_height += 1;
         }
@@ -1301,36 +2351,8 @@ impl MarkdownTextReader {
                         };
 
                         // Determine styling based on link type
-                        let (link_color, link_modifier) = if is_focused {
-                            match link_type {
-                                Some(crate::markdown::LinkType::External) => {
-                                    (palette.base_0c, Modifier::UNDERLINED) // Cyan + underlined
-                                }
-                                Some(crate::markdown::LinkType::InternalChapter) => {
-                                    (palette.base_0b, Modifier::UNDERLINED | Modifier::BOLD) // Green + bold underlined
-                                }
-                                Some(crate::markdown::LinkType::InternalAnchor) => {
-                                    (palette.base_0a, Modifier::UNDERLINED | Modifier::ITALIC) // Yellow + italic underlined
-                                }
-                                None => {
-                                    (palette.base_0c, Modifier::UNDERLINED) // Default to external style
-                                }
-                            }
-                        } else {
-                            // Unfocused state - use muted colors but maintain differentiation
-                            match link_type {
-                                Some(crate::markdown::LinkType::External) => {
-                                    (palette.base_03, Modifier::UNDERLINED)
-                                }
-                                Some(crate::markdown::LinkType::InternalChapter) => {
-                                    (palette.base_03, Modifier::UNDERLINED | Modifier::BOLD)
-                                }
-                                Some(crate::markdown::LinkType::InternalAnchor) => {
-                                    (palette.base_03, Modifier::UNDERLINED | Modifier::ITALIC)
-                                }
-                                None => (palette.base_03, Modifier::UNDERLINED),
-                            }
-                        };
+                        let (link_color, link_modifier) =
+                            self.render_theme.link_style(link_type, is_focused, palette);
 
                         let styled_span = Span::styled(
                             link_text_str,
@@ -1346,14 +2368,49 @@ impl MarkdownTextReader {
                     }
 
                     Inline::Image { alt_text, .. } => {
-                        rich_spans
-                            .push(RichSpan::Text(Span::raw(format!("[image: {}]", alt_text))));
+                        rich_spans.push(RichSpan::Text(Span::raw(
+                            self.render_theme.image_placeholder_text(alt_text),
+                        )));
                     }
 
                     Inline::Anchor { .. } => {
                         // Anchors don't produce visible content - position tracking is handled elsewhere
                     }
 
+                    Inline::FootnoteReference { tag } => {
+                        let marker_text = match self.footnote_numbers.get(tag) {
+                            Some(number) => format!("[{number}]"),
+                            None => "[?]".to_string(),
+                        };
+
+                        let link_info = LinkInfo {
+                            text: marker_text.clone(),
+                            url: String::new(),
+                            line: 0,
+                            start_col: 0,
+                            end_col: 0,
+                            link_type: Some(crate::markdown::LinkType::InternalAnchor),
+                            target_chapter: None,
+                            target_anchor: Some(format!("fn-{tag}")),
+                        };
+
+                        let (link_color, link_modifier) = if is_focused {
+                            (palette.base_0a, Modifier::UNDERLINED | Modifier::ITALIC)
+                        } else {
+                            (palette.base_03, Modifier::UNDERLINED | Modifier::ITALIC)
+                        };
+
+                        rich_spans.push(RichSpan::Link {
+                            span: Span::styled(
+                                marker_text,
+                                RatatuiStyle::default()
+                                    .fg(link_color)
+                                    .add_modifier(link_modifier),
+                            ),
+                            info: link_info,
+                        });
+                    }
+
                     Inline::LineBreak => {
                         rich_spans.push(RichSpan::Text(Span::raw("\n")));
                     }
@@ -1405,38 +2462,68 @@ impl MarkdownTextReader {
         content: &str,
         lines: &mut Vec<RenderedLine>,
         total_height: &mut usize,
-        _width: usize, //todo: not supported yet
+        width: usize,
         palette: &Base16Palette,
         is_focused: bool,
     ) {
-        // TODO: Implement syntax highlighting if language is provided
         let code_lines: Vec<&str> = content.lines().collect();
+        let highlighted = if self.syntax_highlighting_enabled {
+            language.and_then(|lang| self.highlight_code_lines(content, lang, palette))
+        } else {
+            None
+        };
 
-        for code_line in code_lines {
-            let styled_span = Span::styled(
-                code_line.to_string(),
-                RatatuiStyle::default()
-                    .fg(if is_focused {
-                        palette.base_0b
-                    } else {
-                        palette.base_03
-                    })
-                    .bg(palette.base_00),
-            );
+        for (idx, code_line) in code_lines.iter().enumerate() {
+            let spans = match &highlighted {
+                Some(highlighted_lines) if idx < highlighted_lines.len() => {
+                    highlighted_lines[idx].clone()
+                }
+                _ => vec![Span::styled(
+                    code_line.to_string(),
+                    RatatuiStyle::default()
+                        .fg(if is_focused {
+                            palette.base_0b
+                        } else {
+                            palette.base_03
+                        })
+                        .bg(palette.base_00),
+                )],
+            };
 
-            lines.push(RenderedLine {
-                spans: vec![styled_span],
-                raw_text: code_line.to_string(),
-                line_type: LineType::CodeBlock {
-                    language: language.map(String::from),
-                },
-                link_nodes: vec![],
-                node_anchor: None,
-                node_index: None,
-            });
+            // Code lines aren't pre-wrapped like `render_text_spans`' prose,
+            // so a long one would otherwise only get a single `RenderedLine`
+            // and rely on the `Paragraph`'s own `Wrap` to reflow it onto
+            // several terminal rows - breaking the "one RenderedLine, one
+            // screen row" assumption scrolling/highlighting/image clipping
+            // all depend on. Wrap it here the same way prose does.
+            let ranges = Self::wrap_line_boundaries(code_line, width);
+            let ranges = if ranges.is_empty() {
+                vec![(0, 0)]
+            } else {
+                ranges
+            };
 
-            self.raw_text_lines.push(code_line.to_string());
-            *total_height += 1;
+            for (seg_start, seg_end) in ranges {
+                let seg_spans = Self::slice_spans_by_byte_range(&spans, seg_start, seg_end);
+                let seg_text = code_line[seg_start..seg_end].to_string();
+
+                lines.push(RenderedLine {
+                    spans: seg_spans,
+                    raw_text: seg_text.clone(),
+                    line_type: LineType::CodeBlock {
+                        language: language.map(String::from),
+                    },
+                    link_nodes: vec![],
+                    node_anchor: None,
+                    node_index: None,
+
+                    portion: 0,
+                    char_offset: 0,
+                });
+
+                self.raw_text_lines.push(seg_text);
+                *total_height += 1;
+            }
         }
 
         // Add empty line after code block
@@ -1447,11 +2534,119 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
 
+    /// Tokenizes `content` with `syntect`'s incremental line parser and maps
+    /// each token's topmost scope to the active `Base16Palette`. Returns
+    /// `None` (caller falls back to uncolored rendering) when `language`
+    /// isn't a syntax `syntax_set` recognizes. Memoized in
+    /// `code_highlight_cache` by `(language, content)` so scrolling, resizing,
+    /// or toggling focus - none of which change a block's source text -
+    /// never re-runs the tokenizer for a block already seen this session.
+    fn highlight_code_lines(
+        &mut self,
+        content: &str,
+        language: &str,
+        palette: &Base16Palette,
+    ) -> Option<Vec<Vec<Span<'static>>>> {
+        let cache_key = (language.to_string(), content.to_string());
+        if let Some(cached) = self.code_highlight_cache.get(&cache_key) {
+            return Some(cached.clone());
+        }
+
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_token(language)
+            .or_else(|| self.syntax_set.find_syntax_by_extension(language))?;
+
+        let mut parse_state = ParseState::new(syntax);
+        let mut scope_stack = ScopeStack::new();
+        let mut rendered_lines = Vec::new();
+
+        for line in LinesWithEndings::from(content) {
+            let ops = parse_state.parse_line(line, &self.syntax_set).ok()?;
+
+            let mut spans = Vec::new();
+            let mut cursor = 0usize;
+            for (offset, op) in ops {
+                if offset > cursor {
+                    spans.push(Self::styled_token(&line[cursor..offset], &scope_stack, palette));
+                    cursor = offset;
+                }
+                let _ = scope_stack.apply(&op);
+            }
+            if cursor < line.len() {
+                spans.push(Self::styled_token(&line[cursor..], &scope_stack, palette));
+            }
+
+            // `LinesWithEndings` keeps the line's trailing newline so syntect
+            // sees the terminator; drop it from the rendered span, it was
+            // only needed for parsing.
+            if let Some(last) = spans.last_mut() {
+                if last.content.ends_with('\n') {
+                    let trimmed = last.content.trim_end_matches('\n').to_string();
+                    *last = Span::styled(trimmed, last.style);
+                }
+            }
+
+            rendered_lines.push(spans);
+        }
+
+        self.code_highlight_cache
+            .insert(cache_key, rendered_lines.clone());
+        Some(rendered_lines)
+    }
+
+    /// Color for one highlighted token, from the innermost scope on the
+    /// stack that we recognize, falling back to plain text.
+    fn styled_token(
+        text: &str,
+        scope_stack: &ScopeStack,
+        palette: &Base16Palette,
+    ) -> Span<'static> {
+        let color = scope_stack
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| Self::color_for_scope(&scope.to_string(), palette))
+            .unwrap_or(palette.base_05);
+
+        Span::styled(
+            text.to_string(),
+            RatatuiStyle::default().fg(color).bg(palette.base_00),
+        )
+    }
+
+    /// Maps a dotted TextMate scope name (e.g. `keyword.control.rust`) to a
+    /// Base16 slot, by prefix - the standard way consumers of syntect scopes
+    /// pick colors since the scope hierarchy is broad-to-narrow.
+    fn color_for_scope(scope_name: &str, palette: &Base16Palette) -> Option<Color> {
+        if scope_name.starts_with("comment") {
+            Some(palette.base_03)
+        } else if scope_name.starts_with("string") || scope_name.starts_with("constant.character")
+        {
+            Some(palette.base_0b)
+        } else if scope_name.starts_with("constant.numeric") {
+            Some(palette.base_09)
+        } else if scope_name.starts_with("entity.name.type")
+            || scope_name.starts_with("storage.type")
+            || scope_name.starts_with("support.type")
+            || scope_name.starts_with("support.class")
+        {
+            Some(palette.base_0a)
+        } else if scope_name.starts_with("keyword") || scope_name.starts_with("storage.modifier") {
+            Some(palette.base_0e)
+        } else {
+            None
+        }
+    }
+
     fn render_list(
         &mut self,
         kind: &crate::markdown::ListKind,
@@ -1462,13 +2657,16 @@ impl MarkdownTextReader {
         palette: &Base16Palette,
         is_focused: bool,
         indent: usize,
+        node_index: Option<usize>,
     ) {
-        use crate::markdown::ListKind;
+        use crate::markdown::{ListKind, TaskStatus};
 
         for (idx, item) in items.iter().enumerate() {
-            // Determine bullet/number for this item
+            // Determine bullet/number for this item (task items get a
+            // checkbox glyph instead, inserted as its own styled span below
+            // so it can be colored independently of a plain text prefix)
             let prefix = match kind {
-                ListKind::Unordered => "• ".to_string(),
+                ListKind::Unordered => self.render_theme.characters.bullet.to_string(),
                 ListKind::Ordered { start } => {
                     let num = start + idx as u32;
                     format!("{}. ", num)
@@ -1479,7 +2677,7 @@ impl MarkdownTextReader {
             // List items can contain multiple blocks (paragraphs, nested lists, etc.)
             for (block_idx, block_node) in item.content.iter().enumerate() {
                 if block_idx == 0 {
-                    // First block gets the bullet/number prefix
+                    // First block gets the bullet/number/checkbox prefix
                     match &block_node.block {
                         MarkdownBlock::Paragraph { content } => {
                             // Render the rich text content with prefix and indentation
@@ -1492,9 +2690,26 @@ impl MarkdownTextReader {
                             // Store original line count to update line types after
                             let lines_before = lines.len();
 
+                            let line_prefix = match &item.task_status {
+                                Some(status) => {
+                                    let (glyph, color) = self
+                                        .render_theme
+                                        .checkbox_glyph(*status == TaskStatus::Checked, palette);
+                                    content_rich_spans.insert(
+                                        0,
+                                        RichSpan::Text(Span::styled(
+                                            glyph,
+                                            RatatuiStyle::default().fg(color),
+                                        )),
+                                    );
+                                    None
+                                }
+                                None => Some(prefix.as_str()),
+                            };
+
                             self.render_text_spans(
                                 &content_rich_spans,
-                                Some(&prefix), // add bullet/number prefix
+                                line_prefix,
                                 lines,
                                 total_height,
                                 width,
@@ -1504,9 +2719,17 @@ impl MarkdownTextReader {
 
                             // Update line types for all newly added lines
                             for line in &mut lines[lines_before..] {
-                                line.line_type = LineType::ListItem {
-                                    kind: kind.clone(),
-                                    indent,
+                                line.line_type = match &item.task_status {
+                                    Some(status) => LineType::TaskListItem {
+                                        node_index,
+                                        item_index: idx,
+                                        checked: matches!(status, TaskStatus::Checked),
+                                        indent,
+                                    },
+                                    None => LineType::ListItem {
+                                        kind: kind.clone(),
+                                        indent,
+                                    },
                                 };
                             }
                         }
@@ -1548,7 +2771,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -1557,7 +2783,7 @@ impl MarkdownTextReader {
         &mut self,
         header: &Option<crate::markdown::TableRow>,
         rows: &[crate::markdown::TableRow],
-        _alignment: &[crate::markdown::TableAlignment],
+        alignment: &[crate::markdown::TableAlignment],
         lines: &mut Vec<RenderedLine>,
         total_height: &mut usize,
         width: usize,
@@ -1618,9 +2844,24 @@ impl MarkdownTextReader {
             use_block: false,
         };
 
+        // Translate the parsed delimiter-row alignment into the widget's own
+        // `Alignment` enum (columns without an explicit marker stay `None` →
+        // `Left`).
+        let cell_alignments: Vec<TableCellAlignment> = alignment
+            .iter()
+            .map(|a| match a {
+                crate::markdown::TableAlignment::Center => TableCellAlignment::Center,
+                crate::markdown::TableAlignment::Right => TableCellAlignment::Right,
+                crate::markdown::TableAlignment::Left | crate::markdown::TableAlignment::None => {
+                    TableCellAlignment::Left
+                }
+            })
+            .collect();
+
         // Create the table widget
         let mut custom_table = CustomTable::new(table_rows.clone())
             .constraints(constraints)
+            .alignments(cell_alignments)
             .config(table_config);
 
         if !table_headers.is_empty() {
@@ -1646,7 +2887,10 @@ impl MarkdownTextReader {
                 link_nodes: vec![],
                 node_anchor: None,
                 node_index: None,
-            };
+            
+            portion: 0,
+            char_offset: 0,
+};
 
             lines.push(rendered_line);
             self.raw_text_lines.push(raw_text);
@@ -1682,7 +2926,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -1886,7 +3133,127 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
+        self.raw_text_lines.push(String::new());
+        *total_height += 1;
+    }
+
+    /// Renders one footnote's definition in the trailing footnotes section:
+    /// a quote-styled block numbered to match its reference marker, with a
+    /// back-reference link (`↩`) on the end that jumps back to the citing
+    /// marker via the `fnref-{tag}` anchor registered for it during the
+    /// anchor-tracking pass.
+    fn render_footnote_definition(
+        &mut self,
+        tag: &str,
+        number: usize,
+        content: &[Node],
+        lines: &mut Vec<RenderedLine>,
+        total_height: &mut usize,
+        width: usize,
+        palette: &Base16Palette,
+        is_focused: bool,
+    ) {
+        self.anchor_positions
+            .insert(format!("fn-{tag}"), *total_height);
+
+        let prefix = format!("[{number}] ");
+        let quote_color = if is_focused {
+            palette.base_03
+        } else {
+            palette.base_02
+        };
+        let last_index = content.len().saturating_sub(1);
+
+        for (idx, node) in content.iter().enumerate() {
+            match &node.block {
+                MarkdownBlock::Paragraph {
+                    content: para_content,
+                } => {
+                    let mut rich_spans = Vec::new();
+                    for item in para_content.iter() {
+                        rich_spans.extend(self.render_text_or_inline(item, palette, is_focused));
+                    }
+
+                    if idx == last_index {
+                        let back_link_info = LinkInfo {
+                            text: "\u{21a9}".to_string(),
+                            url: String::new(),
+                            line: 0,
+                            start_col: 0,
+                            end_col: 0,
+                            link_type: Some(crate::markdown::LinkType::InternalAnchor),
+                            target_chapter: None,
+                            target_anchor: Some(format!("fnref-{tag}")),
+                        };
+                        rich_spans.push(RichSpan::Link {
+                            span: Span::styled(
+                                " \u{21a9}",
+                                RatatuiStyle::default()
+                                    .fg(quote_color)
+                                    .add_modifier(Modifier::UNDERLINED),
+                            ),
+                            info: back_link_info,
+                        });
+                    }
+
+                    let styled_rich_spans: Vec<RichSpan> = rich_spans
+                        .into_iter()
+                        .map(|rich_span| match rich_span {
+                            RichSpan::Text(span) => RichSpan::Text(Span::styled(
+                                span.content.clone(),
+                                span.style.fg(quote_color).add_modifier(Modifier::ITALIC),
+                            )),
+                            RichSpan::Link { span, info } => RichSpan::Link {
+                                span: Span::styled(
+                                    span.content.clone(),
+                                    span.style.fg(quote_color).add_modifier(Modifier::ITALIC),
+                                ),
+                                info,
+                            },
+                        })
+                        .collect();
+
+                    self.render_text_spans(
+                        &styled_rich_spans,
+                        Some(&prefix),
+                        lines,
+                        total_height,
+                        width,
+                        0,
+                        false, // don't add empty line after
+                    );
+                }
+                _ => {
+                    self.render_node(
+                        node,
+                        lines,
+                        total_height,
+                        width,
+                        palette,
+                        is_focused,
+                        1,
+                        None,
+                    );
+                }
+            }
+        }
+
+        // Add empty line after each footnote's definition
+        lines.push(RenderedLine {
+            spans: vec![Span::raw("")],
+            raw_text: String::new(),
+            line_type: LineType::Empty,
+            link_nodes: vec![],
+            node_anchor: None,
+            node_index: None,
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -1915,7 +3282,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
 
         self.raw_text_lines.push(hr_line);
         *total_height += 1;
@@ -1928,7 +3298,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -2011,7 +3384,10 @@ impl MarkdownTextReader {
                     link_nodes: vec![],
                     node_anchor: None,
                     node_index: None,
-                });
+                
+                portion: 0,
+                char_offset: 0,
+});
                 self.raw_text_lines.push(String::new());
                 *total_height += 1;
             }
@@ -2025,7 +3401,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -2057,7 +3436,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(separator_line.clone());
         *total_height += 1;
         //
@@ -2069,7 +3451,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
 
@@ -2118,7 +3503,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(separator_line);
         *total_height += 1;
 
@@ -2130,11 +3518,122 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
 
+    /// Computes line-wrap boundaries as byte ranges into `text`, breaking on
+    /// whitespace as well as hyphens and em-dashes (`—`) so hyphenated and
+    /// dashed words wrap at a sensible point instead of only at spaces. A
+    /// "word" that's wider than `width` on its own is hard-split rather than
+    /// left to overflow, so no characters are ever dropped. Segmentation is
+    /// done per grapheme cluster (not `char`) and width per cluster via
+    /// `unicode-width`, so a hard split never separates a base character from
+    /// a combining mark, and wide (e.g. CJK) clusters count for their real
+    /// display width.
+    fn wrap_line_boundaries(text: &str, width: usize) -> Vec<(usize, usize)> {
+        if text.is_empty() {
+            return Vec::new();
+        }
+        if width == 0 {
+            return vec![(0, text.len())];
+        }
+
+        let mut ranges = Vec::new();
+        let mut line_start = 0usize;
+        let mut line_width = 0usize;
+        // Most recent in-line position it's safe to break: `break_end` is
+        // where the line should end, `next_start` is where the following
+        // line resumes (past a dropped whitespace character, if any).
+        let mut break_end: Option<usize> = None;
+        let mut next_start = 0usize;
+
+        for (idx, cluster) in text.grapheme_indices(true) {
+            let cluster_end = idx + cluster.len();
+            let cluster_width = UnicodeWidthStr::width(cluster);
+
+            if cluster == "\n" {
+                ranges.push((line_start, idx));
+                line_start = cluster_end;
+                line_width = 0;
+                break_end = None;
+                next_start = line_start;
+                continue;
+            }
+
+            if line_width > 0 && line_width + cluster_width > width {
+                match break_end {
+                    Some(end) => {
+                        ranges.push((line_start, end));
+                        line_start = next_start;
+                    }
+                    None => {
+                        // No break point seen yet on this line: hard-split
+                        // right before this cluster instead of dropping it.
+                        ranges.push((line_start, idx));
+                        line_start = idx;
+                        next_start = idx;
+                    }
+                }
+                break_end = None;
+                line_width = text[line_start..cluster_end]
+                    .graphemes(true)
+                    .map(UnicodeWidthStr::width)
+                    .sum();
+            } else {
+                line_width += cluster_width;
+            }
+
+            match cluster {
+                " " | "\t" => {
+                    break_end = Some(idx);
+                    next_start = cluster_end;
+                }
+                "-" | "—" => {
+                    break_end = Some(cluster_end);
+                    next_start = cluster_end;
+                }
+                _ => {}
+            }
+        }
+
+        if line_start < text.len() {
+            ranges.push((line_start, text.len()));
+        }
+
+        ranges
+    }
+
+    /// Slice a sequence of spans (whose concatenated content is the text
+    /// `wrap_line_boundaries` was called on) down to the byte range
+    /// `[start, end)`, keeping each surviving fragment's original style.
+    /// Used to carry syntax-highlighting styles across a wrapped code line.
+    fn slice_spans_by_byte_range(
+        spans: &[Span<'static>],
+        start: usize,
+        end: usize,
+    ) -> Vec<Span<'static>> {
+        let mut out = Vec::new();
+        let mut cursor = 0usize;
+        for span in spans {
+            let span_start = cursor;
+            let span_end = cursor + span.content.len();
+            cursor = span_end;
+
+            let lo = start.max(span_start);
+            let hi = end.min(span_end);
+            if lo < hi {
+                let text = &span.content[lo - span_start..hi - span_start];
+                out.push(Span::styled(text.to_string(), span.style));
+            }
+        }
+        out
+    }
+
     fn render_text_spans(
         &mut self,
         rich_spans: &[RichSpan],
@@ -2165,8 +3664,12 @@ impl MarkdownTextReader {
         let indent_str = "  ".repeat(indent);
         let available_width = width.saturating_sub(indent_str.len());
 
-        // Wrap the text
-        let wrapped = textwrap::wrap(&plain_text, available_width);
+        // Wrap the text, breaking on hyphens and em-dashes in addition to
+        // whitespace so long compound/hyphenated words wrap sensibly.
+        let wrapped: Vec<&str> = Self::wrap_line_boundaries(&plain_text, available_width)
+            .into_iter()
+            .map(|(start, end)| &plain_text[start..end])
+            .collect();
 
         // Create lines from wrapped text
         for (line_idx, wrapped_line) in wrapped.iter().enumerate() {
@@ -2187,18 +3690,38 @@ impl MarkdownTextReader {
             for rich_span in rich_spans_for_line {
                 match rich_span {
                     RichSpan::Text(span) => {
-                        let len = span.content.len();
+                        // Columns are grapheme-cluster indices, matching the
+                        // coordinate space `text_selection::screen_to_text_coords`
+                        // produces, not a byte offset.
+                        let len = span.content.graphemes(true).count();
                         line_spans.push(span);
                         current_col += len;
                     }
                     RichSpan::Link { span, mut info } => {
-                        let len = span.content.len();
+                        let len = span.content.graphemes(true).count();
                         info.line = lines.len(); // Set to current line being created
                         info.start_col = current_col;
                         info.end_col = current_col + len;
 
+                        // OSC 8 wrapping is applied to the drawn span only,
+                        // after wrapping/column math above has already used
+                        // the plain `span.content` - `raw_text` (built from
+                        // this same plain text further down) never sees it.
+                        let display_span = if self.should_emit_osc8_hyperlink(&info.link_type) {
+                            Span::styled(
+                                Self::wrap_osc8_hyperlink(
+                                    &info.url,
+                                    &span.content,
+                                    Self::osc8_link_id(&info),
+                                ),
+                                span.style,
+                            )
+                        } else {
+                            span
+                        };
+
                         line_links.push(info);
-                        line_spans.push(span);
+                        line_spans.push(display_span);
                         current_col += len;
                     }
                 }
@@ -2228,7 +3751,10 @@ impl MarkdownTextReader {
                 link_nodes: line_links, // Captured links!
                 node_anchor: None,
                 node_index: None,
-            });
+            
+            portion: 0,
+            char_offset: 0,
+});
 
             self.raw_text_lines.push(final_raw_text);
             *total_height += 1;
@@ -2243,7 +3769,10 @@ impl MarkdownTextReader {
                 link_nodes: vec![],
                 node_anchor: None,
                 node_index: None,
-            });
+            
+            portion: 0,
+            char_offset: 0,
+});
             self.raw_text_lines.push(String::new());
             *total_height += 1;
         }
@@ -2262,50 +3791,53 @@ impl MarkdownTextReader {
             self.scroll_offset,
             content_area.x,
             content_area.y,
+            &self.raw_text_lines,
         )
     }
 
-    /// Map a wrapped line back to its rich spans, preserving links
+    /// Map a wrapped line back to its rich spans, preserving links. Matching
+    /// is done per grapheme cluster (not `char`) so a base character and a
+    /// trailing combining mark are never treated as separate units and can't
+    /// end up reconstructed into different rich spans.
     fn map_wrapped_line_to_rich_spans(
         &self,
         wrapped_line: &str,
         original_rich_spans: &[RichSpan],
     ) -> Vec<RichSpan> {
         // Build a flattened representation with rich span info
-        #[derive(Clone)]
-        struct CharWithRichSpan {
-            ch: char,
-            rich_span_idx: usize,    // Index into original_rich_spans
-            char_idx_in_span: usize, // Position within the span's text
+        struct GraphemeWithRichSpan<'a> {
+            cluster: &'a str,
+            rich_span_idx: usize, // Index into original_rich_spans
         }
 
-        let mut chars_with_rich = Vec::new();
+        let mut clusters_with_rich = Vec::new();
         for (span_idx, rich_span) in original_rich_spans.iter().enumerate() {
             let span_text = match rich_span {
                 RichSpan::Text(span) => &span.content,
                 RichSpan::Link { span, .. } => &span.content,
             };
-            for (char_idx, ch) in span_text.chars().enumerate() {
-                chars_with_rich.push(CharWithRichSpan {
-                    ch,
+            for cluster in span_text.graphemes(true) {
+                clusters_with_rich.push(GraphemeWithRichSpan {
+                    cluster,
                     rich_span_idx: span_idx,
-                    char_idx_in_span: char_idx,
                 });
             }
         }
 
         // Find where this wrapped line starts in the original content
-        let wrapped_chars: Vec<char> = wrapped_line.chars().collect();
-        if wrapped_chars.is_empty() {
+        let wrapped_clusters: Vec<&str> = wrapped_line.graphemes(true).collect();
+        if wrapped_clusters.is_empty() {
             return vec![RichSpan::Text(Span::raw(""))];
         }
 
         // Find the starting position
         let mut start_pos = None;
-        for i in 0..=chars_with_rich.len().saturating_sub(wrapped_chars.len()) {
+        for i in 0..=clusters_with_rich.len().saturating_sub(wrapped_clusters.len()) {
             let mut matches = true;
-            for (j, &wrapped_ch) in wrapped_chars.iter().enumerate() {
-                if i + j >= chars_with_rich.len() || chars_with_rich[i + j].ch != wrapped_ch {
+            for (j, &wrapped_cluster) in wrapped_clusters.iter().enumerate() {
+                if i + j >= clusters_with_rich.len()
+                    || clusters_with_rich[i + j].cluster != wrapped_cluster
+                {
                     matches = false;
                     break;
                 }
@@ -2322,14 +3854,14 @@ impl MarkdownTextReader {
             let mut current_span_idx = None;
             let mut current_text = String::new();
 
-            for i in pos..pos + wrapped_chars.len() {
-                if i >= chars_with_rich.len() {
+            for i in pos..pos + wrapped_clusters.len() {
+                if i >= clusters_with_rich.len() {
                     break;
                 }
 
-                let char_info = &chars_with_rich[i];
+                let cluster_info = &clusters_with_rich[i];
 
-                if current_span_idx != Some(char_info.rich_span_idx) {
+                if current_span_idx != Some(cluster_info.rich_span_idx) {
                     // Span changed, push accumulated span
                     if !current_text.is_empty() {
                         if let Some(idx) = current_span_idx {
@@ -2351,10 +3883,10 @@ impl MarkdownTextReader {
                         }
                         current_text.clear();
                     }
-                    current_span_idx = Some(char_info.rich_span_idx);
+                    current_span_idx = Some(cluster_info.rich_span_idx);
                 }
 
-                current_text.push(char_info.ch);
+                current_text.push_str(cluster_info.cluster);
             }
 
             // Push final accumulated span
@@ -2406,7 +3938,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
 
@@ -2475,7 +4010,10 @@ impl MarkdownTextReader {
                 link_nodes: vec![],
                 node_anchor: None,
                 node_index: None,
-            });
+            
+            portion: 0,
+            char_offset: 0,
+});
 
             self.raw_text_lines.push(String::new()); // Keep raw_text_lines in sync
             *total_height += 1;
@@ -2489,7 +4027,10 @@ impl MarkdownTextReader {
             link_nodes: vec![],
             node_anchor: None,
             node_index: None,
-        });
+        
+        portion: 0,
+        char_offset: 0,
+});
         self.raw_text_lines.push(String::new());
         *total_height += 1;
     }
@@ -2520,36 +4061,1008 @@ impl MarkdownTextReader {
         self.last_active_anchor = anchor;
     }
 
-    /// Get the currently active section based on viewport position
-    pub fn get_active_section(&mut self, current_chapter: usize) -> ActiveSection {
-        // Calculate middle of viewport
-        let viewport_middle = self.scroll_offset + (self.visible_height / 2);
+    /// Save the current reading position under mark `c` (vim's `m{char}`).
+    pub fn set_mark(&mut self, c: char) {
+        let position = MarkPosition {
+            chapter_file: self.current_chapter_file.clone(),
+            node_index: self.get_current_node_index(),
+            scroll_offset: self.scroll_offset,
+        };
+        debug!(
+            "Set mark '{}' at chapter {:?}, node {}",
+            c, position.chapter_file, position.node_index
+        );
+        self.marks.insert(c, position);
+    }
 
-        // Scan backwards from middle to find the most recent anchor
-        let lines_to_check = viewport_middle.min(self.rendered_content.lines.len());
+    /// Jump to mark `c` (vim's `'{char}`). Returns `false` if no such mark
+    /// is set. If the mark is in a different chapter, the jump is deferred:
+    /// `pending_mark_navigation` is recorded for the caller to pick up via
+    /// `take_pending_mark_navigation` after it switches chapters, the same
+    /// way `pending_anchor_scroll` defers cross-chapter anchor links.
+    pub fn jump_to_mark(&mut self, c: char) -> bool {
+        let Some(position) = self.marks.get(&c).cloned() else {
+            debug!("No mark set for '{}'", c);
+            return false;
+        };
 
-        for line_idx in (0..=lines_to_check).rev() {
-            if let Some(line) = self.rendered_content.lines.get(line_idx) {
-                if let Some(ref anchor) = line.node_anchor {
-                    // Found an anchor - update and return it
-                    self.last_active_anchor = Some(anchor.clone());
-                    return ActiveSection::Anchor(anchor.clone());
-                }
+        match &position.chapter_file {
+            Some(chapter_file) if Some(chapter_file) != self.current_chapter_file.as_ref() => {
+                self.pending_mark_navigation = Some((chapter_file.clone(), position.node_index));
+            }
+            _ => {
+                self.restore_to_node_index(position.node_index);
             }
         }
 
-        // No anchor found before middle - check if we have a stored one
-        if let Some(ref anchor) = self.last_active_anchor {
-            // We still use the last known anchor if we're in the same chapter
-            // This maintains highlighting when scrolling past the last anchor
-            return ActiveSection::Anchor(anchor.clone());
-        }
+        true
+    }
 
-        // Fall back to chapter-level highlighting
-        ActiveSection::Chapter(current_chapter)
+    /// Takes the pending cross-chapter navigation request left by
+    /// `jump_to_mark`, if any, as `(chapter_file, node_index)`.
+    pub fn take_pending_mark_navigation(&mut self) -> Option<(String, usize)> {
+        self.pending_mark_navigation.take()
     }
 
-    /// Apply search highlighting to a line's spans
+    /// The chapter's hierarchical heading outline, as built by `TocBuilder`
+    /// during the last render.
+    pub fn toc(&self) -> &[TocEntry] {
+        &self.rendered_content.toc
+    }
+
+    /// Opts into (or back out of) OSC 8 hyperlink wrapping for external
+    /// links. See [`Osc8Mode`].
+    pub fn set_osc8_hyperlinks(&mut self, mode: Osc8Mode) {
+        self.osc8_hyperlinks = mode;
+    }
+
+    /// Swaps the drawing characters and palette roles used to render
+    /// headings, quotes, lists and links. See [`RenderTheme`].
+    pub fn set_render_theme(&mut self, theme: RenderTheme) {
+        self.render_theme = theme;
+    }
+
+    /// Attaches virtual annotation lines to the first rendered line of the
+    /// document node with this anchor id, replacing any previously set for
+    /// it. Takes effect on the next render.
+    pub fn set_annotations_for_anchor(&mut self, anchor: &str, annotations: Vec<VirtualAnnotation>) {
+        self.annotations
+            .insert(AnnotationKey::Anchor(anchor.to_string()), annotations);
+    }
+
+    /// Attaches virtual annotation lines right after the document node whose
+    /// first rendered line is at `line` (the rendered-line index, before any
+    /// annotations are injected), replacing any previously set for it. Takes
+    /// effect on the next render.
+    pub fn set_annotations_for_line(&mut self, line: usize, annotations: Vec<VirtualAnnotation>) {
+        self.annotations.insert(AnnotationKey::Line(line), annotations);
+    }
+
+    /// Clears every virtual annotation (anchor- and line-keyed).
+    pub fn clear_annotations(&mut self) {
+        self.annotations.clear();
+    }
+
+    /// Whether the keyboard vi-mode cursor is currently active.
+    pub fn is_vi_cursor_active(&self) -> bool {
+        self.vi_cursor.is_some()
+    }
+
+    /// The vi-mode cursor's current `(line, column)`, or `None` while
+    /// inactive.
+    pub fn vi_cursor_position(&self) -> Option<(usize, usize)> {
+        self.vi_cursor
+    }
+
+    /// Activates the vi-mode cursor in Normal mode at the top of the current
+    /// viewport, so it starts where the user is already reading.
+    pub fn enter_vi_cursor(&mut self) {
+        let line = self
+            .scroll_offset
+            .min(self.raw_text_lines.len().saturating_sub(1));
+        self.vi_cursor = Some((line, 0));
+        self.vi_cursor_mode = ViCursorMode::Normal;
+        self.vi_cursor_pending_g = false;
+    }
+
+    /// Deactivates the vi-mode cursor and drops any in-progress visual
+    /// selection.
+    pub fn exit_vi_cursor(&mut self) {
+        self.vi_cursor = None;
+        self.vi_cursor_mode = ViCursorMode::Normal;
+        self.vi_cursor_pending_g = false;
+        self.text_selection.clear_selection();
+    }
+
+    /// Routes a single key press to the active vi-mode cursor's motions and
+    /// commands. Returns whether the key was recognized; callers should
+    /// otherwise leave the key unhandled rather than falling through to
+    /// normal-mode bindings, which would stomp on the cursor/selection state.
+    pub fn handle_vi_cursor_key(&mut self, ch: char) -> bool {
+        let Some((line, col)) = self.vi_cursor else {
+            return false;
+        };
+
+        if self.vi_cursor_pending_g {
+            self.vi_cursor_pending_g = false;
+            if self.vi_keymap.motion_for(ch) == Some(ViMotion::PendingG) {
+                self.move_vi_cursor_to(0, 0);
+                return true;
+            }
+        }
+
+        let Some(motion) = self.vi_keymap.motion_for(ch) else {
+            return false;
+        };
+
+        match motion {
+            ViMotion::Left => self.move_vi_cursor_to(line, col.saturating_sub(1)),
+            ViMotion::Right => {
+                let max_col = self.vi_line_len(line);
+                self.move_vi_cursor_to(line, (col + 1).min(max_col));
+            }
+            ViMotion::Down => {
+                let new_line = (line + 1).min(self.raw_text_lines.len().saturating_sub(1));
+                let new_col = col.min(self.vi_line_len(new_line));
+                self.move_vi_cursor_to(new_line, new_col);
+            }
+            ViMotion::Up => {
+                let new_line = line.saturating_sub(1);
+                let new_col = col.min(self.vi_line_len(new_line));
+                self.move_vi_cursor_to(new_line, new_col);
+            }
+            ViMotion::LineStart => self.move_vi_cursor_to(line, 0),
+            ViMotion::LineEnd => self.move_vi_cursor_to(line, self.vi_line_len(line)),
+            ViMotion::WordForward => {
+                let (new_line, new_col) = self.vi_word_motion_forward(line, col, false);
+                self.move_vi_cursor_to(new_line, new_col);
+            }
+            ViMotion::WordEnd => {
+                let (new_line, new_col) = self.vi_word_motion_forward(line, col, true);
+                self.move_vi_cursor_to(new_line, new_col);
+            }
+            ViMotion::WordBackward => {
+                let (new_line, new_col) = self.vi_word_motion_backward(line, col);
+                self.move_vi_cursor_to(new_line, new_col);
+            }
+            ViMotion::ParagraphForward => {
+                let new_line = self.vi_paragraph_motion(line, true);
+                self.move_vi_cursor_to(new_line, 0);
+            }
+            ViMotion::ParagraphBackward => {
+                let new_line = self.vi_paragraph_motion(line, false);
+                self.move_vi_cursor_to(new_line, 0);
+            }
+            ViMotion::PendingG => {
+                self.vi_cursor_pending_g = true;
+            }
+            ViMotion::DocumentEnd => {
+                let last_line = self.raw_text_lines.len().saturating_sub(1);
+                self.move_vi_cursor_to(last_line, 0);
+            }
+            ViMotion::MatchingBracket => {
+                if let Some((new_line, new_col)) = self.find_matching_bracket(line, col) {
+                    self.move_vi_cursor_to(new_line, new_col);
+                }
+            }
+            ViMotion::EnterVisual => {
+                self.vi_cursor_mode = ViCursorMode::Visual;
+                self.text_selection.start_selection(line, col);
+            }
+            ViMotion::EnterVisualLine => {
+                self.vi_cursor_mode = ViCursorMode::VisualLine;
+                self.text_selection.start_selection(line, 0);
+                self.text_selection
+                    .update_selection(line, self.vi_line_len(line));
+            }
+            ViMotion::Yank => {
+                if self.vi_cursor_mode != ViCursorMode::Normal {
+                    let _ = self.copy_selection_to_clipboard();
+                    self.vi_cursor_mode = ViCursorMode::Normal;
+                    self.text_selection.clear_selection();
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Rebinds a vi-cursor key so it performs whatever motion `like`
+    /// currently performs (e.g. `rebind_vi_key('k', 'w')` makes `k` do
+    /// word-forward). Returns `false` if `like` isn't itself bound.
+    pub fn rebind_vi_key(&mut self, key: char, like: char) -> bool {
+        self.vi_keymap.rebind(key, like)
+    }
+
+    /// `Ctrl-d`/`Ctrl-u` while the vi-mode cursor is active: jump it half a
+    /// screen down/up (mirroring `scroll_half_screen_down`/`_up`'s distance)
+    /// rather than just scrolling, so the cursor stays where the reader's
+    /// eye lands. `move_vi_cursor_to` takes care of keeping it on screen.
+    pub fn handle_vi_cursor_ctrl(&mut self, ch: char, screen_height: usize) -> bool {
+        let Some((line, col)) = self.vi_cursor else {
+            return false;
+        };
+        let half = (screen_height / 2).max(1);
+
+        match ch {
+            'd' => {
+                let new_line = (line + half).min(self.raw_text_lines.len().saturating_sub(1));
+                self.move_vi_cursor_to(new_line, col);
+                true
+            }
+            'u' => {
+                let new_line = line.saturating_sub(half);
+                self.move_vi_cursor_to(new_line, col);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Grapheme-cluster length of `raw_text_lines[line]`, the same column
+    /// unit `wrap_line_boundaries`/`get_link_at_position` use.
+    fn vi_line_len(&self, line: usize) -> usize {
+        self.raw_text_lines
+            .get(line)
+            .map(|l| l.graphemes(true).count())
+            .unwrap_or(0)
+    }
+
+    /// Moves the vi cursor to `(line, col)` (clamped to content), extends
+    /// the active selection if in Visual/VisualLine mode, and scrolls the
+    /// viewport just enough to keep the cursor on screen.
+    fn move_vi_cursor_to(&mut self, line: usize, col: usize) {
+        let line = line.min(self.raw_text_lines.len().saturating_sub(1));
+        let col = col.min(self.vi_line_len(line));
+        self.vi_cursor = Some((line, col));
+
+        match self.vi_cursor_mode {
+            ViCursorMode::Visual => self.text_selection.update_selection(line, col),
+            ViCursorMode::VisualLine => {
+                let line_end = self.vi_line_len(line);
+                self.text_selection.update_selection(line, line_end);
+            }
+            ViCursorMode::Normal => {}
+        }
+
+        if line < self.scroll_offset {
+            self.scroll_offset = line;
+        } else if self.visible_height > 0 && line >= self.scroll_offset + self.visible_height {
+            self.scroll_offset = line + 1 - self.visible_height;
+        }
+        self.scroll_offset = self.scroll_offset.min(self.get_max_scroll_offset());
+    }
+
+    /// `w`/`e` motion: advances to the start of the next word (`end =
+    /// false`) or the end of the current/next word (`end = true`), scanning
+    /// for word/blank run boundaries and crossing into following lines when
+    /// the current one is exhausted.
+    fn vi_word_motion_forward(&self, mut line: usize, mut col: usize, end: bool) -> (usize, usize) {
+        loop {
+            let Some(raw_line) = self.raw_text_lines.get(line) else {
+                return (line.saturating_sub(1), 0);
+            };
+            let clusters: Vec<&str> = raw_line.graphemes(true).collect();
+            let len = clusters.len();
+
+            if col >= len {
+                if line + 1 >= self.raw_text_lines.len() {
+                    return (line, len);
+                }
+                line += 1;
+                col = 0;
+                continue;
+            }
+
+            let mut i = col;
+            if end {
+                i += 1;
+            } else {
+                let start_class = vi_char_class(clusters[i]);
+                while i < len && vi_char_class(clusters[i]) == start_class {
+                    i += 1;
+                }
+            }
+
+            while i < len && vi_char_class(clusters[i]) == ViCharClass::Blank {
+                i += 1;
+            }
+
+            if i >= len {
+                if line + 1 >= self.raw_text_lines.len() {
+                    return (line, len);
+                }
+                line += 1;
+                col = 0;
+                continue;
+            }
+
+            if end {
+                let class = vi_char_class(clusters[i]);
+                while i + 1 < len && vi_char_class(clusters[i + 1]) == class {
+                    i += 1;
+                }
+            }
+
+            return (line, i);
+        }
+    }
+
+    /// `b` motion: the mirror of `vi_word_motion_forward`, scanning
+    /// backwards for the start of the previous word, crossing into prior
+    /// lines when the current one is exhausted.
+    fn vi_word_motion_backward(&self, mut line: usize, mut col: usize) -> (usize, usize) {
+        loop {
+            if col == 0 {
+                if line == 0 {
+                    return (0, 0);
+                }
+                line -= 1;
+                col = self.vi_line_len(line);
+                continue;
+            }
+
+            let clusters: Vec<&str> = self
+                .raw_text_lines
+                .get(line)
+                .map(|l| l.graphemes(true).collect())
+                .unwrap_or_default();
+            let mut i = col - 1;
+
+            while i > 0 && vi_char_class(clusters[i]) == ViCharClass::Blank {
+                i -= 1;
+            }
+
+            if vi_char_class(clusters[i]) == ViCharClass::Blank {
+                if line == 0 {
+                    return (0, 0);
+                }
+                line -= 1;
+                col = self.vi_line_len(line);
+                continue;
+            }
+
+            let class = vi_char_class(clusters[i]);
+            while i > 0 && vi_char_class(clusters[i - 1]) == class {
+                i -= 1;
+            }
+
+            return (line, i);
+        }
+    }
+
+    /// `{`/`}` motions: jumps to the nearest preceding/following blank
+    /// line, i.e. a paragraph boundary, landing on column 0 like vim does.
+    /// Skips over a run of blank lines already under the cursor first, so
+    /// repeated presses keep advancing rather than getting stuck.
+    fn vi_paragraph_motion(&self, line: usize, forward: bool) -> usize {
+        let is_blank = |l: usize| {
+            self.raw_text_lines
+                .get(l)
+                .map(|s| s.trim().is_empty())
+                .unwrap_or(true)
+        };
+        let last_line = self.raw_text_lines.len().saturating_sub(1);
+
+        if forward {
+            let mut l = line;
+            while l < last_line && is_blank(l) {
+                l += 1;
+            }
+            while l < last_line && !is_blank(l) {
+                l += 1;
+            }
+            l
+        } else {
+            let mut l = line;
+            while l > 0 && is_blank(l) {
+                l -= 1;
+            }
+            while l > 0 && !is_blank(l) {
+                l -= 1;
+            }
+            l
+        }
+    }
+
+    /// `%` motion: finds the first bracket at or after `col` on `line`, then
+    /// walks forward/backward through `raw_text_lines` with a nesting
+    /// counter to find its match.
+    fn find_matching_bracket(&self, line: usize, col: usize) -> Option<(usize, usize)> {
+        const PAIRS: &[(char, char)] = &[('(', ')'), ('[', ']'), ('{', '}')];
+
+        let raw_line = self.raw_text_lines.get(line)?;
+        let clusters: Vec<&str> = raw_line.graphemes(true).collect();
+
+        let (start_idx, open, close, forward) = clusters
+            .iter()
+            .enumerate()
+            .skip(col)
+            .find_map(|(i, cluster)| {
+                let ch = cluster.chars().next()?;
+                PAIRS.iter().find_map(|&(open, close)| {
+                    if ch == open {
+                        Some((i, open, close, true))
+                    } else if ch == close {
+                        Some((i, open, close, false))
+                    } else {
+                        None
+                    }
+                })
+            })?;
+
+        let mut depth = 0i32;
+        if forward {
+            for l in line..self.raw_text_lines.len() {
+                let line_clusters: Vec<&str> = self.raw_text_lines[l].graphemes(true).collect();
+                let from = if l == line { start_idx } else { 0 };
+                for (i, cluster) in line_clusters.iter().enumerate().skip(from) {
+                    if let Some(ch) = cluster.chars().next() {
+                        if ch == open {
+                            depth += 1;
+                        } else if ch == close {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Some((l, i));
+                            }
+                        }
+                    }
+                }
+            }
+        } else {
+            for l in (0..=line).rev() {
+                let line_clusters: Vec<&str> = self.raw_text_lines[l].graphemes(true).collect();
+                let from = if l == line {
+                    start_idx
+                } else {
+                    line_clusters.len().saturating_sub(1)
+                };
+                for i in (0..=from).rev() {
+                    if let Some(ch) = line_clusters[i].chars().next() {
+                        if ch == close {
+                            depth += 1;
+                        } else if ch == open {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Some((l, i));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Draws the vi cursor on its line as a single reversed-video cell,
+    /// against this frame's already-wrapped spans - the cursor's line index
+    /// is a post-wrap `raw_text_lines` index, so it lines up with
+    /// `rendered_content.lines` with no extra translation.
+    fn apply_vi_cursor_highlighting(
+        &self,
+        line_idx: usize,
+        line_spans: Vec<Span<'static>>,
+    ) -> Vec<Span<'static>> {
+        let Some((cursor_line, cursor_col)) = self.vi_cursor else {
+            return line_spans;
+        };
+        if cursor_line != line_idx {
+            return line_spans;
+        }
+
+        let mut result_spans = Vec::new();
+        let mut cluster_offset = 0usize;
+        for span in line_spans {
+            let clusters: Vec<&str> = span.content.graphemes(true).collect();
+            let span_len = clusters.len();
+            let span_end = cluster_offset + span_len;
+
+            if cursor_col >= cluster_offset && cursor_col < span_end {
+                let rel = cursor_col - cluster_offset;
+                if rel > 0 {
+                    result_spans.push(Span::styled(clusters[..rel].concat(), span.style));
+                }
+                result_spans.push(Span::styled(
+                    clusters[rel].to_string(),
+                    span.style.add_modifier(Modifier::REVERSED),
+                ));
+                if rel + 1 < span_len {
+                    result_spans.push(Span::styled(clusters[rel + 1..].concat(), span.style));
+                }
+            } else {
+                result_spans.push(span);
+            }
+
+            cluster_offset = span_end;
+        }
+
+        if cursor_col >= cluster_offset {
+            result_spans.push(Span::styled(
+                " ",
+                RatatuiStyle::default().add_modifier(Modifier::REVERSED),
+            ));
+        }
+
+        result_spans
+    }
+
+    /// Whether `link_type` should be drawn with an OSC 8 wrapper under the
+    /// renderer's current [`Osc8Mode`], probing the environment for `Auto`.
+    fn should_emit_osc8_hyperlink(&self, link_type: &Option<crate::markdown::LinkType>) -> bool {
+        if !matches!(link_type, Some(crate::markdown::LinkType::External)) {
+            return false;
+        }
+
+        match self.osc8_hyperlinks {
+            Osc8Mode::Disabled => false,
+            Osc8Mode::Enabled => true,
+            Osc8Mode::Auto => Self::terminal_supports_osc8_hyperlinks(),
+        }
+    }
+
+    /// Best-effort probe for OSC 8 support: there's no terminal query for
+    /// it, so any terminal advertising `$TERM_PROGRAM` is assumed modern
+    /// enough, and otherwise `$TERM` just has to be set and not `dumb`.
+    fn terminal_supports_osc8_hyperlinks() -> bool {
+        if std::env::var("TERM_PROGRAM").is_ok() {
+            return true;
+        }
+        std::env::var("TERM").is_ok_and(|term| !term.is_empty() && term != "dumb")
+    }
+
+    /// Wraps `display_text` in an OSC 8 hyperlink sequence targeting `url`,
+    /// tagged with `id` via the `id=` parameter so a terminal groups
+    /// multiple fragments sharing the same `id` (a link wrapped across
+    /// several rendered lines by `map_wrapped_line_to_rich_spans`) into one
+    /// hoverable/clickable region instead of treating each fragment as its
+    /// own link. Callers must apply this only to the `Span` handed to
+    /// ratatui for drawing, after wrapping and link column bookkeeping have
+    /// already run against the plain `display_text` - the escape bytes must
+    /// never leak into `raw_text` or column math.
+    fn wrap_osc8_hyperlink(url: &str, display_text: &str, id: u64) -> String {
+        format!("\x1b]8;id={id};{url}\x1b\\{display_text}\x1b]8;;\x1b\\")
+    }
+
+    /// Stable id shared by every wrapped-line fragment of the same logical
+    /// link, for [`Self::wrap_osc8_hyperlink`]'s `id=` grouping parameter.
+    /// Derived from the link's url and full text rather than carried on
+    /// `LinkInfo` itself, since every fragment clones the same `LinkInfo`
+    /// and neither field changes across fragments of one link.
+    fn osc8_link_id(info: &LinkInfo) -> u64 {
+        use std::hash::{Hash, Hasher};
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        info.url.hash(&mut hasher);
+        info.text.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Moves keyboard link focus to the next link after the currently
+    /// hovered/focused one (wrapping to the first), reusing the mouse-hover
+    /// highlight (`apply_link_hover_highlighting`) so a keyboard-focused
+    /// link looks the same as one the mouse is over, and scrolls it into
+    /// view. Returns `false` if the chapter has no links at all.
+    pub fn focus_next_link(&mut self) -> bool {
+        self.step_focused_link(1)
+    }
+
+    /// Same as [`Self::focus_next_link`], stepping to the previous link.
+    pub fn focus_previous_link(&mut self) -> bool {
+        self.step_focused_link(-1)
+    }
+
+    fn step_focused_link(&mut self, step: isize) -> bool {
+        if self.links.is_empty() {
+            return false;
+        }
+
+        let current_id = self.hovered_link.as_ref().map(|hover| hover.id);
+        let current_index = current_id
+            .and_then(|id| self.links.iter().position(|l| Self::osc8_link_id(l) == id));
+
+        let len = self.links.len() as isize;
+        let mut next_index = match current_index {
+            Some(idx) => (idx as isize + step).rem_euclid(len),
+            None if step >= 0 => 0,
+            None => len - 1,
+        } as usize;
+        // A link wrapped across lines has one entry per fragment; step past
+        // the current link's other fragments.
+        if let Some(id) = current_id {
+            let mut steps = 0;
+            while steps < len && Self::osc8_link_id(&self.links[next_index]) == id {
+                next_index = (next_index as isize + step).rem_euclid(len) as usize;
+                steps += 1;
+            }
+        }
+
+        let link = &self.links[next_index];
+        self.hovered_link = Some(LinkHover {
+            id: Self::osc8_link_id(link),
+            url: link.url.clone(),
+        });
+        self.scroll_to_line(link.line);
+        true
+    }
+
+    /// Opens the fuzzy TOC picker over the current chapter's headings.
+    pub fn open_toc_picker(&mut self) {
+        let mut candidates = Vec::new();
+        Self::flatten_toc(&self.rendered_content.toc, 0, &mut candidates);
+        self.toc_picker = Some(TocPickerState::new(candidates));
+    }
+
+    /// Depth-first flatten of the hierarchical `Toc` into picker candidates,
+    /// indenting nested headings two spaces per level so the outline's shape
+    /// stays visible in the picker's flat list.
+    fn flatten_toc(entries: &[TocEntry], depth: usize, out: &mut Vec<TocCandidate>) {
+        for entry in entries {
+            let title = format!("{}{}", "  ".repeat(depth), entry.text);
+            out.push(TocCandidate {
+                title,
+                line: entry.line,
+            });
+            Self::flatten_toc(&entry.children, depth + 1, out);
+        }
+    }
+
+    pub fn close_toc_picker(&mut self) {
+        self.toc_picker = None;
+    }
+
+    pub fn is_toc_picker_active(&self) -> bool {
+        self.toc_picker.is_some()
+    }
+
+    /// Appends `c` to the picker's filter query and re-runs the fuzzy match.
+    pub fn toc_picker_input_char(&mut self, c: char) {
+        let Some(picker) = &mut self.toc_picker else {
+            return;
+        };
+        picker.query.push(c);
+        Self::refilter_toc_picker(picker);
+    }
+
+    pub fn toc_picker_backspace(&mut self) {
+        let Some(picker) = &mut self.toc_picker else {
+            return;
+        };
+        picker.query.pop();
+        Self::refilter_toc_picker(picker);
+    }
+
+    fn refilter_toc_picker(picker: &mut TocPickerState) {
+        let titles = picker.titles();
+        picker.matches = if picker.query.is_empty() {
+            all_matches(&titles)
+        } else {
+            find_fuzzy_matches_in_text(&picker.query, &titles)
+        };
+        picker.selected = 0;
+    }
+
+    /// Moves the picker's selection by `delta` rows, wrapping around.
+    pub fn toc_picker_move_selection(&mut self, delta: i32) {
+        let Some(picker) = &mut self.toc_picker else {
+            return;
+        };
+        if picker.matches.is_empty() {
+            return;
+        }
+        let len = picker.matches.len() as i32;
+        picker.selected = (picker.selected as i32 + delta).rem_euclid(len) as usize;
+    }
+
+    /// Jumps to the currently selected heading and closes the picker.
+    /// Returns `false` if there was nothing to jump to.
+    pub fn confirm_toc_picker(&mut self) -> bool {
+        let Some(picker) = &self.toc_picker else {
+            return false;
+        };
+        let target_line = picker
+            .matches
+            .get(picker.selected)
+            .and_then(|m| picker.candidates.get(m.index))
+            .map(|candidate| candidate.line);
+
+        self.toc_picker = None;
+
+        match target_line {
+            Some(target_line) => {
+                self.scroll_to_line(target_line);
+                self.highlight_line_temporarily(target_line, Duration::from_secs(2));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Context lines rendered around `candidate_idx`'s heading, cached so
+    /// moving the picker selection doesn't re-wrap the document.
+    fn toc_picker_preview(&mut self, candidate_idx: usize) -> Vec<Line<'static>> {
+        if let Some(cached) = self
+            .toc_picker
+            .as_ref()
+            .and_then(|picker| picker.preview_cache.get(&candidate_idx))
+        {
+            return cached.clone();
+        }
+
+        const PREVIEW_LINES: usize = 6;
+        let preview = self
+            .toc_picker
+            .as_ref()
+            .and_then(|picker| picker.candidates.get(candidate_idx))
+            .map(|candidate| {
+                let end = (candidate.line + PREVIEW_LINES).min(self.rendered_content.lines.len());
+                self.rendered_content.lines[candidate.line..end]
+                    .iter()
+                    .map(|line| Line::from(line.spans.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if let Some(picker) = &mut self.toc_picker {
+            picker.preview_cache.insert(candidate_idx, preview.clone());
+        }
+
+        preview
+    }
+
+    /// Draws the TOC picker overlay: a fuzzy-filtered heading list on the
+    /// left, a live preview of the selected heading's surrounding lines on
+    /// the right.
+    fn render_toc_picker(&mut self, frame: &mut Frame, area: Rect, palette: &Base16Palette) {
+        let Some(picker) = &self.toc_picker else {
+            return;
+        };
+        let query = picker.query.clone();
+        let selected = picker.selected;
+        let entries: Vec<(String, usize)> = picker
+            .matches
+            .iter()
+            .map(|m| (picker.candidates[m.index].title.clone(), m.index))
+            .collect();
+
+        let popup_area = toc_picker_centered_rect(80, 70, area);
+        frame.render_widget(ratatui::widgets::Clear, popup_area);
+
+        let columns = ratatui::layout::Layout::default()
+            .direction(ratatui::layout::Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(popup_area);
+
+        let items: Vec<ratatui::widgets::ListItem> = entries
+            .iter()
+            .enumerate()
+            .map(|(i, (title, _))| {
+                let style = if i == selected {
+                    RatatuiStyle::default()
+                        .fg(palette.base_00)
+                        .bg(palette.base_0a)
+                } else {
+                    RatatuiStyle::default().fg(palette.base_05)
+                };
+                ratatui::widgets::ListItem::new(title.clone()).style(style)
+            })
+            .collect();
+
+        let list = ratatui::widgets::List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Jump to heading: {query} "))
+                .style(
+                    RatatuiStyle::default()
+                        .fg(palette.base_04)
+                        .bg(palette.base_00),
+                ),
+        );
+        frame.render_widget(list, columns[0]);
+
+        let preview_lines = entries
+            .get(selected)
+            .map(|(_, candidate_idx)| self.toc_picker_preview(*candidate_idx))
+            .unwrap_or_default();
+
+        let preview = Paragraph::new(preview_lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Preview ")
+                .style(
+                    RatatuiStyle::default()
+                        .fg(palette.base_04)
+                        .bg(palette.base_00),
+                ),
+        );
+        frame.render_widget(preview, columns[1]);
+    }
+
+    /// Opens the URL hint overlay (`Space u`): scans every link visible in
+    /// the current viewport - external URLs and internal chapter/anchor
+    /// links alike - plus any bare `http(s)://`/`mailto:` text not already
+    /// captured as a parsed link, and labels each with a short alphabetic
+    /// tag (`generate_hint_labels`) that `handle_hint_key` matches against.
+    /// A no-op if nothing is on screen.
+    pub fn enter_hint_mode(&mut self) {
+        let start_line = self.scroll_offset;
+        let end_line = (self.scroll_offset + self.visible_height).min(self.raw_text_lines.len());
+
+        let mut targets: Vec<(usize, usize, usize, LinkInfo)> = Vec::new();
+
+        for link in &self.links {
+            if link.line >= start_line && link.line < end_line {
+                targets.push((link.line, link.start_col, link.end_col, link.clone()));
+            }
+        }
+
+        if let Ok(url_re) = regex::Regex::new(r"(https?://\S+|mailto:\S+)") {
+            for line_idx in start_line..end_line {
+                let Some(raw_line) = self.raw_text_lines.get(line_idx) else {
+                    continue;
+                };
+                for m in url_re.find_iter(raw_line) {
+                    let start_col = raw_line[..m.start()].graphemes(true).count();
+                    let end_col = raw_line[..m.end()].graphemes(true).count();
+                    let overlaps_link = targets
+                        .iter()
+                        .any(|(line, s, e, _)| *line == line_idx && start_col < *e && end_col > *s);
+                    if !overlaps_link {
+                        let url = m.as_str().trim_end_matches(['.', ',', ')']).to_string();
+                        targets.push((line_idx, start_col, end_col, LinkInfo::from_url(url)));
+                    }
+                }
+            }
+        }
+
+        targets.sort_by_key(|(line, start_col, ..)| (*line, *start_col));
+
+        if targets.is_empty() {
+            self.hint_state = None;
+            return;
+        }
+
+        let labels = generate_hint_labels(targets.len());
+        let hints = targets
+            .into_iter()
+            .zip(labels)
+            .map(|((line, start_col, end_col, link), label)| UrlHint {
+                label,
+                link,
+                line,
+                start_col,
+                end_col,
+            })
+            .collect();
+
+        self.hint_state = Some(HintState {
+            hints,
+            input: String::new(),
+        });
+    }
+
+    /// Cancels the active hint overlay without opening anything.
+    pub fn exit_hint_mode(&mut self) {
+        self.hint_state = None;
+    }
+
+    pub fn is_hint_mode_active(&self) -> bool {
+        self.hint_state.is_some()
+    }
+
+    /// Feeds one typed character into the active hint overlay. Returns
+    /// `Some(link)` and closes the overlay once `input` exactly matches a
+    /// hint's label; stays open (returning `None`) while `input` is still a
+    /// prefix of at least one label; resets `input` if it matches none.
+    pub fn handle_hint_key(&mut self, c: char) -> Option<LinkInfo> {
+        let Some(state) = &mut self.hint_state else {
+            return None;
+        };
+
+        state.input.push(c);
+
+        if let Some(hint) = state.hints.iter().find(|h| h.label == state.input) {
+            let link = hint.link.clone();
+            self.hint_state = None;
+            return Some(link);
+        }
+
+        if !state.hints.iter().any(|h| h.label.starts_with(&state.input)) {
+            state.input.clear();
+        }
+
+        None
+    }
+
+    /// Overlays each hint's label directly before its target text, replacing
+    /// as many characters of the target as the label is long so the overlay
+    /// doesn't shift the rest of the line. Applied last, over every other
+    /// highlight layer, so hints are always legible.
+    fn apply_hint_label_overlay(
+        &self,
+        line_idx: usize,
+        line_spans: Vec<Span<'static>>,
+        palette: &Base16Palette,
+    ) -> Vec<Span<'static>> {
+        let Some(state) = &self.hint_state else {
+            return line_spans;
+        };
+
+        let line_hints: Vec<&UrlHint> = state.hints.iter().filter(|h| h.line == line_idx).collect();
+        if line_hints.is_empty() {
+            return line_spans;
+        }
+
+        let hint_style = RatatuiStyle::default()
+            .bg(palette.base_0a)
+            .fg(palette.base_00)
+            .add_modifier(Modifier::BOLD);
+
+        let mut result_spans = Vec::new();
+        let mut cluster_offset = 0usize;
+
+        for span in line_spans {
+            let clusters: Vec<&str> = span.content.graphemes(true).collect();
+            let span_len = clusters.len();
+            let span_end = cluster_offset + span_len;
+
+            let hint = line_hints
+                .iter()
+                .find(|h| h.start_col >= cluster_offset && h.start_col < span_end);
+
+            let Some(hint) = hint else {
+                result_spans.push(span);
+                cluster_offset = span_end;
+                continue;
+            };
+
+            let rel_start = hint.start_col - cluster_offset;
+            let label_len = hint.label.graphemes(true).count();
+            let rel_label_end = (rel_start + label_len).min(span_len);
+
+            if rel_start > 0 {
+                result_spans.push(Span::styled(clusters[..rel_start].concat(), span.style));
+            }
+            result_spans.push(Span::styled(hint.label.clone(), hint_style));
+            if rel_label_end < span_len {
+                result_spans.push(Span::styled(clusters[rel_label_end..].concat(), span.style));
+            }
+
+            cluster_offset = span_end;
+        }
+
+        result_spans
+    }
+
+    /// Get the currently active section based on viewport position
+    pub fn get_active_section(&mut self, current_chapter: usize) -> ActiveSection {
+        // Calculate middle of viewport
+        let viewport_middle = self.scroll_offset + (self.visible_height / 2);
+
+        // Scan backwards from middle to find the most recent anchor
+        let lines_to_check = viewport_middle.min(self.rendered_content.lines.len());
+
+        for line_idx in (0..=lines_to_check).rev() {
+            if let Some(line) = self.rendered_content.lines.get(line_idx) {
+                if let Some(ref anchor) = line.node_anchor {
+                    // Found an anchor - update and return it
+                    self.last_active_anchor = Some(anchor.clone());
+                    return ActiveSection::Anchor(anchor.clone());
+                }
+            }
+        }
+
+        // No anchor found before middle - check if we have a stored one
+        if let Some(ref anchor) = self.last_active_anchor {
+            // We still use the last known anchor if we're in the same chapter
+            // This maintains highlighting when scrolling past the last anchor
+            return ActiveSection::Anchor(anchor.clone());
+        }
+
+        // Fall back to chapter-level highlighting
+        ActiveSection::Chapter(current_chapter)
+    }
+
+    /// Apply search highlighting to a line's spans
     fn apply_search_highlighting(
         &self,
         line_idx: usize,
@@ -2650,6 +5163,86 @@ impl MarkdownTextReader {
         result_spans
     }
 
+    /// Underlines the span(s) of the link currently under the mouse cursor
+    /// (see `handle_mouse_move`). The hitbox map is simply this line's own
+    /// `link_nodes` on `self.rendered_content` - already rebuilt for the
+    /// current width/generation by the time `render` gets here - so hover
+    /// never lags a resize or re-wrap. Every `link_nodes` entry sharing the
+    /// hovered link's `osc8_link_id` is highlighted, so a link wrapped
+    /// across multiple rendered lines highlights as a single region.
+    fn apply_link_hover_highlighting(
+        &self,
+        line_idx: usize,
+        line_spans: Vec<Span<'static>>,
+    ) -> Vec<Span<'static>> {
+        let Some(hovered) = &self.hovered_link else {
+            return line_spans;
+        };
+
+        let line_links: Vec<&LinkInfo> = self
+            .rendered_content
+            .lines
+            .get(line_idx)
+            .map(|l| &l.link_nodes)
+            .into_iter()
+            .flatten()
+            .filter(|link: &&LinkInfo| Self::osc8_link_id(*link) == hovered.id)
+            .collect();
+
+        if line_links.is_empty() {
+            return line_spans;
+        }
+
+        // Columns here are grapheme-cluster indices (see `render_text_spans`),
+        // so segments are sliced by grapheme cluster rather than by byte.
+        let mut result_spans = Vec::new();
+        let mut cluster_offset = 0usize;
+
+        for span in line_spans {
+            let clusters: Vec<&str> = span.content.graphemes(true).collect();
+            let span_len = clusters.len();
+            let span_end = cluster_offset + span_len;
+
+            let mut segments = vec![];
+            let mut last_pos = 0usize;
+            for link in &line_links {
+                if link.end_col > cluster_offset && link.start_col < span_end {
+                    let rel_start = link.start_col.saturating_sub(cluster_offset).min(span_len);
+                    let rel_end = link.end_col.saturating_sub(cluster_offset).min(span_len);
+                    if rel_start > last_pos {
+                        segments.push((last_pos, rel_start, false));
+                    }
+                    segments.push((rel_start, rel_end, true));
+                    last_pos = rel_end;
+                }
+            }
+            if last_pos < span_len {
+                segments.push((last_pos, span_len, false));
+            }
+
+            if segments.is_empty() {
+                result_spans.push(span);
+            } else {
+                for (start, end, is_hovered) in segments {
+                    if start >= end {
+                        continue;
+                    }
+                    let text_segment = clusters[start..end].concat();
+                    let style = if is_hovered {
+                        span.style.add_modifier(Modifier::UNDERLINED)
+                    } else {
+                        span.style
+                    };
+                    result_spans.push(Span::styled(text_segment, style));
+                }
+            }
+
+            cluster_offset = span_end;
+        }
+
+        result_spans
+    }
+
     /// Get searchable content (visible lines as text)
     fn get_visible_text(&self) -> Vec<String> {
         self.rendered_content
@@ -2751,40 +5344,159 @@ impl MarkdownTextReader {
         Ok(())
     }
 
-    /// Actually perform the node restoration (called after rendering)
-    fn perform_node_restore(&mut self, node_index: usize) {
+    /// Actually perform the node restoration (called after rendering). Walks
+    /// the freshly rendered lines for the target node and scrolls to the one
+    /// whose accumulated `char_offset` is closest to `position.char_offset`,
+    /// so restoration lands on the exact wrapped segment the reader was on
+    /// even if re-wrapping at a new width changed how many lines the node
+    /// produced. Falls back to the first line of the next available node if
+    /// the exact node is no longer present.
+    fn perform_node_restore(&mut self, position: NodePosition) {
+        info!(
+            "perform_node_restore called with {:?}, total lines={}",
+            position,
+            self.rendered_content.lines.len()
+        );
+
+        let mut best: Option<(usize, usize)> = None; // (line_idx, char_offset distance)
+        let mut fallback: Option<usize> = None;
+
+        for (line_idx, line) in self.rendered_content.lines.iter().enumerate() {
+            let Some(node_idx) = line.node_index else {
+                continue;
+            };
+
+            if node_idx == position.node_index {
+                let distance = line.char_offset.abs_diff(position.char_offset);
+                if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+                    best = Some((line_idx, distance));
+                }
+            } else if node_idx > position.node_index && fallback.is_none() {
+                fallback = Some(line_idx);
+            }
+        }
+
+        if let Some((line_idx, _)) = best {
+            self.scroll_offset = line_idx.min(self.get_max_scroll_offset());
+            info!("Restored to {:?} at line {}", position, line_idx);
+        } else if let Some(line_idx) = fallback {
+            self.scroll_offset = line_idx.min(self.get_max_scroll_offset());
+            info!(
+                "Node {} not found, restored to next node at line {}",
+                position.node_index, line_idx
+            );
+        } else {
+            info!(
+                "Could not find node index {} in rendered content, staying at current position",
+                position.node_index
+            );
+        }
+    }
+
+    /// Get the current reading position at wrap-segment granularity (see
+    /// `NodePosition`). Used internally where sub-node precision matters,
+    /// such as restoring scroll position across a terminal resize.
+    fn get_current_node_position(&self) -> NodePosition {
+        let visible_start = self.scroll_offset;
+
+        for line in self.rendered_content.lines.iter().skip(visible_start) {
+            if let Some(node_index) = line.node_index {
+                return NodePosition {
+                    node_index,
+                    portion: line.portion,
+                    char_offset: line.char_offset,
+                };
+            }
+        }
+
+        NodePosition {
+            node_index: 0,
+            portion: 0,
+            char_offset: 0,
+        }
+    }
+
+    /// Defer restoring to a precise `NodePosition` until after the next
+    /// render (mirroring `restore_to_node_index`).
+    fn restore_to_node_position(&mut self, position: NodePosition) {
         info!(
-            "perform_node_restore called with node_index={}, total lines={}",
-            node_index,
-            self.rendered_content.lines.len()
+            "restore_to_node_position called with {:?}, deferring until after render",
+            position
         );
+        self.pending_node_restore = Some(position);
+    }
+
+    /// Selected text, or the whole chapter if nothing is selected.
+    fn export_as_plain_text(&self) -> Result<String, String> {
+        if self.text_selection.has_selection() {
+            self.text_selection
+                .extract_selected_text(&self.raw_text_lines)
+                .ok_or_else(|| "No text selected".to_string())
+        } else if !self.raw_text_lines.is_empty() {
+            Ok(self.raw_text_lines.join("\n"))
+        } else {
+            Err("No content to export".to_string())
+        }
+    }
+
+    /// The cached AST re-serialized to Markdown, clipped to the blocks a
+    /// selection overlaps (if any).
+    fn export_as_markdown(&self) -> Result<String, String> {
+        let doc = self
+            .markdown_document
+            .as_ref()
+            .ok_or_else(|| "No content to export".to_string())?;
+
+        let renderer = MarkdownRenderer::new();
+
+        let Some((start, end)) = self.text_selection.get_selection_range() else {
+            return Ok(renderer.render(doc));
+        };
 
-        // Count how many lines have node indices
-        let lines_with_nodes = self
+        let node_indices: BTreeSet<usize> = self
             .rendered_content
             .lines
             .iter()
-            .filter(|line| line.node_index.is_some())
-            .count();
-        info!("Lines with node indices: {}", lines_with_nodes);
+            .enumerate()
+            .filter(|(line_idx, _)| (start.line..=end.line).contains(line_idx))
+            .filter_map(|(_, line)| line.node_index)
+            .collect();
 
-        // Find the first line that belongs to this node
-        for (line_idx, line) in self.rendered_content.lines.iter().enumerate() {
-            if let Some(node_idx) = line.node_index {
-                if node_idx >= node_index {
-                    // Found the node or a later one, scroll to this position
-                    self.scroll_offset = line_idx.min(self.get_max_scroll_offset());
-                    info!("Restored to node {} at line {}", node_index, line_idx);
-                    return;
-                }
-            }
+        if node_indices.is_empty() {
+            // The selection doesn't map to any node (e.g. raw HTML mode);
+            // fall back to the flattened text.
+            return self.export_as_plain_text();
         }
 
-        // Node not found, stay at current position
-        info!(
-            "Could not find node index {} in rendered content, staying at current position",
-            node_index
-        );
+        let selected_doc = Document {
+            blocks: node_indices
+                .into_iter()
+                .filter_map(|idx| doc.blocks.get(idx).cloned())
+                .collect(),
+        };
+        Ok(renderer.render(&selected_doc))
+    }
+
+    /// If `(x, y)` lands on the scrollbar gutter column drawn by
+    /// `render_scrollbar_gutter`, jump to the document line its row
+    /// represents and report that the click was consumed so the caller
+    /// doesn't also start a text selection there.
+    fn handle_gutter_click(&mut self, x: u16, y: u16) -> bool {
+        let Some(gutter_area) = self.last_gutter_area else {
+            return false;
+        };
+        if x != gutter_area.x || y < gutter_area.y || y >= gutter_area.y + gutter_area.height {
+            return false;
+        }
+        if self.total_wrapped_lines == 0 || gutter_area.height == 0 {
+            return false;
+        }
+
+        let row = (y - gutter_area.y) as usize;
+        let gutter_height = gutter_area.height as usize;
+        let target_line = (row * self.total_wrapped_lines) / gutter_height;
+        self.scroll_to_line(target_line);
+        true
     }
 }
 
@@ -2802,6 +5514,8 @@ impl TextReaderTrait for MarkdownTextReader {
         self.raw_text_lines.clear();
         self.scroll_offset = 0;
         self.text_selection.clear_selection();
+        self.scrollbar_markers.cancel();
+        self.annotations.clear();
 
         // Mark cache as invalid to force re-rendering
         self.cache_generation += 1;
@@ -2827,6 +5541,7 @@ impl TextReaderTrait for MarkdownTextReader {
             lines: Vec::new(),
             total_height: 0,
             generation: 0,
+            toc: Vec::new(),
         };
         self.embedded_images.borrow_mut().clear();
 
@@ -2889,37 +5604,33 @@ impl TextReaderTrait for MarkdownTextReader {
 
     /// Get the index of the first visible node in the viewport
     fn get_current_node_index(&self) -> usize {
-        // Find the first visible line in the viewport
-        let visible_start = self.scroll_offset;
-
-        // Look through rendered lines to find the node index
-        for (line_idx, line) in self.rendered_content.lines.iter().enumerate() {
-            if line_idx >= visible_start {
-                if let Some(node_idx) = line.node_index {
-                    return node_idx;
-                }
-            }
-        }
-
-        0 // Default to first node
+        self.get_current_node_position().node_index
     }
 
-    /// Restore scroll position to show a specific node
+    /// Restore scroll position to the start of a specific node. Callers that
+    /// only track a bare node index (marks, jump list, bookmarks) land on the
+    /// node's first wrapped line; `restore_to_node_position` is used
+    /// internally where exact-segment precision matters.
     fn restore_to_node_index(&mut self, node_index: usize) {
-        info!(
-            "restore_to_node_index called with node_index={}, deferring until after render",
-            node_index
-        );
-
-        // Defer the restoration until after content is rendered
-        self.pending_node_restore = Some(node_index);
+        self.restore_to_node_position(NodePosition {
+            node_index,
+            portion: 0,
+            char_offset: 0,
+        });
     }
 
     fn get_max_scroll_offset(&self) -> usize {
         self.total_wrapped_lines.saturating_sub(self.visible_height)
     }
 
-    fn handle_mouse_down(&mut self, x: u16, y: u16, area: Rect) {
+    /// `block` selects a rectangle (column range x row range) instead of a
+    /// linear span, for the modifier-held drag described on
+    /// `TextSelection::start_block_selection`.
+    fn handle_mouse_down(&mut self, x: u16, y: u16, area: Rect, block: bool) {
+        if self.handle_gutter_click(x, y) {
+            return;
+        }
+
         // Use the inner text area if available, otherwise fall back to the provided area
         let text_area = self.last_inner_text_area.unwrap_or(area);
 
@@ -2932,8 +5643,13 @@ impl TextReaderTrait for MarkdownTextReader {
                 return;
             }
 
-            self.text_selection.start_selection(line, column);
-            debug!("Started text selection at line {}, column {}", line, column);
+            if block {
+                self.text_selection.start_block_selection(line, column);
+                debug!("Started block text selection at line {}, column {}", line, column);
+            } else {
+                self.text_selection.start_selection(line, column);
+                debug!("Started text selection at line {}, column {}", line, column);
+            }
         }
     }
 
@@ -2947,19 +5663,26 @@ impl TextReaderTrait for MarkdownTextReader {
                 self.text_selection.update_selection(line, column);
             }
 
-            // Check if we need to auto-scroll due to dragging outside the visible area
-            const SCROLL_MARGIN: u16 = 3;
-            let needs_scroll_up = y <= text_area.y + SCROLL_MARGIN && self.scroll_offset > 0;
-            let needs_scroll_down = y >= text_area.y + text_area.height - SCROLL_MARGIN;
+            // Check if we need to auto-scroll due to dragging outside the
+            // visible area. The trigger zone extends `SCROLL_MARGIN` rows
+            // inside the content from each edge, so scrolling starts before
+            // the cursor actually leaves the window; speed stays at the
+            // floor of one line/tick anywhere in that zone and grows the
+            // further past the edge the cursor goes.
+            const SCROLL_MARGIN: u16 = 5;
+            let content_top = text_area.y;
+            let content_bottom = text_area.y + text_area.height.saturating_sub(1);
+            let needs_scroll_up = y <= content_top + SCROLL_MARGIN && self.scroll_offset > 0;
+            let needs_scroll_down = y + SCROLL_MARGIN >= content_bottom;
 
             if needs_scroll_up {
                 self.auto_scroll_active = true;
-                self.auto_scroll_speed = -1.0;
+                self.auto_scroll_speed = -((content_top as i32 - y as i32).max(1) as f32);
                 // Perform immediate scroll like text_reader.rs does
                 self.perform_auto_scroll();
             } else if needs_scroll_down {
                 self.auto_scroll_active = true;
-                self.auto_scroll_speed = 1.0;
+                self.auto_scroll_speed = (y as i32 - content_bottom as i32).max(1) as f32;
                 // Perform immediate scroll like text_reader.rs does
                 self.perform_auto_scroll();
             } else {
@@ -3031,15 +5754,34 @@ impl TextReaderTrait for MarkdownTextReader {
         }
     }
 
+    fn handle_mouse_move(&mut self, x: u16, y: u16, area: Rect) {
+        // Use the inner text area if available, otherwise fall back to the provided area
+        let text_area = self.last_inner_text_area.unwrap_or(area);
+
+        self.hovered_link = self
+            .screen_to_text_coords(x, y, text_area)
+            .and_then(|(line, column)| self.get_link_at_position(line, column))
+            .map(|link| LinkHover {
+                id: Self::osc8_link_id(link),
+                url: link.url.clone(),
+            });
+    }
+
+    fn hovered_link_url(&self) -> Option<&str> {
+        self.hovered_link.as_ref().map(|hover| hover.url.as_str())
+    }
+
     fn clear_selection(&mut self) {
         self.text_selection.clear_selection();
     }
 
-    fn copy_selection_to_clipboard(&self) -> Result<(), String> {
-        if let Some(selected_text) = self
-            .text_selection
+    fn get_selected_text(&self) -> Option<String> {
+        self.text_selection
             .extract_selected_text(&self.raw_text_lines)
-        {
+    }
+
+    fn copy_selection_to_clipboard(&self) -> Result<(), String> {
+        if let Some(selected_text) = self.get_selected_text() {
             use arboard::Clipboard;
             let mut clipboard =
                 Clipboard::new().map_err(|e| format!("Failed to access clipboard: {}", e))?;
@@ -3086,6 +5828,25 @@ impl TextReaderTrait for MarkdownTextReader {
         self.text_selection.has_selection()
     }
 
+    /// Write the active selection (or the whole chapter, if nothing is
+    /// selected) to `path`. `Markdown` re-serializes the cached
+    /// `markdown_document` AST via `MarkdownRenderer` so headings, emphasis,
+    /// links and code fences round-trip; a selection is clipped to the AST
+    /// blocks whose rendered lines it overlaps, using the `node_index` each
+    /// `RenderedLine` already carries. `PlainText` reuses the flattened
+    /// `raw_text_lines`, like `copy_chapter_to_clipboard` does.
+    fn export_to_path(&self, path: &Path, format: ExportFormat) -> Result<(), String> {
+        let content = match format {
+            ExportFormat::Markdown => self.export_as_markdown()?,
+            ExportFormat::PlainText => self.export_as_plain_text()?,
+        };
+
+        std::fs::write(path, content)
+            .map_err(|e| format!("Failed to write export to {}: {}", path.display(), e))?;
+        debug!("Exported content to {}", path.display());
+        Ok(())
+    }
+
     fn preload_image_dimensions(&mut self, book_images: &BookImages) {
         // Extract images from the AST and preload their dimensions
         if let Some(doc) = self.markdown_document.clone() {
@@ -3140,19 +5901,71 @@ impl TextReaderTrait for MarkdownTextReader {
         }
     }
 
+    /// Downscales `image` to its target cell footprint (in pixels), caching
+    /// the result by `(src, target_width, target_height)` so the same
+    /// footprint is never resized twice. Uses Lanczos3 for downscale and
+    /// nearest-neighbor for the rare tiny-upscale case.
+    fn resized_for_display(
+        &self,
+        src: &str,
+        image: &DynamicImage,
+        target_width: u32,
+        target_height: u32,
+    ) -> Arc<DynamicImage> {
+        let cache_key = (src.to_string(), target_width, target_height);
+        if let Some(cached) = self.resized_image_cache.borrow().get(&cache_key) {
+            return cached.clone();
+        }
+
+        let (orig_width, orig_height) = image.dimensions();
+        let filter = if target_width <= orig_width && target_height <= orig_height {
+            image::imageops::FilterType::Lanczos3
+        } else {
+            image::imageops::FilterType::Nearest
+        };
+        let resized = Arc::new(image.resize(target_width.max(1), target_height.max(1), filter));
+        self.resized_image_cache
+            .borrow_mut()
+            .insert(cache_key, resized.clone());
+        resized
+    }
+
     fn check_for_loaded_images(&mut self) -> bool {
         let mut any_loaded = false;
 
         // Check for background-loaded images
         if let Some(loaded_images) = self.background_loader.check_for_loaded_images() {
             for (img_src, image) in loaded_images {
+                let target_dims = self.image_picker.as_ref().and_then(|picker| {
+                    let height_cells = self
+                        .embedded_images
+                        .borrow()
+                        .get(&img_src)
+                        .map(|embedded_image| embedded_image.height_cells)?;
+                    let (cell_width, cell_height) = picker.font_size();
+                    let (orig_width, orig_height) = image.dimensions();
+                    let target_height = height_cells as u32 * cell_height as u32;
+                    let target_width = (target_height as f32 * orig_width as f32
+                        / orig_height.max(1) as f32)
+                        .round()
+                        .max(cell_width as f32) as u32;
+                    Some((target_width, target_height))
+                });
+
+                let resized = target_dims.map(|(target_width, target_height)| {
+                    self.resized_for_display(&img_src, &image, target_width, target_height)
+                });
+
                 let mut embedded_images = self.embedded_images.borrow_mut();
                 if let Some(embedded_image) = embedded_images.get_mut(&img_src) {
                     // Store the loaded image with protocol
-                    embedded_image.state = if let Some(ref picker) = self.image_picker {
+                    embedded_image.state = if let (Some(ref picker), Some(resized)) =
+                        (&self.image_picker, resized)
+                    {
                         ImageLoadState::Loaded {
                             image: Arc::new(image.clone()),
-                            protocol: picker.new_resize_protocol(image),
+                            protocol: picker.new_resize_protocol((*resized).clone()),
+                            resized,
                         }
                     } else {
                         ImageLoadState::Failed {
@@ -3235,6 +6048,10 @@ impl TextReaderTrait for MarkdownTextReader {
         false
     }
 
+    fn is_auto_scrolling(&self) -> bool {
+        self.auto_scroll_active
+    }
+
     fn update_auto_scroll(&mut self) -> bool {
         if self.auto_scroll_active {
             let scroll_amount = self.auto_scroll_speed.abs() as usize;
@@ -3272,6 +6089,13 @@ impl TextReaderTrait for MarkdownTextReader {
     }
 
     fn handle_terminal_resize(&mut self) {
+        // Re-wrapping at the new width changes how many lines each node
+        // produces, so a bare scroll_offset would land on the wrong row.
+        // Capture the precise wrap-segment position now and restore it once
+        // the re-render below has recomputed `rendered_content`.
+        let position = self.get_current_node_position();
+        self.restore_to_node_position(position);
+
         // Clear caches on resize
         self.cache_generation += 1;
     }
@@ -3319,9 +6143,9 @@ impl TextReaderTrait for MarkdownTextReader {
                 self.last_focus_state = is_focused;
 
                 // Check if we have a pending node restore after re-render
-                if let Some(node_index) = self.pending_node_restore.take() {
-                    self.perform_node_restore(node_index);
-                    debug!("Performed pending node restore for node {}", node_index);
+                if let Some(position) = self.pending_node_restore.take() {
+                    self.perform_node_restore(position);
+                    debug!("Performed pending node restore for {:?}", position);
                 }
 
                 // Check if we have a pending anchor scroll after re-render
@@ -3342,21 +6166,31 @@ impl TextReaderTrait for MarkdownTextReader {
             }
         }
         // Build the title
-        let title_text = if let Some(title) = chapter_title {
+        let mut title_text = if let Some(title) = chapter_title {
             format!("[{}/{}] {}", current_chapter, total_chapters, title)
         } else {
             format!("Chapter {}/{}", current_chapter, total_chapters)
         };
+        if self.search_state.mode == SearchMode::NavigationMode {
+            title_text.push(' ');
+            title_text.push_str(&self.search_state.get_match_info());
+        }
 
         // Calculate progress percentage
         let progress = self.calculate_progress("", width, self.visible_height);
 
         // Create the border block
-        let block = Block::default()
+        let mut block = Block::default()
             .borders(Borders::ALL)
             .title(title_text)
             .title_bottom(Line::from(format!(" {}% ", progress)).right_aligned());
 
+        // Surface the hovered link's target as a status area in the bottom
+        // border, mirroring the progress percentage's title_bottom.
+        if let Some(hover) = &self.hovered_link {
+            block = block.title_bottom(Line::from(format!(" {} ", hover.url)).left_aligned());
+        }
+
         // Calculate the inner area after borders
         let mut inner_area = block.inner(area);
         inner_area.y = inner_area.y.saturating_add(1);
@@ -3378,37 +6212,7 @@ impl TextReaderTrait for MarkdownTextReader {
             palette.base_01
         };
 
-        // Check if we need to insert space for comment textarea
-        let mut textarea_lines_to_insert = 0;
-        let mut textarea_insert_position = None;
-
-        if self.comment_input_active {
-            if let Some(target_line) = self.comment_target_line {
-                if target_line >= self.scroll_offset && target_line < end_offset {
-                    textarea_insert_position = Some(target_line);
-
-                    let content_lines = if let Some(ref textarea) = self.comment_textarea {
-                        textarea.lines().len()
-                    } else {
-                        0
-                    };
-
-                    let min_lines = 3;
-                    let actual_content_lines = content_lines.max(min_lines);
-                    textarea_lines_to_insert = actual_content_lines + 2;
-                }
-            }
-        }
-
         for line_idx in self.scroll_offset..end_offset {
-            if let Some(insert_pos) = textarea_insert_position {
-                if line_idx == insert_pos {
-                    for _ in 0..textarea_lines_to_insert {
-                        visible_lines.push(Line::from(""));
-                    }
-                }
-            }
-
             if let Some(rendered_line) = self.rendered_content.lines.get(line_idx) {
                 let visual_line_idx = line_idx - self.scroll_offset;
 
@@ -3457,6 +6261,19 @@ impl TextReaderTrait for MarkdownTextReader {
                 // Apply search highlighting after selection highlighting
                 line_spans = self.apply_search_highlighting(line_idx, line_spans, palette);
 
+                // Apply link hover highlighting against this frame's
+                // own link_nodes, so it reflects the wrap just computed
+                // above rather than a stale previous frame.
+                line_spans = self.apply_link_hover_highlighting(line_idx, line_spans);
+
+                // Apply the vi-mode cursor last so it's always visible even
+                // over a hovered link or search match.
+                line_spans = self.apply_vi_cursor_highlighting(line_idx, line_spans);
+
+                // Apply URL hint labels over everything else, so they stay
+                // legible while the overlay is open.
+                line_spans = self.apply_hint_label_overlay(line_idx, line_spans, palette);
+
                 visible_lines.push(Line::from(line_spans));
             }
         }
@@ -3496,11 +6313,12 @@ impl TextReaderTrait for MarkdownTextReader {
                     {
                         // Check if image is loaded
                         if let ImageLoadState::Loaded {
-                            ref image,
+                            ref resized,
                             ref mut protocol,
+                            ..
                         } = embedded_image.state
                         {
-                            let scaled_image = image;
+                            let scaled_image = resized;
 
                             if let Some(ref picker) = self.image_picker {
                                 let image_screen_start = if scroll_offset > image_start_line {
@@ -3597,71 +6415,188 @@ impl TextReaderTrait for MarkdownTextReader {
 
         if self.comment_input_active {
             if let Some(ref mut textarea) = self.comment_textarea {
-                if let Some(target_line) = self.comment_target_line {
-                    if target_line >= self.scroll_offset
-                        && target_line < self.scroll_offset + self.visible_height
-                    {
-                        let visual_position = target_line - self.scroll_offset;
-
-                        let textarea_y = inner_area.y + visual_position as u16;
-
-                        if textarea_y < inner_area.y + inner_area.height {
-                            // Calculate dynamic height based on content (same as above)
-                            let content_lines = textarea.lines().len();
-                            let min_lines = 3;
-                            let actual_content_lines = content_lines.max(min_lines);
-                            // Add 2 for borders
-                            let desired_height = (actual_content_lines + 2) as u16;
-
-                            // Make sure it fits in the available space
-                            let textarea_height =
-                                desired_height.min(inner_area.y + inner_area.height - textarea_y);
-
-                            let textarea_rect = Rect {
-                                x: inner_area.x, // Use full width
-                                y: textarea_y,
-                                width: inner_area.width, // Full width
-                                height: textarea_height,
-                            };
-
-                            let clear_block =
-                                Block::default().style(RatatuiStyle::default().bg(palette.base_00));
-                            frame.render_widget(clear_block, textarea_rect);
-
-                            let padded_rect = Rect {
-                                x: inner_area.x + 2,
-                                y: textarea_y,
-                                width: inner_area.width.saturating_sub(4),
-                                height: textarea_height,
-                            };
+                // Docked to the bottom of the content area rather than
+                // positioned at `comment_target_line`, so the editor stays
+                // reachable even after the user scrolls its target offscreen.
+                let content_lines = textarea.lines().len();
+                let min_lines = 3;
+                let actual_content_lines = content_lines.max(min_lines);
+                let textarea_height = (actual_content_lines + 2) as u16; // + borders
+                let header_height = 1u16.min(inner_area.height);
+                let dock_height = (header_height + textarea_height).min(inner_area.height);
+                let dock_y = inner_area.y + inner_area.height.saturating_sub(dock_height);
+
+                let dock_rect = Rect {
+                    x: inner_area.x,
+                    y: dock_y,
+                    width: inner_area.width,
+                    height: dock_height,
+                };
 
-                            textarea.set_style(
-                                RatatuiStyle::default()
-                                    .fg(palette.base_05)
-                                    .bg(palette.base_00),
-                            );
-                            textarea.set_cursor_style(
-                                RatatuiStyle::default()
-                                    .fg(palette.base_00)
-                                    .bg(palette.base_05),
-                            );
+                let clear_block =
+                    Block::default().style(RatatuiStyle::default().bg(palette.base_00));
+                frame.render_widget(clear_block, dock_rect);
 
-                            let block = Block::default()
-                                .borders(Borders::ALL)
-                                .title(" Add Comment ")
-                                .style(
-                                    RatatuiStyle::default()
-                                        .fg(palette.base_04)
-                                        .bg(palette.base_00),
-                                );
-                            textarea.set_block(block);
-
-                            frame.render_widget(&*textarea, padded_rect);
+                if header_height > 0 {
+                    let header_rect = Rect {
+                        x: inner_area.x + 2,
+                        y: dock_y,
+                        width: inner_area.width.saturating_sub(4),
+                        height: header_height,
+                    };
+                    let preview = self.comment_target_preview.as_deref().unwrap_or("");
+                    // The editor is always docked to the bottom, so let the
+                    // reader know which direction to scroll to see the
+                    // paragraph it's attached to once that paragraph is no
+                    // longer the one on screen.
+                    let anchor_indicator = match self.comment_target_line {
+                        Some(target_line) if target_line < self.scroll_offset => "↑ ",
+                        Some(target_line)
+                            if target_line >= self.scroll_offset + self.visible_height =>
+                        {
+                            "↓ "
                         }
-                    }
+                        _ => "",
+                    };
+                    let header = Paragraph::new(format!("{anchor_indicator}On: {preview}")).style(
+                        RatatuiStyle::default()
+                            .fg(palette.base_03)
+                            .bg(palette.base_00),
+                    );
+                    frame.render_widget(header, header_rect);
                 }
+
+                let padded_rect = Rect {
+                    x: inner_area.x + 2,
+                    y: dock_y + header_height,
+                    width: inner_area.width.saturating_sub(4),
+                    height: dock_height.saturating_sub(header_height),
+                };
+
+                textarea.set_style(
+                    RatatuiStyle::default()
+                        .fg(palette.base_05)
+                        .bg(palette.base_00),
+                );
+                textarea.set_cursor_style(
+                    RatatuiStyle::default()
+                        .fg(palette.base_00)
+                        .bg(palette.base_05),
+                );
+
+                let block = Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Add Comment ")
+                    .style(
+                        RatatuiStyle::default()
+                            .fg(palette.base_04)
+                            .bg(palette.base_00),
+                    );
+                textarea.set_block(block);
+
+                frame.render_widget(&*textarea, padded_rect);
+            }
+        }
+
+        self.render_scrollbar_gutter(frame, area, inner_area, palette);
+
+        if self.is_toc_picker_active() {
+            self.render_toc_picker(frame, area, palette);
+        }
+    }
+
+    /// Refreshes (off-thread, when stale) and draws the document-wide
+    /// match/anchor scrollbar gutter in the block's right border column.
+    /// Drawn after the text and images so its markers sit on top of the
+    /// border instead of being overwritten by it.
+    fn render_scrollbar_gutter(
+        &mut self,
+        frame: &mut Frame,
+        area: Rect,
+        inner_area: Rect,
+        palette: &Base16Palette,
+    ) {
+        let gutter_height = inner_area.height as usize;
+        if gutter_height == 0 || area.width == 0 {
+            return;
+        }
+
+        let highlight_line = self
+            .highlight_visual_line
+            .map(|visual_line| self.scroll_offset + visual_line);
+
+        let signature = ScrollbarMarkerSignature {
+            content_generation: self.rendered_content.generation,
+            query: self.search_state.query.clone(),
+            match_count: self.search_state.matches.len(),
+            current_match_index: self.search_state.current_match_index,
+            comment_count: self.current_chapter_comments.len(),
+            highlight_line,
+            gutter_height,
+        };
+
+        self.scrollbar_markers.poll();
+        let anchor_lines: Vec<usize> = self.anchor_positions.values().copied().collect();
+        let comment_lines: Vec<usize> = self
+            .current_chapter_comments
+            .keys()
+            .filter_map(|&node_idx| {
+                self.rendered_content
+                    .lines
+                    .iter()
+                    .position(|line| line.node_index == Some(node_idx))
+            })
+            .collect();
+        let match_lines: Vec<(usize, bool)> = self
+            .search_state
+            .matches
+            .iter()
+            .enumerate()
+            .map(|(i, m)| (m.index, self.search_state.current_match_index == Some(i)))
+            .collect();
+        self.scrollbar_markers.start_if_stale(
+            signature,
+            anchor_lines,
+            comment_lines,
+            match_lines,
+            highlight_line,
+            self.total_wrapped_lines,
+        );
+
+        let gutter_x = area.x + area.width.saturating_sub(1);
+        let mut gutter_lines: Vec<Line<'static>> = (0..gutter_height)
+            .map(|_| {
+                Line::from(Span::styled(
+                    "│",
+                    RatatuiStyle::default().fg(palette.base_01),
+                ))
+            })
+            .collect();
+
+        for marker in self.scrollbar_markers.markers() {
+            if marker.row >= gutter_lines.len() {
+                continue;
             }
+            let (glyph, color) = match marker.kind {
+                ScrollbarMarkerKind::Highlight => ("█", palette.base_0d),
+                ScrollbarMarkerKind::CurrentMatch => ("█", Color::Yellow),
+                ScrollbarMarkerKind::Match => ("█", Color::Rgb(150, 150, 0)),
+                ScrollbarMarkerKind::Comment => ("▐", palette.base_0e),
+                ScrollbarMarkerKind::Anchor => ("▐", palette.base_0c),
+            };
+            gutter_lines[marker.row] =
+                Line::from(Span::styled(glyph, RatatuiStyle::default().fg(color)));
         }
+
+        let gutter_rect = Rect {
+            x: gutter_x,
+            y: inner_area.y,
+            width: 1,
+            height: inner_area.height,
+        };
+        self.last_gutter_area = Some(gutter_rect);
+        let gutter_widget = Paragraph::new(gutter_lines);
+        frame.render_widget(gutter_widget, gutter_rect);
     }
 
     fn get_last_content_area(&self) -> Option<Rect> {
@@ -3711,6 +6646,192 @@ impl TextReaderTrait for MarkdownTextReader {
     }
 }
 
+impl MarkdownTextReader {
+    /// Like `start_search`, but opens the query in backward mode (`?`), so
+    /// `n` steps toward the document start and `N` reverses toward the end.
+    pub fn start_search_backward(&mut self) {
+        self.search_state
+            .start_search_direction(self.scroll_offset, Direction::Backward);
+    }
+
+    /// Flips whether fenced code blocks are run through `syntect`. Bumps
+    /// `cache_generation` so the next render re-runs `render_code_block`
+    /// with the new setting rather than reusing the stale `rendered_content`.
+    pub fn toggle_syntax_highlighting(&mut self) {
+        self.syntax_highlighting_enabled = !self.syntax_highlighting_enabled;
+        self.cache_generation += 1;
+        debug!(
+            "Toggled code syntax highlighting: {}",
+            self.syntax_highlighting_enabled
+        );
+    }
+
+    /// Jumps to the first (`land_last = false`) or last (`true`) match in
+    /// the current chapter's search results, bypassing the
+    /// direction-relative stepping `next_match`/`previous_match` do. Used
+    /// after a cross-chapter search hop lands in a freshly-scanned
+    /// chapter, where there's no "current" match to step from yet.
+    pub fn land_on_chapter_search_edge(&mut self, land_last: bool) -> bool {
+        if self.search_state.matches.is_empty() {
+            return false;
+        }
+        let target_idx = if land_last {
+            self.search_state.matches.len() - 1
+        } else {
+            0
+        };
+        self.search_state.current_match_index = Some(target_idx);
+        if let Some(search_match) = self.search_state.matches.get(target_idx) {
+            let match_index = search_match.index;
+            self.jump_to_match(match_index);
+        }
+        true
+    }
+
+    /// Flips between literal substring search (the default) and regex
+    /// search, re-running the current query against the new mode.
+    pub fn toggle_search_literal_mode(&mut self) {
+        self.search_literal_mode = !self.search_literal_mode;
+        if self.search_state.active {
+            let query = self.search_state.query.clone();
+            self.update_search_query(&query);
+        }
+    }
+
+    /// Kick off a document-wide search scan on a background thread, the way
+    /// a busy editor offloads marker computation so the render loop never
+    /// blocks on a large book. `get_searchable_content` is partitioned into
+    /// `MAX_SEARCH_LINES`-sized chunks (so each chunk fits the existing
+    /// bounded `find_regex_matches_in_text` window without clipping) and
+    /// searched independently; every match is re-tagged with its absolute
+    /// line index. Each chunk's matches are streamed back over the channel
+    /// as soon as they're found, tagged with `epoch` so `poll_search_scan`
+    /// can discard them if a newer query has superseded this scan.
+    fn start_search_scan(&mut self, query: String) {
+        self.search_scan_epoch += 1;
+        let epoch = self.search_scan_epoch;
+        self.search_state.begin_scan();
+
+        let lines = self.get_searchable_content();
+        let literal = self.search_literal_mode;
+
+        let (tx, rx) = mpsc::channel();
+        self.search_scan_rx = Some(rx);
+
+        thread::spawn(move || {
+            for chunk_start in (0..lines.len()).step_by(MAX_SEARCH_LINES) {
+                let chunk_end = (chunk_start + MAX_SEARCH_LINES).min(lines.len());
+                let chunk = &lines[chunk_start..chunk_end];
+
+                // `origin` is the chunk's own length so the ±MAX_SEARCH_LINES
+                // window always covers the whole (<=MAX_SEARCH_LINES) chunk.
+                match find_regex_matches_in_text(&query, chunk, chunk.len(), literal) {
+                    Ok(chunk_matches) => {
+                        let matches: Vec<SearchMatch> = chunk_matches
+                            .into_iter()
+                            .map(|mut m| {
+                                m.index += chunk_start;
+                                m
+                            })
+                            .collect();
+
+                        // Stream this chunk back immediately instead of
+                        // buffering the whole scan, so the UI can show
+                        // partial results on a large book. The receiver may
+                        // already be gone if a newer query replaced this
+                        // scan's channel; stop scanning if so.
+                        if tx.send((epoch, matches)).is_err() {
+                            return;
+                        }
+                    }
+                    Err(e) => {
+                        warn!("Invalid search pattern '{}': {}", query, e);
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Drives the debounced, asynchronous side of search: starts a scan once
+    /// the query has settled for a bit, and drains every scan-chunk result
+    /// waiting on `search_scan_rx`, appending each to `search_state` as it
+    /// arrives. Called once per event loop tick. Returns `true` if a redraw
+    /// is needed.
+    pub fn poll_search_scan(&mut self) -> bool {
+        const DEBOUNCE: Duration = Duration::from_millis(150);
+
+        if let Some((query, changed_at)) = self.pending_search_query.clone() {
+            if changed_at.elapsed() >= DEBOUNCE {
+                self.pending_search_query = None;
+                self.start_search_scan(query);
+            }
+        }
+
+        let Some(rx) = &self.search_scan_rx else {
+            return false;
+        };
+
+        let mut redraw = false;
+        loop {
+            match rx.try_recv() {
+                Ok((epoch, matches)) => {
+                    if epoch != self.search_scan_epoch {
+                        // Superseded by a newer query; drop the stale chunk.
+                        continue;
+                    }
+                    let had_current = self.search_state.current_match_index.is_some();
+                    self.search_state.append_matches(matches);
+                    if !had_current {
+                        // Jump as soon as the scan finds its first match,
+                        // rather than waiting for the whole document.
+                        if let Some(match_index) = self.search_state.get_current_match() {
+                            self.jump_to_match(match_index);
+                        }
+                    }
+                    redraw = true;
+                }
+                Err(mpsc::TryRecvError::Empty) => break,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    self.search_scan_rx = None;
+                    self.search_state.finish_scan();
+                    redraw = true;
+                    break;
+                }
+            }
+        }
+        redraw
+    }
+
+    /// Jumps to the match nearest `self.scroll_offset` in `direction`,
+    /// anchored to where the reader actually is rather than stepping through
+    /// `matches` positionally from `current_match_index` - so `n`/`N` stay
+    /// correct even after the reader scrolled or jumped independently of a
+    /// previous search navigation.
+    fn advance_match(&mut self, direction: Direction) {
+        let current_position = self.scroll_offset;
+        let Some(target_idx) = self
+            .search_state
+            .match_index_for_direction(current_position, direction)
+        else {
+            return;
+        };
+
+        self.search_state.current_match_index = Some(target_idx);
+        if let Some(search_match) = self.search_state.matches.get(target_idx) {
+            self.jump_to_match(search_match.index);
+        }
+    }
+
+    fn advance_match_forward(&mut self) {
+        self.advance_match(Direction::Forward);
+    }
+
+    fn advance_match_backward(&mut self) {
+        self.advance_match(Direction::Backward);
+    }
+}
+
 impl SearchablePanel for MarkdownTextReader {
     fn start_search(&mut self) {
         self.search_state.start_search(self.scroll_offset);
@@ -3738,92 +6859,35 @@ impl SearchablePanel for MarkdownTextReader {
     fn update_search_query(&mut self, query: &str) {
         self.search_state.update_query(query.to_string());
 
-        // Find matches in visible text
-        let searchable = self.get_searchable_content();
-        let matches = find_matches_in_text(query, &searchable);
-        self.search_state.set_matches(matches);
-
-        // Jump to match if found
-        if let Some(match_index) = self.search_state.get_current_match() {
-            self.jump_to_match(match_index);
-        }
-    }
-
-    fn next_match(&mut self) {
-        if self.search_state.matches.is_empty() {
+        if query.is_empty() {
+            // Preserve the caller's original scroll position: don't scan,
+            // don't jump, just drop whatever matches/scan were pending.
+            self.search_state.set_matches(Vec::new());
+            self.search_state.finish_scan();
+            self.pending_search_query = None;
+            self.search_scan_rx = None;
             return;
         }
 
-        // If we have a current match index, go to the next one
-        if let Some(current_idx) = self.search_state.current_match_index {
-            // Move to next match
-            let next_idx = (current_idx + 1) % self.search_state.matches.len();
-            self.search_state.current_match_index = Some(next_idx);
-
-            if let Some(search_match) = self.search_state.matches.get(next_idx) {
-                self.jump_to_match(search_match.index);
-            }
-        } else {
-            // No current match, find the first match after current viewport position
-            let current_position = self.scroll_offset;
-
-            // Find the first match that's after the current position
-            let mut next_match_idx = None;
-            for (idx, search_match) in self.search_state.matches.iter().enumerate() {
-                if search_match.index > current_position {
-                    next_match_idx = Some(idx);
-                    break;
-                }
-            }
-
-            // If no match found after current position, wrap to beginning
-            let target_idx = next_match_idx.unwrap_or(0);
-            self.search_state.current_match_index = Some(target_idx);
+        // Debounce: record the query and let `poll_search_scan` kick off the
+        // actual background scan once typing settles, rather than spawning a
+        // worker per keystroke.
+        self.pending_search_query = Some((query.to_string(), Instant::now()));
+    }
 
-            if let Some(search_match) = self.search_state.matches.get(target_idx) {
-                self.jump_to_match(search_match.index);
-            }
+    fn next_match(&mut self) {
+        // 'n' continues in whichever direction the search was opened with.
+        match self.search_state.direction {
+            Direction::Forward => self.advance_match_forward(),
+            Direction::Backward => self.advance_match_backward(),
         }
     }
 
     fn previous_match(&mut self) {
-        if self.search_state.matches.is_empty() {
-            return;
-        }
-
-        // If we have a current match index, go to the previous one
-        if let Some(current_idx) = self.search_state.current_match_index {
-            // Move to previous match
-            let prev_idx = if current_idx == 0 {
-                self.search_state.matches.len() - 1
-            } else {
-                current_idx - 1
-            };
-            self.search_state.current_match_index = Some(prev_idx);
-
-            if let Some(search_match) = self.search_state.matches.get(prev_idx) {
-                self.jump_to_match(search_match.index);
-            }
-        } else {
-            // No current match, find the last match before current viewport position
-            let current_position = self.scroll_offset;
-
-            // Find the last match that's before the current position
-            let mut prev_match_idx = None;
-            for (idx, search_match) in self.search_state.matches.iter().enumerate().rev() {
-                if search_match.index < current_position {
-                    prev_match_idx = Some(idx);
-                    break;
-                }
-            }
-
-            // If no match found before current position, wrap to end
-            let target_idx = prev_match_idx.unwrap_or(self.search_state.matches.len() - 1);
-            self.search_state.current_match_index = Some(target_idx);
-
-            if let Some(search_match) = self.search_state.matches.get(target_idx) {
-                self.jump_to_match(search_match.index);
-            }
+        // 'N' reverses the direction the search was opened with.
+        match self.search_state.direction {
+            Direction::Forward => self.advance_match_backward(),
+            Direction::Backward => self.advance_match_forward(),
         }
     }
 
@@ -3840,7 +6904,15 @@ impl SearchablePanel for MarkdownTextReader {
     }
 
     fn jump_to_match(&mut self, match_index: usize) {
-        self.jump_to_line(match_index);
+        // Position the match a third of the way down the viewport, rather
+        // than dead center (`jump_to_line`), so the surrounding context
+        // reads more naturally for `n`/`N` navigation.
+        if match_index < self.rendered_content.lines.len() {
+            let third = self.visible_height / 3;
+            self.scroll_offset = match_index.saturating_sub(third);
+            self.scroll_offset = self.scroll_offset.min(self.get_max_scroll_offset());
+        }
+        self.highlight_line_temporarily(match_index, Duration::from_secs(2));
     }
 
     fn get_searchable_content(&self) -> Vec<String> {
@@ -3852,3 +6924,23 @@ fn calculate_image_height_in_cells(image: &DynamicImage) -> u16 {
     let (width, height) = image.dimensions();
     EmbeddedImage::height_in_cells(width, height)
 }
+
+fn toc_picker_centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = ratatui::layout::Layout::default()
+        .direction(ratatui::layout::Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    ratatui::layout::Layout::default()
+        .direction(ratatui::layout::Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -0,0 +1,15 @@
+/// A saved reading position for `MarkdownTextReader`'s named-mark
+/// subsystem (`m{char}` to set, `'{char}` to jump back), vim-style.
+///
+/// Positions are recorded by document node rather than raw line number so
+/// a mark survives re-renders and terminal-width changes, neither of which
+/// change node indices even though they reflow line numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkPosition {
+    /// Chapter the mark was set in, for detecting cross-chapter jumps.
+    pub chapter_file: Option<String>,
+    /// Document node nearest the marked position.
+    pub node_index: usize,
+    /// Scroll offset at the time the mark was set.
+    pub scroll_offset: usize,
+}
@@ -0,0 +1,256 @@
+use crate::bookmark::NamedBookmark;
+use crate::theme::Base16Palette;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+/// What `App` should do after a named-bookmarks popup key event.
+pub enum NamedBookmarksAction {
+    JumpTo { chapter: usize, scroll_offset: usize },
+    Delete { index: usize },
+    Rename { index: usize },
+    Close,
+}
+
+/// Lists the named bookmarks dropped inside the current book (see
+/// `Bookmarks::add_named_bookmark`) so the user can jump to one or delete
+/// it, the way a file manager lists its saved locations.
+pub struct NamedBookmarksPopup {
+    book_path: String,
+    items: Vec<NamedBookmark>,
+    state: ListState,
+}
+
+impl NamedBookmarksPopup {
+    pub fn new(book_path: String, items: Vec<NamedBookmark>) -> Self {
+        let mut state = ListState::default();
+        if !items.is_empty() {
+            state.select(Some(0));
+        }
+        Self {
+            book_path,
+            items,
+            state,
+        }
+    }
+
+    pub fn book_path(&self) -> &str {
+        &self.book_path
+    }
+
+    /// Index of the currently selected bookmark, for the caller to pass to
+    /// `Bookmarks::delete_named_bookmark`/`rename_named_bookmark` on `d`/`r`.
+    pub fn selected_index(&self) -> Option<usize> {
+        self.state.selected()
+    }
+
+    /// Current label of the bookmark at `index`, for pre-filling the rename
+    /// label popup.
+    pub fn label_at(&self, index: usize) -> Option<&str> {
+        self.items.get(index).map(|item| item.label.as_str())
+    }
+
+    fn move_selection(&mut self, delta: i32) {
+        if self.items.is_empty() {
+            return;
+        }
+        let len = self.items.len() as i32;
+        let current = self.state.selected().unwrap_or(0) as i32;
+        self.state
+            .select(Some((current + delta).rem_euclid(len) as usize));
+    }
+
+    /// Removes the currently selected entry from the popup's own snapshot
+    /// (the caller is responsible for deleting it from persisted storage),
+    /// keeping the selection in range.
+    pub fn remove_selected(&mut self) {
+        if let Some(index) = self.state.selected() {
+            if index < self.items.len() {
+                self.items.remove(index);
+            }
+            if self.items.is_empty() {
+                self.state.select(None);
+            } else {
+                self.state.select(Some(index.min(self.items.len() - 1)));
+            }
+        }
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<NamedBookmarksAction> {
+        match key.code {
+            KeyCode::Esc => Some(NamedBookmarksAction::Close),
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.move_selection(1);
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.move_selection(-1);
+                None
+            }
+            KeyCode::Enter => self
+                .state
+                .selected()
+                .and_then(|i| self.items.get(i))
+                .map(|item| NamedBookmarksAction::JumpTo {
+                    chapter: item.chapter,
+                    scroll_offset: item.scroll_offset,
+                }),
+            KeyCode::Char('d') => self
+                .state
+                .selected()
+                .map(|index| NamedBookmarksAction::Delete { index }),
+            KeyCode::Char('r') => self
+                .state
+                .selected()
+                .map(|index| NamedBookmarksAction::Rename { index }),
+            _ => None,
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect, palette: &Base16Palette) {
+        let popup_area = centered_rect(50, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let items: Vec<ListItem> = self
+            .items
+            .iter()
+            .map(|item| {
+                let date_str = item.created_at.format("%Y-%m-%d").to_string();
+                ListItem::new(Line::from(vec![
+                    Span::styled(date_str, Style::default().fg(palette.base_03)),
+                    Span::raw(" : "),
+                    Span::styled(&item.label, Style::default().fg(palette.base_05)),
+                    Span::styled(
+                        format!(" [ch. {}]", item.chapter + 1),
+                        Style::default().fg(palette.base_03),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title(" Bookmarks ")
+                    .borders(Borders::ALL)
+                    .style(Style::default().bg(palette.base_00).fg(palette.base_05)),
+            )
+            .highlight_style(
+                Style::default()
+                    .bg(palette.base_02)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("» ");
+
+        f.render_stateful_widget(list, popup_area, &mut self.state);
+    }
+}
+
+/// Which bookmark a [`NamedBookmarkLabelPopup`] submission applies to.
+pub enum NamedBookmarkLabelTarget {
+    Add { chapter: usize, scroll_offset: usize },
+    Rename { index: usize },
+}
+
+/// What `App` should do after a label-entry popup key event.
+pub enum NamedBookmarkLabelAction {
+    Submit(String),
+    Close,
+}
+
+/// Single-line label entry popup, reused for naming a new bookmark
+/// (`Add`) and for renaming an existing one (`Rename`). Same shape as
+/// `UrlImportPopup`, applied to bookmark labels instead of a URL.
+pub struct NamedBookmarkLabelPopup {
+    target: NamedBookmarkLabelTarget,
+    label: String,
+}
+
+impl NamedBookmarkLabelPopup {
+    pub fn new(target: NamedBookmarkLabelTarget, initial_label: String) -> Self {
+        Self {
+            target,
+            label: initial_label,
+        }
+    }
+
+    pub fn target(&self) -> &NamedBookmarkLabelTarget {
+        &self.target
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<NamedBookmarkLabelAction> {
+        match key.code {
+            KeyCode::Esc => Some(NamedBookmarkLabelAction::Close),
+            KeyCode::Backspace => {
+                self.label.pop();
+                None
+            }
+            KeyCode::Char(c) => {
+                self.label.push(c);
+                None
+            }
+            KeyCode::Enter if !self.label.trim().is_empty() => {
+                Some(NamedBookmarkLabelAction::Submit(self.label.trim().to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, palette: &Base16Palette) {
+        let popup_area = centered_rect_fixed_height(60, 3, area);
+        f.render_widget(Clear, popup_area);
+
+        let title = match self.target {
+            NamedBookmarkLabelTarget::Add { .. } => " New Bookmark Label ",
+            NamedBookmarkLabelTarget::Rename { .. } => " Rename Bookmark ",
+        };
+        let input = Paragraph::new(format!("> {}", self.label)).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .style(Style::default().bg(palette.base_00).fg(palette.base_05)),
+        );
+        f.render_widget(input, popup_area);
+    }
+}
+
+/// Centers a `percent_x`-wide, `height`-tall rect within `r`, for the
+/// single-line label popup (see `UrlImportPopup::centered_rect`, the same
+/// shape applied to URL entry).
+fn centered_rect_fixed_height(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let x = r.x + (r.width.saturating_sub(r.width * percent_x / 100)) / 2;
+    let y = r.y + r.height.saturating_sub(height) / 2;
+    let width = r.width * percent_x / 100;
+    Rect {
+        x,
+        y,
+        width,
+        height: height.min(r.height),
+    }
+}
+
+/// Centers a `percent_x` x `percent_y` rect within `r`.
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
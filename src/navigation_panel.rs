@@ -1,10 +1,12 @@
 use crate::book_list::BookList;
 use crate::book_manager::BookManager;
+use crate::inputs::KeySeq;
 use crate::main_app::VimNavMotions;
 use crate::markdown_text_reader::ActiveSection;
 use crate::search::{SearchState, SearchablePanel};
 use crate::table_of_contents::{SelectedTocItem, TableOfContents, TocItem};
 use crate::theme::Base16Palette;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{Frame, layout::Rect};
 
 pub enum SelectedActionOwned {
@@ -14,6 +16,24 @@ pub enum SelectedActionOwned {
     TocItem(TocItem),
 }
 
+/// Outcome of a key routed to the navigation panel via `handle_key`: either
+/// the panel consumed it itself (`None`), or it resolves to something the
+/// caller must carry out against the rest of the app (open a book, jump to
+/// a chapter, ...). `Bypass` means the panel had no use for the key at all,
+/// so the caller's own bindings (quit, Tab to switch panels, etc) should
+/// still see it.
+pub enum NavigationPanelAction {
+    Bypass,
+    SelectBook { book_index: usize },
+    SelectBookInNewTab { book_index: usize },
+    SwitchToBookList,
+    NavigateToChapter {
+        href: String,
+        anchor: Option<String>,
+    },
+    ToggleSection,
+}
+
 #[derive(Clone)]
 pub struct CurrentBookInfo {
     pub path: String,
@@ -123,6 +143,40 @@ impl NavigationPanel {
         }
     }
 
+    /// Arms a potential book-list reorder drag on mouse-down; a no-op
+    /// outside `BookSelection` mode.
+    pub fn handle_book_drag_start(&mut self, y: u16, area: Rect) {
+        if self.mode == NavigationMode::BookSelection {
+            self.book_list.start_press(y, area);
+        }
+    }
+
+    /// Updates an in-progress book-list drag on mouse-move; a no-op
+    /// outside `BookSelection` mode.
+    pub fn handle_book_drag_move(&mut self, y: u16, area: Rect) {
+        if self.mode == NavigationMode::BookSelection {
+            self.book_list.handle_drag(y, area);
+        }
+    }
+
+    /// Whether the book list has an active (past-threshold) reorder drag,
+    /// so the caller can suppress the normal click-to-open behavior on
+    /// release.
+    pub fn is_dragging_book(&self) -> bool {
+        self.mode == NavigationMode::BookSelection && self.book_list.is_dragging()
+    }
+
+    /// Resolves a book-list drag on mouse-up, reordering it if one had
+    /// actually started. Returns the `(from, to)` indices moved, for the
+    /// caller to mirror into `BookManager`.
+    pub fn handle_book_drag_end(&mut self) -> Option<(usize, usize)> {
+        if self.mode == NavigationMode::BookSelection {
+            self.book_list.end_drag()
+        } else {
+            None
+        }
+    }
+
     /// Get the currently selected index based on the mode
     pub fn get_selected_action(&self) -> SelectedActionOwned {
         match self.mode {
@@ -145,6 +199,89 @@ impl NavigationPanel {
         }
     }
 
+    /// Routes a key press while the navigation panel has focus. Vim motions
+    /// (`j`/`k`/`h`/`l`, `gg`/`G`, `Ctrl-d`/`Ctrl-u`) are handled directly via
+    /// `VimNavMotions` regardless of mode; `Enter` resolves the current
+    /// selection into a `NavigationPanelAction` for the caller, and `/`
+    /// starts the panel's own search. Anything else is bypassed so the
+    /// caller's own bindings (quit, Tab) still fire.
+    pub fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        key_sequence: &mut KeySeq,
+    ) -> Option<NavigationPanelAction> {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.handle_j();
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.handle_k();
+                None
+            }
+            KeyCode::Char('h') | KeyCode::Left => {
+                self.handle_h();
+                None
+            }
+            KeyCode::Char('l') | KeyCode::Right => {
+                self.handle_l();
+                None
+            }
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.handle_ctrl_d();
+                None
+            }
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.handle_ctrl_u();
+                None
+            }
+            KeyCode::Char('G') => {
+                self.handle_upper_g();
+                None
+            }
+            KeyCode::Char('g') => {
+                if key_sequence.handle_key('g') == "gg" {
+                    self.handle_gg();
+                    key_sequence.clear();
+                }
+                None
+            }
+            KeyCode::Char('/') => {
+                self.start_search();
+                None
+            }
+            KeyCode::Char('t') => match self.get_selected_action() {
+                SelectedActionOwned::BookIndex(book_index) => {
+                    Some(NavigationPanelAction::SelectBookInNewTab { book_index })
+                }
+                _ => Some(NavigationPanelAction::Bypass),
+            },
+            KeyCode::Enter => Some(self.resolve_selection()),
+            _ => Some(NavigationPanelAction::Bypass),
+        }
+    }
+
+    /// Translates the currently selected row into the action `handle_key`'s
+    /// caller should perform, mirroring what the mouse double-click path
+    /// (`handle_navigation_panel_enter` in main_app.rs) does for the
+    /// equivalent `SelectedActionOwned` values.
+    fn resolve_selection(&mut self) -> NavigationPanelAction {
+        match self.get_selected_action() {
+            SelectedActionOwned::None => NavigationPanelAction::Bypass,
+            SelectedActionOwned::BookIndex(book_index) => {
+                NavigationPanelAction::SelectBook { book_index }
+            }
+            SelectedActionOwned::BackToBooks => NavigationPanelAction::SwitchToBookList,
+            SelectedActionOwned::TocItem(toc_item) => match toc_item.href() {
+                Some(href) => NavigationPanelAction::NavigateToChapter {
+                    href: href.to_string(),
+                    anchor: toc_item.anchor().cloned(),
+                },
+                None => NavigationPanelAction::ToggleSection,
+            },
+        }
+    }
+
     pub fn render(
         &mut self,
         f: &mut Frame,
@@ -1,4 +1,293 @@
-use crate::markdown::{Block, Document, HeadingLevel, Inline, Node, Text, TextNode, TextOrInline};
+use std::collections::{HashMap, HashSet};
+
+use crate::markdown::{
+    Block, Document, HeadingLevel, Inline, ListItem, ListKind, Node, TableAlignment, TableRow,
+    Text, TextNode, TextOrInline,
+};
+
+/// Footnotes collected while rendering a document: definitions keyed by
+/// label, and the labels in first-appearance-of-reference order so the
+/// trailing Notes section can be numbered and deduplicated.
+#[derive(Default)]
+struct FootnoteState {
+    definitions: HashMap<String, Vec<Node>>,
+    order: Vec<String>,
+    seen: HashSet<String>,
+}
+
+/// One heading in the nested table of contents built by [`TocBuilder`].
+/// `children` holds headings whose level is greater than this entry's,
+/// nested the same way the rendered document itself nests under them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    pub level: HeadingLevel,
+    pub title: String,
+    /// Unique, URL-safe anchor derived from `title`.
+    pub slug: String,
+    /// Byte offset of this heading in the string `render_with_toc` returns.
+    pub offset: usize,
+    pub children: Vec<TocEntry>,
+}
+
+/// Builds the heading hierarchy for `render_with_toc`, rustdoc-`derive_id`
+/// style: each heading is slugified and disambiguated against every slug
+/// handed out so far, and a stack of open `(level, TocEntry)` pairs is
+/// closed down to the incoming heading's level before it's pushed, so it
+/// attaches as a child of the current parent (or becomes a new root).
+#[derive(Default)]
+struct TocBuilder {
+    slugs: HashMap<String, usize>,
+    stack: Vec<(u8, TocEntry)>,
+    roots: Vec<TocEntry>,
+}
+
+impl TocBuilder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lowercases and trims `text`, collapsing every run of non-alphanumeric
+    /// characters to a single `-` (and trimming any that remain at the
+    /// edges), matching rustdoc's heading-id slugification.
+    fn slugify(text: &str) -> String {
+        let mut slug = String::new();
+        let mut pending_dash = false;
+        for c in text.trim().to_lowercase().chars() {
+            if c.is_ascii_alphanumeric() {
+                if pending_dash && !slug.is_empty() {
+                    slug.push('-');
+                }
+                pending_dash = false;
+                slug.push(c);
+            } else {
+                pending_dash = true;
+            }
+        }
+        slug
+    }
+
+    fn unique_slug(&mut self, base: String) -> String {
+        match self.slugs.get_mut(&base) {
+            None => {
+                self.slugs.insert(base.clone(), 0);
+                base
+            }
+            Some(count) => {
+                *count += 1;
+                format!("{base}-{count}")
+            }
+        }
+    }
+
+    /// Closes every open entry whose level is `>= level`, attaching each as
+    /// a child of whatever remains below it on the stack (or, once the
+    /// stack empties, as a new root).
+    fn close_to(&mut self, level: u8) {
+        while let Some((top_level, _)) = self.stack.last() {
+            if *top_level >= level {
+                let (_, entry) = self.stack.pop().unwrap();
+                match self.stack.last_mut() {
+                    Some((_, parent)) => parent.children.push(entry),
+                    None => self.roots.push(entry),
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Registers a heading, returning its unique slug.
+    fn push_heading(&mut self, level: HeadingLevel, title: &str, offset: usize) -> String {
+        self.close_to(level.as_u8());
+
+        let slug = self.unique_slug(Self::slugify(title));
+        self.stack.push((
+            level.as_u8(),
+            TocEntry {
+                level,
+                title: title.to_string(),
+                slug: slug.clone(),
+                offset,
+                children: Vec::new(),
+            },
+        ));
+        slug
+    }
+
+    fn finish(mut self) -> Vec<TocEntry> {
+        self.close_to(0);
+        self.roots
+    }
+}
+
+/// Category of a highlighted code-block token, used to pick its ANSI color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightKind {
+    Plain,
+    Keyword,
+    String,
+    Comment,
+    Number,
+}
+
+/// Tokenizes a fenced code block's contents for styled terminal output.
+/// Implementations recognize some set of `language` info-string values;
+/// returning `None` tells the renderer the language isn't recognized, so
+/// it falls back to plain, unstyled text.
+pub trait CodeHighlighter {
+    fn highlight(&self, code: &str, language: &str) -> Option<Vec<(HighlightKind, String)>>;
+}
+
+/// Default highlighter: recognizes no languages, so fenced code blocks
+/// render exactly as before (plain monospace, no ANSI codes). Swap in a
+/// real implementation (e.g. syntect-backed) via
+/// [`MarkdownRenderer::with_highlighter`].
+struct NoOpHighlighter;
+
+impl CodeHighlighter for NoOpHighlighter {
+    fn highlight(&self, _code: &str, _language: &str) -> Option<Vec<(HighlightKind, String)>> {
+        None
+    }
+}
+
+const ANSI_RESET: &str = "\x1b[0m";
+
+fn ansi_color(kind: HighlightKind) -> &'static str {
+    match kind {
+        HighlightKind::Plain => "",
+        HighlightKind::Keyword => "\x1b[35m",
+        HighlightKind::String => "\x1b[32m",
+        HighlightKind::Comment => "\x1b[90m",
+        HighlightKind::Number => "\x1b[33m",
+    }
+}
+
+fn ansi_wrap(kind: HighlightKind, text: &str) -> String {
+    match kind {
+        HighlightKind::Plain => text.to_string(),
+        _ => format!("{}{}{}", ansi_color(kind), text, ANSI_RESET),
+    }
+}
+
+/// A dependency-free [`CodeHighlighter`] covering a handful of common
+/// languages via naive scanning (identifiers against a per-language
+/// keyword list, `"`/`'`-delimited strings, line comments, bare numbers).
+/// Good enough for terminal output; a syntect-backed highlighter can
+/// replace it wherever finer-grained or grammar-accurate highlighting is
+/// worth the dependency.
+pub struct BasicCodeHighlighter;
+
+impl BasicCodeHighlighter {
+    fn keywords_for(language: &str) -> Option<(&'static [&'static str], &'static str)> {
+        match language.to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Some((
+                &[
+                    "fn", "let", "mut", "pub", "struct", "enum", "impl", "trait", "use", "mod",
+                    "match", "if", "else", "for", "while", "loop", "return", "self", "Self",
+                    "true", "false", "const", "static", "async", "await", "move", "where", "dyn",
+                    "ref", "as", "in", "break", "continue", "unsafe",
+                ],
+                "//",
+            )),
+            "python" | "py" => Some((
+                &[
+                    "def", "class", "import", "from", "return", "if", "elif", "else", "for",
+                    "while", "try", "except", "finally", "with", "as", "pass", "break",
+                    "continue", "lambda", "yield", "None", "True", "False", "and", "or", "not",
+                    "in", "is", "async", "await", "self",
+                ],
+                "#",
+            )),
+            "javascript" | "js" | "typescript" | "ts" => Some((
+                &[
+                    "function", "const", "let", "var", "return", "if", "else", "for", "while",
+                    "class", "extends", "import", "export", "from", "async", "await", "try",
+                    "catch", "finally", "new", "this", "true", "false", "null", "undefined",
+                    "typeof", "instanceof", "switch", "case", "break", "continue",
+                ],
+                "//",
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl CodeHighlighter for BasicCodeHighlighter {
+    fn highlight(&self, code: &str, language: &str) -> Option<Vec<(HighlightKind, String)>> {
+        let (keywords, line_comment) = Self::keywords_for(language)?;
+
+        let chars: Vec<(usize, char)> = code.char_indices().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let (byte_idx, c) = chars[i];
+
+            if code[byte_idx..].starts_with(line_comment) {
+                let start = i;
+                while i < chars.len() && chars[i].1 != '\n' {
+                    i += 1;
+                }
+                tokens.push((HighlightKind::Comment, slice(&chars, code, start, i)));
+                continue;
+            }
+
+            if c == '"' || c == '\'' {
+                let start = i;
+                i += 1;
+                while i < chars.len() && chars[i].1 != c {
+                    if chars[i].1 == '\\' && i + 1 < chars.len() {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                if i < chars.len() {
+                    i += 1; // consume closing quote
+                }
+                tokens.push((HighlightKind::String, slice(&chars, code, start, i)));
+                continue;
+            }
+
+            if c.is_ascii_digit() {
+                let start = i;
+                while i < chars.len() && (chars[i].1.is_ascii_alphanumeric() || chars[i].1 == '.')
+                {
+                    i += 1;
+                }
+                tokens.push((HighlightKind::Number, slice(&chars, code, start, i)));
+                continue;
+            }
+
+            if c.is_alphabetic() || c == '_' {
+                let start = i;
+                while i < chars.len() && (chars[i].1.is_alphanumeric() || chars[i].1 == '_') {
+                    i += 1;
+                }
+                let word = slice(&chars, code, start, i);
+                let kind = if keywords.contains(&word.as_str()) {
+                    HighlightKind::Keyword
+                } else {
+                    HighlightKind::Plain
+                };
+                tokens.push((kind, word));
+                continue;
+            }
+
+            tokens.push((HighlightKind::Plain, c.to_string()));
+            i += 1;
+        }
+
+        Some(tokens)
+    }
+}
+
+/// Extracts `code[chars[start].0 .. end_byte]` where `end_byte` is the
+/// start of `chars[end]` (or `code.len()` when `end` runs off the end),
+/// i.e. the substring spanning char-index range `[start, end)`.
+fn slice(chars: &[(usize, char)], code: &str, start: usize, end: usize) -> String {
+    let start_byte = chars[start].0;
+    let end_byte = chars.get(end).map(|(b, _)| *b).unwrap_or(code.len());
+    code[start_byte..end_byte].to_string()
+}
 
 /// Simple Markdown AST to string renderer with no cleanup logic.
 ///
@@ -40,60 +329,177 @@ use crate::markdown::{Block, Document, HeadingLevel, Inline, Node, Text, TextNod
 /// # }
 /// ```
 pub struct MarkdownRenderer {
-    // Simple renderer - no cleanup logic needed as it's handled during conversion
+    highlighter: Box<dyn CodeHighlighter>,
 }
 
 impl MarkdownRenderer {
     pub fn new() -> Self {
-        MarkdownRenderer {}
+        MarkdownRenderer {
+            highlighter: Box::new(NoOpHighlighter),
+        }
+    }
+
+    /// Swaps in a different code-block highlighter (e.g. a syntect-backed
+    /// one). The default is [`NoOpHighlighter`], which leaves every fenced
+    /// code block unstyled.
+    pub fn with_highlighter(mut self, highlighter: Box<dyn CodeHighlighter>) -> Self {
+        self.highlighter = highlighter;
+        self
     }
 
     /// Renders a Markdown AST document to formatted string.
+    ///
+    /// Footnote definitions are collected up front (recursing into quotes,
+    /// lists and epub blocks) rather than rendered inline at their
+    /// definition site; a `[^label]` marker is emitted at each reference,
+    /// and a trailing "Notes" section lists the referenced definitions in
+    /// first-appearance order once the main body is done.
     pub fn render(&self, doc: &Document) -> String {
+        self.render_internal(doc, &mut None)
+    }
+
+    /// Like [`render`](Self::render), but additionally builds the nested
+    /// heading hierarchy implied by heading levels (rustdoc-`derive_id`
+    /// slugs, disambiguated on collision) so a reader UI can build a
+    /// jump-to-section menu for this chapter the same way it does for PDFs.
+    pub fn render_with_toc(&self, doc: &Document) -> (String, Vec<TocEntry>) {
+        let mut toc = Some(TocBuilder::new());
+        let output = self.render_internal(doc, &mut toc);
+        (output, toc.take().map(TocBuilder::finish).unwrap_or_default())
+    }
+
+    fn render_internal(&self, doc: &Document, toc: &mut Option<TocBuilder>) -> String {
         let mut output = String::new();
+        let mut footnotes = FootnoteState::default();
 
         for node in &doc.blocks {
-            self.render_node(node, &mut output);
+            self.collect_footnote_definitions(node, &mut footnotes);
         }
 
+        for node in &doc.blocks {
+            self.render_node(node, &mut output, &mut footnotes, toc);
+        }
+
+        self.render_footnotes_section(&mut footnotes, &mut output);
+
         output
     }
 
-    fn render_node(&self, node: &Node, output: &mut String) {
+    fn collect_footnote_definitions(&self, node: &Node, footnotes: &mut FootnoteState) {
+        match &node.block {
+            Block::FootnoteDefinition { tag, content } => {
+                footnotes
+                    .definitions
+                    .entry(tag.clone())
+                    .or_insert_with(|| content.clone());
+                for child in content {
+                    self.collect_footnote_definitions(child, footnotes);
+                }
+            }
+            Block::Quote { content } => {
+                for child in content {
+                    self.collect_footnote_definitions(child, footnotes);
+                }
+            }
+            Block::List { items, .. } => {
+                for item in items {
+                    for child in &item.content {
+                        self.collect_footnote_definitions(child, footnotes);
+                    }
+                }
+            }
+            Block::EpubBlock { content, .. } => {
+                for child in content {
+                    self.collect_footnote_definitions(child, footnotes);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Appends a `---` separator and a "Notes" section rendering each
+    /// referenced footnote as `[^label]: <text>`, in first-appearance
+    /// order. Definitions with no matching reference are left out since
+    /// there is no appearance order to number them by; references with no
+    /// matching definition already rendered their raw `[^label]` marker
+    /// inline, so no content is lost either way.
+    fn render_footnotes_section(&self, footnotes: &mut FootnoteState, output: &mut String) {
+        if footnotes.order.is_empty() {
+            return;
+        }
+
+        output.push_str("---\n\n");
+        output.push_str("## Notes\n\n");
+
+        let mut i = 0;
+        while i < footnotes.order.len() {
+            let tag = footnotes.order[i].clone();
+            if let Some(content) = footnotes.definitions.get(&tag).cloned() {
+                let mut note_output = String::new();
+                for node in &content {
+                    // Notes aren't part of the document outline, so headings
+                    // inside a footnote definition don't get a TOC entry.
+                    self.render_node(node, &mut note_output, footnotes, &mut None);
+                }
+                output.push_str(&format!("[^{}]: {}\n\n", tag, note_output.trim()));
+            }
+            i += 1;
+        }
+    }
+
+    fn render_node(
+        &self,
+        node: &Node,
+        output: &mut String,
+        footnotes: &mut FootnoteState,
+        toc: &mut Option<TocBuilder>,
+    ) {
         match &node.block {
             Block::Heading { level, content } => {
-                self.render_heading(*level, content, output);
+                self.render_heading(*level, content, output, footnotes, toc);
             }
             Block::Paragraph { content } => {
-                self.render_paragraph(content, output);
+                self.render_paragraph(content, output, footnotes);
             }
-            Block::CodeBlock {
-                language: _,
-                content,
-            } => {
-                self.render_code_block(content, output);
+            Block::CodeBlock { language, content } => {
+                self.render_code_block(language.as_deref(), content, output);
             }
             Block::Quote { content } => {
-                self.render_quote(content, output);
+                self.render_quote(content, output, footnotes, toc);
             }
-            Block::List { kind: _, items: _ } => {
-                // Skip lists for now as per the plan
+            Block::List { kind, items } => {
+                self.render_list(kind, items, output, footnotes, toc);
             }
             Block::Table {
-                header: _,
-                rows: _,
-                alignment: _,
+                header,
+                rows,
+                alignment,
             } => {
-                // Skip tables for now as per the plan
+                self.render_table(header, rows, alignment, output, footnotes);
             }
             Block::ThematicBreak => {
                 output.push_str("---\n\n");
             }
+            Block::FootnoteDefinition { .. } => {
+                // Collected up-front by `render` and rendered together in
+                // the trailing Notes section instead of inline here.
+            }
         }
     }
 
-    fn render_heading(&self, level: HeadingLevel, content: &Text, output: &mut String) {
-        let content_str = self.render_text(content);
+    fn render_heading(
+        &self,
+        level: HeadingLevel,
+        content: &Text,
+        output: &mut String,
+        footnotes: &mut FootnoteState,
+        toc: &mut Option<TocBuilder>,
+    ) {
+        let content_str = self.render_text(content, footnotes);
+
+        if let Some(builder) = toc {
+            builder.push_heading(level, &content.to_plain_string(), output.len());
+        }
 
         // Add markdown hash prefixes based on heading level
         let hashes = match level {
@@ -118,29 +524,178 @@ impl MarkdownRenderer {
         output.push_str("\n\n");
     }
 
-    fn render_paragraph(&self, content: &Text, output: &mut String) {
-        let content_str = self.render_text(content);
+    fn render_paragraph(&self, content: &Text, output: &mut String, footnotes: &mut FootnoteState) {
+        let content_str = self.render_text(content, footnotes);
         if !content_str.trim().is_empty() {
             output.push_str(&content_str);
             output.push_str("\n\n");
         }
     }
 
-    fn render_code_block(&self, content: &str, output: &mut String) {
-        output.push_str("```\n");
-        output.push_str(content);
+    /// Emits a fenced code block, keeping the language info string on the
+    /// opening fence. When `language` is recognized by this renderer's
+    /// highlighter, the content is tokenized and wrapped in ANSI styling;
+    /// otherwise (or with no language at all) it's rendered as plain text.
+    fn render_code_block(&self, language: Option<&str>, content: &str, output: &mut String) {
+        output.push_str("```");
+        if let Some(lang) = language {
+            output.push_str(lang);
+        }
+        output.push('\n');
+
+        match language.and_then(|lang| self.highlighter.highlight(content, lang)) {
+            Some(tokens) => {
+                for (kind, text) in tokens {
+                    output.push_str(&ansi_wrap(kind, &text));
+                }
+            }
+            None => {
+                output.push_str(content);
+            }
+        }
+
         output.push_str("\n```\n\n");
     }
 
-    fn render_quote(&self, content: &[Node], output: &mut String) {
+    fn render_quote(
+        &self,
+        content: &[Node],
+        output: &mut String,
+        footnotes: &mut FootnoteState,
+        toc: &mut Option<TocBuilder>,
+    ) {
         for node in content {
             output.push_str("> ");
-            self.render_node(node, output);
+            self.render_node(node, output, footnotes, toc);
         }
         output.push('\n');
     }
 
-    fn render_text(&self, text: &Text) -> String {
+    /// Renders ordered items as `1.`/`2.`… and unordered as `- `, indenting
+    /// each item's continuation lines (including nested lists) by two
+    /// spaces so nesting stays readable.
+    fn render_list(
+        &self,
+        kind: &ListKind,
+        items: &[ListItem],
+        output: &mut String,
+        footnotes: &mut FootnoteState,
+        toc: &mut Option<TocBuilder>,
+    ) {
+        for (i, item) in items.iter().enumerate() {
+            let marker = match kind {
+                ListKind::Ordered { start } => format!("{}.", start + i as u32),
+                ListKind::Unordered => "-".to_string(),
+            };
+
+            let mut item_output = String::new();
+            for node in &item.content {
+                self.render_node(node, &mut item_output, footnotes, toc);
+            }
+
+            let mut lines = item_output.trim_end().lines();
+            output.push_str(&marker);
+            output.push(' ');
+            if let Some(status) = &item.task_status {
+                output.push_str(match status {
+                    crate::markdown::TaskStatus::Checked => "[x] ",
+                    crate::markdown::TaskStatus::Unchecked => "[ ] ",
+                });
+            }
+            if let Some(first_line) = lines.next() {
+                output.push_str(first_line);
+            }
+            output.push('\n');
+            for line in lines {
+                output.push_str("  ");
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+        output.push('\n');
+    }
+
+    /// Renders a GitHub-flavored pipe table: a header row, a delimiter row
+    /// derived from `alignment`, then the data rows, with every column
+    /// padded to its widest cell so the table stays aligned as plain text.
+    fn render_table(
+        &self,
+        header: &Option<TableRow>,
+        rows: &[TableRow],
+        alignment: &[TableAlignment],
+        output: &mut String,
+        footnotes: &mut FootnoteState,
+    ) {
+        let rendered_header: Vec<String> = header
+            .as_ref()
+            .map(|row| {
+                row.cells
+                    .iter()
+                    .map(|c| self.render_text(&c.content, footnotes))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let rendered_rows: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                row.cells
+                    .iter()
+                    .map(|c| self.render_text(&c.content, footnotes))
+                    .collect()
+            })
+            .collect();
+
+        let column_count = rendered_header
+            .len()
+            .max(rendered_rows.iter().map(|r| r.len()).max().unwrap_or(0));
+        if column_count == 0 {
+            return;
+        }
+
+        // GFM pads every delimiter cell to at least 3 dashes.
+        let mut widths = vec![3usize; column_count];
+        for cells in std::iter::once(&rendered_header).chain(rendered_rows.iter()) {
+            for (i, cell) in cells.iter().enumerate() {
+                widths[i] = widths[i].max(cell.chars().count());
+            }
+        }
+
+        let render_row = |cells: &[String], widths: &[usize], output: &mut String| {
+            output.push('|');
+            for (i, width) in widths.iter().enumerate() {
+                let cell = cells.get(i).map(|s| s.as_str()).unwrap_or("");
+                output.push(' ');
+                output.push_str(cell);
+                output.push_str(&" ".repeat(width - cell.chars().count()));
+                output.push_str(" |");
+            }
+            output.push('\n');
+        };
+
+        render_row(&rendered_header, &widths, output);
+
+        output.push('|');
+        for (i, width) in widths.iter().enumerate() {
+            let dashes = "-".repeat(*width);
+            let delimiter = match alignment.get(i).copied().unwrap_or(TableAlignment::None) {
+                TableAlignment::Left => format!(":{}", &dashes[1..]),
+                TableAlignment::Center => format!(":{}:", &dashes[2..]),
+                TableAlignment::Right => format!("{}:", &dashes[..dashes.len() - 1]),
+                TableAlignment::None => dashes,
+            };
+            output.push(' ');
+            output.push_str(&delimiter);
+            output.push_str(" |");
+        }
+        output.push('\n');
+
+        for row in &rendered_rows {
+            render_row(row, &widths, output);
+        }
+        output.push('\n');
+    }
+
+    fn render_text(&self, text: &Text, footnotes: &mut FootnoteState) -> String {
         let mut output = String::new();
 
         for item in text.clone().into_iter() {
@@ -149,7 +704,7 @@ impl MarkdownRenderer {
                     self.render_text_node(&text_node, &mut output);
                 }
                 TextOrInline::Inline(inline) => {
-                    self.render_inline(&inline, &mut output);
+                    self.render_inline(&inline, &mut output, footnotes);
                 }
             }
         }
@@ -191,6 +746,7 @@ impl MarkdownRenderer {
         &self,
         inline: &Inline,
         output: &mut String,
+        footnotes: &mut FootnoteState,
         _prev_item: Option<&TextOrInline>,
         _next_item: Option<&TextOrInline>,
         _is_first: bool,
@@ -215,7 +771,7 @@ impl MarkdownRenderer {
                 title: _,
             } => {
                 output.push('[');
-                output.push_str(&self.render_text(text));
+                output.push_str(&self.render_text(text, footnotes));
                 output.push_str("](");
                 output.push_str(url);
                 output.push(')');
@@ -226,12 +782,18 @@ impl MarkdownRenderer {
             Inline::SoftBreak => {
                 output.push('\n');
             }
+            Inline::FootnoteReference { tag } => {
+                if footnotes.seen.insert(tag.clone()) {
+                    footnotes.order.push(tag.clone());
+                }
+                output.push_str(&format!("[^{}]", tag));
+            }
         }
     }
 
-    fn render_inline(&self, inline: &Inline, output: &mut String) {
+    fn render_inline(&self, inline: &Inline, output: &mut String, footnotes: &mut FootnoteState) {
         // Use the spacing version with default parameters for backward compatibility
-        self.render_inline_with_spacing(inline, output, None, None, false, false);
+        self.render_inline_with_spacing(inline, output, footnotes, None, None, false, false);
     }
 }
 
@@ -30,7 +30,9 @@ pub use parsing::toc::{TocEntry, TocTarget};
 pub use request::{
     PageSelectionBounds, RenderParams, RenderRequest, RenderResponse, RequestId, WorkerFault,
 };
-pub use selection::{ExtractionRequest, SelectionPoint, SelectionRect, TextSelection};
+pub use selection::{
+    ExtractionRequest, SelectionPoint, SelectionRect, TextSelection, extract_text_for_rects,
+};
 pub use service::{DocumentInfo, RenderService};
 pub use state::{Command, Effect, RenderState};
 pub use types::{
@@ -47,5 +49,9 @@ pub const DEFAULT_CACHE_SIZE: usize = 30;
 /// Default prefetch radius (pages before/after current)
 pub const DEFAULT_PREFETCH_RADIUS: usize = 10;
 
-/// Maximum width/height for Kitty protocol images
+/// Maximum width/height, in pixels, for a rasterized page image. Named for
+/// the Kitty protocol (the limit this value was chosen for), but applied in
+/// `worker::RasterSpec::compute` regardless of the active graphics protocol,
+/// since Sixel and the other `ConvertedImage::Generic` backends also benefit
+/// from capping how large a single frame gets encoded.
 pub const KITTY_MAX_DIMENSION: f32 = 10_000.0;
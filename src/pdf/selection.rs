@@ -1,5 +1,7 @@
 //! Text selection state for PDF pages
 
+use super::types::LineBounds;
+
 /// A point in the selection with both terminal and PDF coordinates
 #[derive(Clone, Copy, Debug, Default)]
 pub struct SelectionPoint {
@@ -98,3 +100,155 @@ pub struct ExtractionRequest {
     /// Bounds for each page in the selection
     pub bounds: Vec<super::request::PageSelectionBounds>,
 }
+
+/// Horizontal gap (in the same scaled page-coordinate units as
+/// `CharInfo::x`) above which consecutive glyphs on a line are treated as
+/// separate words rather than kerning within one run.
+const SPACE_GAP_THRESHOLD: f32 = 2.0;
+
+/// Extracts the text under a selection from a page's already-rendered line
+/// bounds: keeps glyphs whose line overlaps a rect's y-range and whose x
+/// position falls within that rect's x-range, groups them back into lines
+/// (sorted top-to-bottom) with each line's glyphs sorted left-to-right, and
+/// joins intra-line runs with a space when the horizontal gap between them
+/// exceeds [`SPACE_GAP_THRESHOLD`]. Lines are joined with newlines.
+///
+/// `rects` may contain more than one rectangle (e.g. a selection spanning a
+/// column break); a glyph is kept if it falls inside any of them. Page
+/// rotation doesn't need separate handling here: `line_bounds` is produced
+/// by the renderer in already-rotated page space, same as the rects.
+pub fn extract_text_for_rects(line_bounds: &[LineBounds], rects: &[SelectionRect]) -> String {
+    let mut lines: Vec<(f32, String)> = Vec::new();
+
+    for line in line_bounds {
+        let overlapping_rects: Vec<&SelectionRect> = rects
+            .iter()
+            .filter(|r| ranges_overlap(line.y0, line.y1, r.topleft_y as f32, r.bottomright_y as f32))
+            .collect();
+        if overlapping_rects.is_empty() {
+            continue;
+        }
+
+        let mut chars: Vec<&super::types::CharInfo> = line
+            .chars
+            .iter()
+            .filter(|c| {
+                overlapping_rects
+                    .iter()
+                    .any(|r| c.x >= r.topleft_x as f32 && c.x <= r.bottomright_x as f32)
+            })
+            .collect();
+        if chars.is_empty() {
+            continue;
+        }
+        chars.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+        let mut text = String::new();
+        let mut prev_x = None;
+        for c in chars {
+            if let Some(prev_x) = prev_x {
+                if c.x - prev_x > SPACE_GAP_THRESHOLD {
+                    text.push(' ');
+                }
+            }
+            text.push(c.c);
+            prev_x = Some(c.x);
+        }
+
+        lines.push((line.y0, text));
+    }
+
+    lines.sort_by(|a, b| a.0.total_cmp(&b.0));
+    lines
+        .into_iter()
+        .map(|(_, text)| text)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn ranges_overlap(a0: f32, a1: f32, b0: f32, b1: f32) -> bool {
+    a0 <= b1 && b0 <= a1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::types::CharInfo;
+
+    fn line(y0: f32, y1: f32, text: &str, block_id: usize) -> LineBounds {
+        let mut x = 0.0;
+        let chars = text
+            .chars()
+            .map(|c| {
+                let info = CharInfo { x, c };
+                x += 1.0;
+                info
+            })
+            .collect();
+        LineBounds {
+            x0: 0.0,
+            y0,
+            x1: x,
+            y1,
+            chars,
+            block_id,
+        }
+    }
+
+    fn rect(page: usize, x0: u32, y0: u32, x1: u32, y1: u32) -> SelectionRect {
+        SelectionRect {
+            page,
+            topleft_x: x0,
+            topleft_y: y0,
+            bottomright_x: x1,
+            bottomright_y: y1,
+        }
+    }
+
+    #[test]
+    fn test_extracts_single_line_within_rect() {
+        let lines = vec![line(10.0, 20.0, "hello", 0)];
+        let rects = vec![rect(0, 0, 0, 100, 100)];
+        assert_eq!(extract_text_for_rects(&lines, &rects), "hello");
+    }
+
+    #[test]
+    fn test_ignores_lines_outside_rect_y_range() {
+        let lines = vec![line(10.0, 20.0, "inside", 0), line(200.0, 210.0, "outside", 0)];
+        let rects = vec![rect(0, 0, 0, 100, 100)];
+        assert_eq!(extract_text_for_rects(&lines, &rects), "inside");
+    }
+
+    #[test]
+    fn test_joins_lines_in_top_to_bottom_order() {
+        // Pushed out of order; extraction should still read top-to-bottom.
+        let lines = vec![line(50.0, 60.0, "second", 0), line(0.0, 10.0, "first", 0)];
+        let rects = vec![rect(0, 0, 0, 100, 100)];
+        assert_eq!(extract_text_for_rects(&lines, &rects), "first\nsecond");
+    }
+
+    #[test]
+    fn test_inserts_space_for_large_horizontal_gaps() {
+        let mut gapped = line(0.0, 10.0, "ab", 0);
+        // Push the second char far enough away to look like a separate word.
+        gapped.chars[1].x = 10.0;
+        let rects = vec![rect(0, 0, 0, 100, 100)];
+        assert_eq!(extract_text_for_rects(&[gapped], &rects), "a b");
+    }
+
+    #[test]
+    fn test_multiple_rects_on_same_line_span_columns() {
+        let text_line = line(0.0, 10.0, "leftright", 0);
+        // Two rects covering disjoint x ranges on the same line (e.g. a
+        // selection spanning a column break).
+        let rects = vec![rect(0, 0, 0, 4, 10), rect(0, 5, 0, 9, 10)];
+        assert_eq!(extract_text_for_rects(&[text_line], &rects), "leftright");
+    }
+
+    #[test]
+    fn test_no_matching_rects_yields_empty_string() {
+        let lines = vec![line(10.0, 20.0, "hello", 0)];
+        let rects = vec![rect(0, 0, 0, 5, 5)];
+        assert_eq!(extract_text_for_rects(&lines, &rects), "");
+    }
+}
@@ -1,7 +1,9 @@
 use crate::bookmark::Bookmarks;
+use crate::inputs::KeySeq;
 use crate::main_app::VimNavMotions;
 use crate::theme::OCEANIC_NEXT;
-use chrono::{DateTime, Local, TimeZone};
+use chrono::{DateTime, Datelike, Duration, Local, TimeZone};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use log::debug;
 use ratatui::{
     Frame,
@@ -10,12 +12,157 @@ use ratatui::{
     text::{Line, Span},
     widgets::{Block, Borders, Clear, List, ListItem, ListState},
 };
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Outcome of a key routed to the reading-history popup via `handle_key`:
+/// either the popup consumed it itself (`None`), or it resolves to
+/// something the caller must carry out against the rest of the app.
+pub enum ReadingHistoryAction {
+    Close,
+    OpenBook { path: String },
+    SortChanged {
+        sort_by: ReadingHistorySortBy,
+        direction: ReadingHistorySortDirection,
+    },
+}
+
+/// Sort key for the reading-history popup's item list, cycled with `s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadingHistorySortBy {
+    LastRead,
+    Progress,
+    Title,
+    TotalChapters,
+}
+
+impl ReadingHistorySortBy {
+    fn next(self) -> Self {
+        match self {
+            ReadingHistorySortBy::LastRead => ReadingHistorySortBy::Progress,
+            ReadingHistorySortBy::Progress => ReadingHistorySortBy::Title,
+            ReadingHistorySortBy::Title => ReadingHistorySortBy::TotalChapters,
+            ReadingHistorySortBy::TotalChapters => ReadingHistorySortBy::LastRead,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ReadingHistorySortBy::LastRead => "Last Read",
+            ReadingHistorySortBy::Progress => "Progress",
+            ReadingHistorySortBy::Title => "Title",
+            ReadingHistorySortBy::TotalChapters => "Total Chapters",
+        }
+    }
+}
+
+/// Direction for the active `ReadingHistorySortBy`, reversed with `S`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadingHistorySortDirection {
+    Ascending,
+    Descending,
+}
+
+impl ReadingHistorySortDirection {
+    fn reversed(self) -> Self {
+        match self {
+            ReadingHistorySortDirection::Ascending => ReadingHistorySortDirection::Descending,
+            ReadingHistorySortDirection::Descending => ReadingHistorySortDirection::Ascending,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ReadingHistorySortDirection::Ascending => "asc",
+            ReadingHistorySortDirection::Descending => "desc",
+        }
+    }
+
+    fn apply(self, ord: Ordering) -> Ordering {
+        match self {
+            ReadingHistorySortDirection::Ascending => ord,
+            ReadingHistorySortDirection::Descending => ord.reverse(),
+        }
+    }
+}
+
+/// Time-based section a history entry's `last_read` falls into, relative
+/// to some `now`. Kept in display order so the popup can iterate them
+/// top to bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeBucket {
+    Today,
+    Yesterday,
+    ThisWeek,
+    ThisMonth,
+    ThisYear,
+    Older,
+}
+
+impl TimeBucket {
+    const ORDER: [TimeBucket; 6] = [
+        TimeBucket::Today,
+        TimeBucket::Yesterday,
+        TimeBucket::ThisWeek,
+        TimeBucket::ThisMonth,
+        TimeBucket::ThisYear,
+        TimeBucket::Older,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            TimeBucket::Today => "Today",
+            TimeBucket::Yesterday => "Yesterday",
+            TimeBucket::ThisWeek => "This Week",
+            TimeBucket::ThisMonth => "This Month",
+            TimeBucket::ThisYear => "This Year",
+            TimeBucket::Older => "Older",
+        }
+    }
+}
+
+/// Buckets `date` relative to `now`. Pulled out as its own function (taking
+/// `now` as a parameter rather than reading the clock itself) so the
+/// boundaries are pinned to a single, fixed place and snapshot tests can
+/// supply a deterministic `now`.
+fn bucket_for(date: DateTime<Local>, now: DateTime<Local>) -> TimeBucket {
+    let today = now.date_naive();
+    let d = date.date_naive();
+
+    if d == today {
+        TimeBucket::Today
+    } else if d == today - Duration::days(1) {
+        TimeBucket::Yesterday
+    } else if today - d <= Duration::days(7) {
+        TimeBucket::ThisWeek
+    } else if d.year() == today.year() && d.month() == today.month() {
+        TimeBucket::ThisMonth
+    } else if d.year() == today.year() {
+        TimeBucket::ThisYear
+    } else {
+        TimeBucket::Older
+    }
+}
+
+/// One rendered row of the (possibly folded) grouped list: either a
+/// bucket's header or one of its items (by index into `ReadingHistory::items`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    Header(TimeBucket, usize),
+    Item(usize),
+}
 
 pub struct ReadingHistory {
     items: Vec<HistoryItem>,
     state: ListState,
     last_popup_area: Option<Rect>,
+    sort_by: ReadingHistorySortBy,
+    sort_direction: ReadingHistorySortDirection,
+    /// Buckets currently collapsed to just their header, toggled with `z`.
+    folded_buckets: HashSet<TimeBucket>,
+    /// Fixed at construction time so bucket assignments don't shift while
+    /// the popup is open.
+    now: DateTime<Local>,
 }
 
 #[derive(Clone)]
@@ -28,7 +175,11 @@ struct HistoryItem {
 }
 
 impl ReadingHistory {
-    pub fn new(bookmarks: &Bookmarks) -> Self {
+    pub fn new(
+        bookmarks: &Bookmarks,
+        sort_by: ReadingHistorySortBy,
+        sort_direction: ReadingHistorySortDirection,
+    ) -> Self {
         // Extract unique books with their most recent access time
         let mut latest_access: HashMap<String, (DateTime<Local>, String, usize, usize)> =
             HashMap::new();
@@ -72,8 +223,7 @@ impl ReadingHistory {
             )
             .collect();
 
-        // Sort by date descending (most recent first)
-        items.sort_by(|a, b| b.date.cmp(&a.date));
+        items.sort_by(|a, b| Self::compare_items(a, b, sort_by, sort_direction));
 
         let mut state = ListState::default();
         if !items.is_empty() {
@@ -84,6 +234,196 @@ impl ReadingHistory {
             items,
             state,
             last_popup_area: None,
+            sort_by,
+            sort_direction,
+            folded_buckets: HashSet::new(),
+            now: Local::now(),
+        }
+    }
+
+    /// Builds the rows the popup actually displays: a `Header` for each
+    /// non-empty bucket (in `TimeBucket::ORDER`), followed by an `Item` row
+    /// per book in that bucket — unless the bucket is folded, in which case
+    /// only its `Header` is emitted.
+    fn visible_rows(&self) -> Vec<Row> {
+        let mut rows = Vec::new();
+        for bucket in TimeBucket::ORDER {
+            let indices: Vec<usize> = self
+                .items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| bucket_for(item.date, self.now) == bucket)
+                .map(|(i, _)| i)
+                .collect();
+
+            if indices.is_empty() {
+                continue;
+            }
+
+            rows.push(Row::Header(bucket, indices.len()));
+            if !self.folded_buckets.contains(&bucket) {
+                rows.extend(indices.into_iter().map(Row::Item));
+            }
+        }
+        rows
+    }
+
+    /// Toggles the fold state of the bucket the current selection belongs
+    /// to, whether the selection sits on a `Header` or an `Item` row.
+    fn toggle_fold(&mut self) {
+        let rows = self.visible_rows();
+        let Some(selected) = self.state.selected() else {
+            return;
+        };
+        let Some(row) = rows.get(selected) else {
+            return;
+        };
+        let bucket = match *row {
+            Row::Header(bucket, _) => bucket,
+            Row::Item(index) => match self.items.get(index) {
+                Some(item) => bucket_for(item.date, self.now),
+                None => return,
+            },
+        };
+
+        if !self.folded_buckets.insert(bucket) {
+            self.folded_buckets.remove(&bucket);
+        }
+
+        // Folding can shrink the row list out from under the current
+        // selection; keep it on the bucket's header in that case.
+        let new_rows = self.visible_rows();
+        if self.state.selected().map(|i| i >= new_rows.len()).unwrap_or(false) {
+            if let Some(header_index) = new_rows
+                .iter()
+                .position(|r| matches!(r, Row::Header(b, _) if *b == bucket))
+            {
+                self.state.select(Some(header_index));
+            }
+        }
+    }
+
+    /// Re-sorts `items` in place for the current `sort_by`/`sort_direction`,
+    /// keeping the selected path (rather than index) under the cursor.
+    fn resort(&mut self) {
+        let selected_path = self.selected_path().map(str::to_string);
+        self.items
+            .sort_by(|a, b| Self::compare_items(a, b, self.sort_by, self.sort_direction));
+        if let Some(path) = selected_path {
+            if let Some(new_index) = self
+                .visible_rows()
+                .iter()
+                .position(|row| matches!(row, Row::Item(i) if self.items[*i].path == path))
+            {
+                self.state.select(Some(new_index));
+            }
+        }
+    }
+
+    /// `Progress` sorts by `chapter / total_chapters`; books with
+    /// `total_chapters == 0` have no meaningful progress, so they're kept
+    /// out of that comparison entirely and always sort after every book
+    /// that does, regardless of direction.
+    fn progress_sort_key(item: &HistoryItem) -> Option<f64> {
+        if item.total_chapters == 0 {
+            None
+        } else {
+            Some(item.chapter as f64 / item.total_chapters as f64)
+        }
+    }
+
+    fn compare_items(
+        a: &HistoryItem,
+        b: &HistoryItem,
+        sort_by: ReadingHistorySortBy,
+        direction: ReadingHistorySortDirection,
+    ) -> Ordering {
+        let primary = match sort_by {
+            ReadingHistorySortBy::LastRead => direction.apply(a.date.cmp(&b.date)),
+            ReadingHistorySortBy::Progress => match (
+                Self::progress_sort_key(a),
+                Self::progress_sort_key(b),
+            ) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(pa), Some(pb)) => {
+                    direction.apply(pa.partial_cmp(&pb).unwrap_or(Ordering::Equal))
+                }
+            },
+            ReadingHistorySortBy::Title => {
+                direction.apply(a.title.to_lowercase().cmp(&b.title.to_lowercase()))
+            }
+            ReadingHistorySortBy::TotalChapters => {
+                direction.apply(a.total_chapters.cmp(&b.total_chapters))
+            }
+        };
+        // Stable fallback for ties, independent of the active sort/direction.
+        primary.then_with(|| b.date.cmp(&a.date))
+    }
+
+    /// Routes a key press while the reading-history popup has focus. Vim
+    /// motions (`j`/`k`, `gg`/`G`, `Ctrl-d`/`Ctrl-u`) move the selection;
+    /// `s`/`S` cycle the sort key and reverse its direction; `Enter` opens
+    /// the selected book; `Esc` closes the popup.
+    pub fn handle_key(
+        &mut self,
+        key: KeyEvent,
+        key_sequence: &mut KeySeq,
+    ) -> Option<ReadingHistoryAction> {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.handle_j();
+                None
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.handle_k();
+                None
+            }
+            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.handle_ctrl_d();
+                None
+            }
+            KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.handle_ctrl_u();
+                None
+            }
+            KeyCode::Char('G') => {
+                self.handle_upper_g();
+                None
+            }
+            KeyCode::Char('g') => {
+                if key_sequence.handle_key('g') == "gg" {
+                    self.handle_gg();
+                    key_sequence.clear();
+                }
+                None
+            }
+            KeyCode::Char('z') => {
+                self.toggle_fold();
+                None
+            }
+            KeyCode::Char('s') => {
+                self.sort_by = self.sort_by.next();
+                self.resort();
+                Some(ReadingHistoryAction::SortChanged {
+                    sort_by: self.sort_by,
+                    direction: self.sort_direction,
+                })
+            }
+            KeyCode::Char('S') => {
+                self.sort_direction = self.sort_direction.reversed();
+                self.resort();
+                Some(ReadingHistoryAction::SortChanged {
+                    sort_by: self.sort_by,
+                    direction: self.sort_direction,
+                })
+            }
+            KeyCode::Enter => self.selected_path().map(|path| ReadingHistoryAction::OpenBook {
+                path: path.to_string(),
+            }),
+            KeyCode::Esc => Some(ReadingHistoryAction::Close),
+            _ => None,
         }
     }
 
@@ -95,31 +435,55 @@ impl ReadingHistory {
         // Clear the background for the popup area
         f.render_widget(Clear, popup_area);
 
-        // Create list items with formatted dates
-        let items: Vec<ListItem> = self
-            .items
+        // Create list items with formatted dates, grouped under their
+        // time-bucket header (folded buckets show only the header).
+        let rows = self.visible_rows();
+        let items: Vec<ListItem> = rows
             .iter()
-            .map(|item| {
-                let date_str = item.date.format("%Y-%m-%d").to_string();
-                let progress_str = if item.total_chapters > 0 {
-                    format!(" [ {} / {} ]", item.chapter + 1, item.total_chapters)
-                } else {
-                    String::new()
-                };
-
-                ListItem::new(Line::from(vec![
-                    Span::styled(date_str, Style::default().fg(OCEANIC_NEXT.base_03)),
-                    Span::raw(" : "),
-                    Span::styled(&item.title, Style::default().fg(OCEANIC_NEXT.base_05)),
-                    Span::styled(progress_str, Style::default().fg(OCEANIC_NEXT.base_03)),
-                ]))
+            .map(|row| match *row {
+                Row::Header(bucket, count) => {
+                    let fold_icon = if self.folded_buckets.contains(&bucket) {
+                        "\u{25b6}"
+                    } else {
+                        "\u{25bc}"
+                    };
+                    ListItem::new(Line::from(vec![Span::styled(
+                        format!("{fold_icon} {} ({count})", bucket.label()),
+                        Style::default()
+                            .fg(OCEANIC_NEXT.base_0c)
+                            .add_modifier(Modifier::BOLD),
+                    )]))
+                }
+                Row::Item(index) => {
+                    let item = &self.items[index];
+                    let date_str = item.date.format("%Y-%m-%d").to_string();
+                    let progress_str = if item.total_chapters > 0 {
+                        format!(" [ {} / {} ]", item.chapter + 1, item.total_chapters)
+                    } else {
+                        String::new()
+                    };
+
+                    ListItem::new(Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(date_str, Style::default().fg(OCEANIC_NEXT.base_03)),
+                        Span::raw(" : "),
+                        Span::styled(&item.title, Style::default().fg(OCEANIC_NEXT.base_05)),
+                        Span::styled(progress_str, Style::default().fg(OCEANIC_NEXT.base_03)),
+                    ]))
+                }
             })
             .collect();
 
+        let title = format!(
+            " Reading History \u{2014} Sort: {} ({}) ",
+            self.sort_by.label(),
+            self.sort_direction.label()
+        );
+
         let list = List::new(items)
             .block(
                 Block::default()
-                    .title(" Reading History ")
+                    .title(title)
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(OCEANIC_NEXT.base_0c))
                     .style(Style::default().bg(OCEANIC_NEXT.base_00)), // Use theme background
@@ -135,9 +499,13 @@ impl ReadingHistory {
     }
 
     pub fn next(&mut self) {
+        let row_count = self.visible_rows().len();
+        if row_count == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.items.len() - 1 {
+                if i >= row_count - 1 {
                     0
                 } else {
                     i + 1
@@ -149,10 +517,14 @@ impl ReadingHistory {
     }
 
     pub fn previous(&mut self) {
+        let row_count = self.visible_rows().len();
+        if row_count == 0 {
+            return;
+        }
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.items.len().saturating_sub(1)
+                    row_count - 1
                 } else {
                     i - 1
                 }
@@ -162,11 +534,14 @@ impl ReadingHistory {
         self.state.select(Some(i));
     }
 
+    /// The currently selected book's path, or `None` if the selection is on
+    /// a bucket header (headers aren't "opened").
     pub fn selected_path(&self) -> Option<&str> {
-        self.state
-            .selected()
-            .and_then(|i| self.items.get(i))
-            .map(|item| item.path.as_str())
+        let rows = self.visible_rows();
+        match self.state.selected().and_then(|i| rows.get(i)) {
+            Some(Row::Item(index)) => self.items.get(*index).map(|item| item.path.as_str()),
+            _ => None,
+        }
     }
 
     /// Handle mouse click at the given position
@@ -197,16 +572,16 @@ impl ReadingHistory {
                 let new_index = offset + relative_y as usize;
 
                 debug!(
-                    "ReadingHistory: relative_y={}, offset={}, new_index={}, items_len={}",
+                    "ReadingHistory: relative_y={}, offset={}, new_index={}, row_count={}",
                     relative_y,
                     offset,
                     new_index,
-                    self.items.len()
+                    self.visible_rows().len()
                 );
 
-                if new_index < self.items.len() {
+                if new_index < self.visible_rows().len() {
                     self.state.select(Some(new_index));
-                    debug!("ReadingHistory: Selected item at index {}", new_index);
+                    debug!("ReadingHistory: Selected row at index {}", new_index);
                     return true;
                 }
             } else {
@@ -263,9 +638,10 @@ impl VimNavMotions for ReadingHistory {
     fn handle_ctrl_d(&mut self) {
         // Page down - move selection down by half page
         let page_size = 10; // Approximate half-page
+        let row_count = self.visible_rows().len();
         for _ in 0..page_size {
             let current = self.state.selected().unwrap_or(0);
-            if current < self.items.len() - 1 {
+            if row_count > 0 && current < row_count - 1 {
                 self.next();
             } else {
                 break;
@@ -287,17 +663,17 @@ impl VimNavMotions for ReadingHistory {
     }
 
     fn handle_gg(&mut self) {
-        // Go to top - select first item
-        if !self.items.is_empty() {
+        // Go to top - select first row
+        if !self.visible_rows().is_empty() {
             self.state.select(Some(0));
         }
     }
 
     fn handle_upper_g(&mut self) {
-        // Go to bottom - select last item
-        if !self.items.is_empty() {
-            let last_index = self.items.len() - 1;
-            self.state.select(Some(last_index));
+        // Go to bottom - select last row
+        let row_count = self.visible_rows().len();
+        if row_count > 0 {
+            self.state.select(Some(row_count - 1));
         }
     }
 }
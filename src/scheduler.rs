@@ -0,0 +1,153 @@
+use std::time::{Duration, Instant};
+
+/// Kinds of events the scheduler can fire, named for what drives them. New
+/// timed features (cursor blink, tooltip dismissal, deferred saves) add a
+/// variant here rather than inventing their own ad hoc timer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduledEventKind {
+    AutoScrollTick,
+}
+
+/// One timed event, as returned by [`Scheduler::poll`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduledEvent {
+    pub kind: ScheduledEventKind,
+    /// If set, the event reschedules itself for `repeat` after the poll that
+    /// fired it, rather than firing once and being dropped.
+    pub repeat: Option<Duration>,
+}
+
+struct Entry {
+    deadline: Instant,
+    event: ScheduledEvent,
+}
+
+/// A queue of `(deadline, ScheduledEvent)` entries, driven from the main
+/// loop alongside `EventSource::poll` rather than tied to redraw cadence.
+/// Frame-rate-independent timers (continuous auto-scroll today) schedule
+/// themselves here instead of piggybacking on whether a redraw happens to
+/// occur.
+#[derive(Default)]
+pub struct Scheduler {
+    entries: Vec<Entry>,
+}
+
+impl Scheduler {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Queues `event` to fire after `delay`. Does not deduplicate against
+    /// already-scheduled events of the same kind - callers that want at most
+    /// one in-flight instance should check `is_scheduled` first.
+    pub fn schedule(&mut self, delay: Duration, event: ScheduledEvent) {
+        self.entries.push(Entry {
+            deadline: Instant::now() + delay,
+            event,
+        });
+    }
+
+    /// Removes every pending entry of `kind`, repeating or not.
+    pub fn unschedule(&mut self, kind: ScheduledEventKind) {
+        self.entries.retain(|entry| entry.event.kind != kind);
+    }
+
+    /// Whether an entry of `kind` is currently pending.
+    pub fn is_scheduled(&self, kind: ScheduledEventKind) -> bool {
+        self.entries.iter().any(|entry| entry.event.kind == kind)
+    }
+
+    /// Returns every event whose deadline has passed, in no particular
+    /// order. One-shot events are dropped after firing; repeating events are
+    /// requeued for `repeat` from now.
+    pub fn poll(&mut self) -> Vec<ScheduledEvent> {
+        let now = Instant::now();
+        let mut due = Vec::new();
+        let mut still_pending = Vec::with_capacity(self.entries.len());
+
+        for entry in self.entries.drain(..) {
+            if entry.deadline <= now {
+                if let Some(repeat) = entry.event.repeat {
+                    still_pending.push(Entry {
+                        deadline: now + repeat,
+                        event: entry.event,
+                    });
+                }
+                due.push(entry.event);
+            } else {
+                still_pending.push(entry);
+            }
+        }
+
+        self.entries = still_pending;
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_returns_nothing_before_deadline() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(
+            Duration::from_secs(60),
+            ScheduledEvent {
+                kind: ScheduledEventKind::AutoScrollTick,
+                repeat: None,
+            },
+        );
+        assert!(scheduler.poll().is_empty());
+        assert!(scheduler.is_scheduled(ScheduledEventKind::AutoScrollTick));
+    }
+
+    #[test]
+    fn test_poll_fires_once_for_one_shot_event() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(
+            Duration::from_millis(0),
+            ScheduledEvent {
+                kind: ScheduledEventKind::AutoScrollTick,
+                repeat: None,
+            },
+        );
+        let due = scheduler.poll();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].kind, ScheduledEventKind::AutoScrollTick);
+        assert!(!scheduler.is_scheduled(ScheduledEventKind::AutoScrollTick));
+        assert!(scheduler.poll().is_empty());
+    }
+
+    #[test]
+    fn test_repeating_event_reschedules_itself() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(
+            Duration::from_millis(0),
+            ScheduledEvent {
+                kind: ScheduledEventKind::AutoScrollTick,
+                repeat: Some(Duration::from_secs(60)),
+            },
+        );
+        assert_eq!(scheduler.poll().len(), 1);
+        // Still pending (rescheduled far in the future), just not due yet.
+        assert!(scheduler.is_scheduled(ScheduledEventKind::AutoScrollTick));
+        assert!(scheduler.poll().is_empty());
+    }
+
+    #[test]
+    fn test_unschedule_removes_pending_entries() {
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(
+            Duration::from_secs(60),
+            ScheduledEvent {
+                kind: ScheduledEventKind::AutoScrollTick,
+                repeat: None,
+            },
+        );
+        scheduler.unschedule(ScheduledEventKind::AutoScrollTick);
+        assert!(!scheduler.is_scheduled(ScheduledEventKind::AutoScrollTick));
+    }
+}
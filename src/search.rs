@@ -1,5 +1,6 @@
 /// Search functionality for BookRat
 /// Provides vim-like search with "/" input and "n"/"N" navigation
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Default)]
 pub struct SearchState {
@@ -9,6 +10,21 @@ pub struct SearchState {
     pub matches: Vec<SearchMatch>,
     pub current_match_index: Option<usize>,
     pub original_position: usize, // Position to restore on cancel
+    pub fuzzy_mode: bool,         // When true, matches via find_fuzzy_matches_in_text
+    pub direction: Direction,     // Which way '/' or '?' was typed; governs what 'n'/'N' do
+    /// Set while a background scan (see `MarkdownTextReader::start_search_scan`)
+    /// is still streaming results in; `get_match_info` reflects this with a
+    /// "searching…" suffix instead of a final count.
+    pub searching: bool,
+}
+
+/// Which way an incremental search was initiated: `/` searches forward, `?`
+/// backward. `n` continues in this direction and `N` reverses it, vim-style.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Direction {
+    #[default]
+    Forward,
+    Backward,
 }
 
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -22,7 +38,7 @@ pub enum SearchMode {
 #[derive(Debug, Clone)]
 pub struct SearchMatch {
     pub index: usize, // Item index (book index, line number, etc.)
-    pub score: f32,   // Match relevance score (1.0 for now)
+    pub score: f32,   // Match relevance score; higher ranks better
     pub highlight_ranges: Vec<(usize, usize)>, // Character ranges to highlight in match
 }
 
@@ -32,12 +48,17 @@ impl SearchState {
     }
 
     pub fn start_search(&mut self, current_position: usize) {
+        self.start_search_direction(current_position, Direction::Forward);
+    }
+
+    pub fn start_search_direction(&mut self, current_position: usize, direction: Direction) {
         self.active = true;
         self.mode = SearchMode::InputMode;
         self.query.clear();
         self.matches.clear();
         self.current_match_index = None;
         self.original_position = current_position;
+        self.direction = direction;
     }
 
     pub fn cancel_search(&mut self) -> usize {
@@ -84,6 +105,66 @@ impl SearchState {
         }
     }
 
+    /// Marks a background search scan as started: clears any results from
+    /// the previous query and flags `searching` so `get_match_info` shows a
+    /// live status until `finish_scan` is called.
+    pub fn begin_scan(&mut self) {
+        self.searching = true;
+        self.matches.clear();
+        self.current_match_index = None;
+    }
+
+    /// Appends one chunk of a streaming background scan's results. Chunks
+    /// arrive in document order, so this just extends `matches` rather than
+    /// re-sorting; the first non-empty chunk also picks the initial current
+    /// match the same way `set_matches` does.
+    pub fn append_matches(&mut self, mut matches: Vec<SearchMatch>) {
+        self.matches.append(&mut matches);
+        if self.current_match_index.is_none() && !self.matches.is_empty() {
+            let start_index = self
+                .matches
+                .iter()
+                .position(|m| m.index >= self.original_position)
+                .unwrap_or(0);
+            self.current_match_index = Some(start_index);
+        }
+    }
+
+    /// Marks the current background scan as complete.
+    pub fn finish_scan(&mut self) {
+        self.searching = false;
+    }
+
+    /// Finds the match `n`/`N` should jump to, anchored to where the cursor
+    /// actually is (`current_position`) rather than stepping through
+    /// `matches` positionally from `current_match_index` - so navigation
+    /// stays correct even after the panel scrolled or jumped without going
+    /// through `next_match`/`previous_match` (mirroring Zed's terminal
+    /// search). Requires `matches` sorted ascending by `index`: `Forward`
+    /// binary-searches for the first match after `current_position`,
+    /// wrapping to the first match if there is none; `Backward` finds the
+    /// last match before it, wrapping to the last match.
+    pub fn match_index_for_direction(
+        &self,
+        current_position: usize,
+        direction: Direction,
+    ) -> Option<usize> {
+        if self.matches.is_empty() {
+            return None;
+        }
+
+        Some(match direction {
+            Direction::Forward => {
+                let idx = self.matches.partition_point(|m| m.index <= current_position);
+                if idx < self.matches.len() { idx } else { 0 }
+            }
+            Direction::Backward => {
+                let idx = self.matches.partition_point(|m| m.index < current_position);
+                if idx > 0 { idx - 1 } else { self.matches.len() - 1 }
+            }
+        })
+    }
+
     pub fn next_match(&mut self) -> Option<usize> {
         if self.matches.is_empty() {
             return None;
@@ -132,7 +213,19 @@ impl SearchState {
             .unwrap_or(false)
     }
 
+    pub fn toggle_fuzzy(&mut self) {
+        self.fuzzy_mode = !self.fuzzy_mode;
+    }
+
     pub fn get_match_info(&self) -> String {
+        if self.searching {
+            return if let Some(current) = self.current_match_index {
+                format!("[{}/? searching\u{2026}]", current + 1)
+            } else {
+                "[searching\u{2026}]".to_string()
+            };
+        }
+
         if self.matches.is_empty() {
             "No matches".to_string()
         } else if let Some(current) = self.current_match_index {
@@ -166,37 +259,577 @@ pub trait SearchablePanel {
     fn get_searchable_content(&self) -> Vec<String>; // Extract searchable text
 }
 
-/// Helper function to find matches in text (case-insensitive)
-pub fn find_matches_in_text(query: &str, items: &[String]) -> Vec<SearchMatch> {
+/// Upper bound, in lines, on how far a single regex search scans out from
+/// its origin in each direction - bounds the cost of a pathological pattern
+/// (or catastrophic backtracking) on a long chapter, mirroring the bounded
+/// scrollback window Alacritty's `RegexSearch` scans rather than searching
+/// an unbounded buffer in one shot.
+pub const MAX_SEARCH_LINES: usize = 100;
+
+/// Finds every match of `query` across `items`, compiled with the `regex`
+/// crate and scanned within `MAX_SEARCH_LINES` lines of `origin` in either
+/// direction. Case-insensitive unless `query` contains an uppercase letter
+/// (smart case, as in vim/ripgrep); `literal` escapes `query` first so
+/// regex metacharacters are matched verbatim rather than interpreted.
+pub fn find_regex_matches_in_text(
+    query: &str,
+    items: &[String],
+    origin: usize,
+    literal: bool,
+) -> Result<Vec<SearchMatch>, regex::Error> {
     if query.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pattern = if literal {
+        regex::escape(query)
+    } else {
+        query.to_string()
+    };
+    let case_insensitive = !query.chars().any(|c| c.is_uppercase());
+    let re = regex::RegexBuilder::new(&pattern)
+        .case_insensitive(case_insensitive)
+        .build()?;
+
+    let scan_start = origin.saturating_sub(MAX_SEARCH_LINES);
+    let scan_end = items.len().min(origin + MAX_SEARCH_LINES);
+    if scan_start > 0 || scan_end < items.len() {
+        log::warn!(
+            "Search scan bounded to lines {}..{} (document has {} lines) to limit cost on pathological patterns",
+            scan_start,
+            scan_end,
+            items.len()
+        );
+    }
+
+    let mut matches = Vec::new();
+    for (index, item) in items.iter().enumerate().take(scan_end).skip(scan_start) {
+        let highlight_ranges: Vec<(usize, usize)> =
+            re.find_iter(item).map(|m| (m.start(), m.end())).collect();
+
+        if !highlight_ranges.is_empty() {
+            matches.push(SearchMatch {
+                index,
+                score: 1.0,
+                highlight_ranges,
+            });
+        }
+    }
+
+    Ok(matches)
+}
+
+/// One parsed fzf-style query atom (see `parse_atoms`). `text` is already
+/// lowercased; `invert` means the atom is a must-not-match constraint.
+#[derive(Debug, Clone, PartialEq)]
+enum AtomKind {
+    /// Every character of `text` occurs in order, not necessarily adjacent.
+    Fuzzy,
+    /// Item must start with `text`.
+    Prefix,
+    /// Item must end with `text`.
+    Postfix,
+    /// `text` occurs verbatim, as a contiguous substring.
+    Exact,
+}
+
+#[derive(Debug, Clone)]
+struct Atom {
+    kind: AtomKind,
+    text: String,
+    invert: bool,
+}
+
+/// Splits a query on spaces into atoms, Helix/fzf-`fuzzy_match` style: a
+/// leading `!` inverts an atom into a must-not-match, `^` anchors it to the
+/// start of the item, a leading `'` requires an exact substring, and a
+/// trailing `$` anchors it to the end; anything left over is matched as a
+/// fuzzy subsequence.
+fn parse_atoms(query: &str) -> Vec<Atom> {
+    query
+        .split(' ')
+        .filter(|raw| !raw.is_empty())
+        .map(|raw| {
+            let mut text = raw;
+            let mut invert = false;
+            if let Some(rest) = text.strip_prefix('!') {
+                invert = true;
+                text = rest;
+            }
+            if let Some(rest) = text.strip_prefix('^') {
+                return Atom {
+                    kind: AtomKind::Prefix,
+                    text: rest.to_lowercase(),
+                    invert,
+                };
+            }
+            if let Some(rest) = text.strip_prefix('\'') {
+                return Atom {
+                    kind: AtomKind::Exact,
+                    text: rest.to_lowercase(),
+                    invert,
+                };
+            }
+            if text.len() > 1 {
+                if let Some(rest) = text.strip_suffix('$') {
+                    return Atom {
+                        kind: AtomKind::Postfix,
+                        text: rest.to_lowercase(),
+                        invert,
+                    };
+                }
+            }
+            Atom {
+                kind: AtomKind::Fuzzy,
+                text: text.to_lowercase(),
+                invert,
+            }
+        })
+        .collect()
+}
+
+/// Tries to match one non-inverse atom against an item (already lowercased,
+/// as both a `&str` and its `Vec<char>`). Returns the atom's score
+/// contribution and the character ranges it matched, or `None` if it
+/// doesn't match at all.
+fn match_atom(
+    atom: &Atom,
+    item_lower: &str,
+    item_chars: &[char],
+) -> Option<(f32, Vec<(usize, usize)>)> {
+    if atom.text.is_empty() {
+        return Some((0.0, Vec::new()));
+    }
+
+    match atom.kind {
+        AtomKind::Prefix => {
+            if item_lower.starts_with(&atom.text) {
+                let len = atom.text.chars().count();
+                Some((len as f32 * 2.0, vec![(0, len)]))
+            } else {
+                None
+            }
+        }
+        AtomKind::Postfix => {
+            if item_lower.ends_with(&atom.text) {
+                let len = atom.text.chars().count();
+                let start = item_chars.len() - len;
+                Some((len as f32 * 2.0, vec![(start, item_chars.len())]))
+            } else {
+                None
+            }
+        }
+        AtomKind::Exact => {
+            let needle: Vec<char> = atom.text.chars().collect();
+            let mut ranges = Vec::new();
+            let mut i = 0;
+            while i + needle.len() <= item_chars.len() {
+                if item_chars[i..i + needle.len()] == needle[..] {
+                    ranges.push((i, i + needle.len()));
+                    i += needle.len(); // non-overlapping, like find_matches_in_text before it
+                } else {
+                    i += 1;
+                }
+            }
+            if ranges.is_empty() {
+                None
+            } else {
+                Some((ranges.len() as f32 * needle.len() as f32 * 1.5, ranges))
+            }
+        }
+        AtomKind::Fuzzy => {
+            // Subsequence match: rewards consecutive matched characters and
+            // penalizes gaps, so e.g. "kill" ranks "To Kill a Mockingbird"
+            // above a scattered match, mirroring find_fuzzy_matches_in_text.
+            let needle: Vec<char> = atom.text.chars().collect();
+            let mut positions = Vec::new();
+            let mut search_from = 0;
+            for &qc in &needle {
+                let rel = item_chars[search_from..].iter().position(|&c| c == qc)?;
+                positions.push(search_from + rel);
+                search_from += rel + 1;
+            }
+
+            let mut score = 10.0_f32;
+            for pair in positions.windows(2) {
+                let gap = pair[1] - pair[0];
+                if gap == 1 {
+                    score += 3.0; // consecutive-character bonus
+                } else {
+                    score -= (gap as f32 - 1.0) * 0.5; // gap penalty
+                }
+            }
+            score -= positions[0] as f32 * 0.05; // earlier matches rank slightly higher
+
+            Some((score, positions.iter().map(|&p| (p, p + 1)).collect()))
+        }
+    }
+}
+
+/// Matches `query` against `items` using fzf-style atoms (see `parse_atoms`):
+/// an item matches only if every non-inverse atom matches and no inverse
+/// atom matches. `SearchMatch.score` is the sum of the matched atoms' scores
+/// and `highlight_ranges` carries their matched character positions; results
+/// are sorted best-score-first.
+pub fn find_matches_in_text(query: &str, items: &[String]) -> Vec<SearchMatch> {
+    if query.trim().is_empty() {
         return Vec::new();
     }
 
-    let query_lower = query.to_lowercase();
+    let atoms = parse_atoms(query);
     let mut matches = Vec::new();
 
-    for (index, item) in items.iter().enumerate() {
+    'items: for (index, item) in items.iter().enumerate() {
         let item_lower = item.to_lowercase();
+        let item_chars: Vec<char> = item_lower.chars().collect();
 
-        // Find all occurrences of the query in this item
+        let mut score = 0.0_f32;
         let mut highlight_ranges = Vec::new();
-        let mut search_start = 0;
 
-        while let Some(pos) = item_lower[search_start..].find(&query_lower) {
-            let actual_pos = search_start + pos;
-            highlight_ranges.push((actual_pos, actual_pos + query.len()));
-            search_start = actual_pos + 1; // Allow overlapping matches
+        for atom in &atoms {
+            match match_atom(atom, &item_lower, &item_chars) {
+                Some((atom_score, ranges)) => {
+                    if atom.invert {
+                        continue 'items;
+                    }
+                    score += atom_score;
+                    highlight_ranges.extend(ranges);
+                }
+                None if atom.invert => {}
+                None => continue 'items,
+            }
         }
 
-        if !highlight_ranges.is_empty() {
-            matches.push(SearchMatch {
-                index,
-                score: 1.0, // Perfect match for now
-                highlight_ranges,
-            });
+        highlight_ranges.sort_by_key(|r| r.0);
+        matches.push(SearchMatch {
+            index,
+            score,
+            highlight_ranges,
+        });
+    }
+
+    matches.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    matches
+}
+
+/// Fuzzy (subsequence) match: every char of `query`, in order, must occur
+/// somewhere in `item` (case-insensitive). Score rewards consecutive matched
+/// characters and penalizes gaps between them, so "bkrt" ranks "bookrat"
+/// above a scattered match, and results are returned best-score-first.
+pub fn find_fuzzy_matches_in_text(query: &str, items: &[String]) -> Vec<SearchMatch> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let mut matches = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        let item_chars: Vec<char> = item.to_lowercase().chars().collect();
+
+        let mut positions = Vec::new();
+        let mut search_from = 0;
+        let mut matched_all = true;
+        for &qc in &query_chars {
+            match item_chars[search_from..].iter().position(|&c| c == qc) {
+                Some(rel) => {
+                    positions.push(search_from + rel);
+                    search_from += rel + 1;
+                }
+                None => {
+                    matched_all = false;
+                    break;
+                }
+            }
+        }
+
+        if !matched_all || positions.is_empty() {
+            continue;
+        }
+
+        let mut score = 10.0_f32;
+        for pair in positions.windows(2) {
+            let gap = pair[1] - pair[0];
+            if gap == 1 {
+                score += 3.0; // consecutive-character bonus
+            } else {
+                score -= (gap as f32 - 1.0) * 0.5; // gap penalty
+            }
+        }
+        score -= positions[0] as f32 * 0.05; // earlier matches rank slightly higher
+
+        matches.push(SearchMatch {
+            index,
+            score,
+            highlight_ranges: positions.iter().map(|&p| (p, p + 1)).collect(),
+        });
+    }
+
+    matches.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    matches
+}
+
+/// A lowercased-once, trigram-indexed snapshot of a `SearchablePanel`'s
+/// content, the way MeiliSearch caches a cheap value before running the real
+/// matcher: `build` lowercases every item a single time and records which
+/// items contain each 3-character trigram, so `candidates` can intersect a
+/// query's trigram postings into a small candidate set instead of
+/// `find_matches_in_text` re-lowercasing and scanning every item on every
+/// keystroke.
+pub struct SearchIndex {
+    lowercased: Vec<String>,
+    trigrams: HashMap<String, HashSet<usize>>,
+}
+
+impl SearchIndex {
+    pub fn build(items: &[String]) -> Self {
+        let lowercased: Vec<String> = items.iter().map(|s| s.to_lowercase()).collect();
+        let mut trigrams: HashMap<String, HashSet<usize>> = HashMap::new();
+
+        for (index, item) in lowercased.iter().enumerate() {
+            let chars: Vec<char> = item.chars().collect();
+            for window in chars.windows(3) {
+                trigrams
+                    .entry(window.iter().collect())
+                    .or_default()
+                    .insert(index);
+            }
+        }
+
+        SearchIndex {
+            lowercased,
+            trigrams,
+        }
+    }
+
+    /// Candidate item indices that could plausibly match `query`: the
+    /// intersection of every one of its trigrams' postings. Falls back to
+    /// every item (`0..len`) for queries under 3 characters, since there's
+    /// no trigram to index on.
+    pub fn candidates(&self, query: &str) -> impl Iterator<Item = usize> {
+        let query_lower = query.to_lowercase();
+        let query_chars: Vec<char> = query_lower.chars().collect();
+
+        let indices: Vec<usize> = if query_chars.len() < 3 {
+            (0..self.lowercased.len()).collect()
+        } else {
+            let mut postings: Vec<&HashSet<usize>> = Vec::new();
+            let mut any_missing = false;
+            for window in query_chars.windows(3) {
+                let trigram: String = window.iter().collect();
+                match self.trigrams.get(&trigram) {
+                    Some(set) => postings.push(set),
+                    None => {
+                        any_missing = true;
+                        break;
+                    }
+                }
+            }
+
+            if any_missing {
+                Vec::new()
+            } else {
+                // Intersect smallest-first so the working set shrinks fast.
+                postings.sort_by_key(|s| s.len());
+                let mut iter = postings.into_iter();
+                match iter.next() {
+                    Some(first) => {
+                        let mut result = first.clone();
+                        for set in iter {
+                            result.retain(|i| set.contains(i));
+                        }
+                        let mut sorted: Vec<usize> = result.into_iter().collect();
+                        sorted.sort_unstable();
+                        sorted
+                    }
+                    None => Vec::new(),
+                }
+            }
+        };
+
+        indices.into_iter()
+    }
+}
+
+/// Like `find_matches_in_text`, but narrows the scan to `index`'s trigram
+/// candidates before running the exact/fuzzy atom matcher, instead of
+/// re-lowercasing and scanning every item. Falls back to a full
+/// `find_matches_in_text` scan if any required atom is too short to usefully
+/// narrow (under 3 characters) or the query is only inverse atoms.
+pub fn find_matches_in_text_indexed(
+    query: &str,
+    items: &[String],
+    index: &SearchIndex,
+) -> Vec<SearchMatch> {
+    if query.trim().is_empty() {
+        return Vec::new();
+    }
+
+    let atoms = parse_atoms(query);
+    let required_atoms: Vec<&Atom> = atoms.iter().filter(|a| !a.invert).collect();
+    let has_short_atom = required_atoms
+        .iter()
+        .any(|a| a.text.chars().count() < 3);
+    // The trigram index only finds `text` as a *contiguous* substring, but
+    // AtomKind::Fuzzy matches `text` as a non-contiguous subsequence (e.g.
+    // "adf" against "abc def"), so narrowing by trigrams would drop real
+    // matches a full scan finds. Only Exact/Prefix/Postfix atoms require a
+    // contiguous occurrence and are safe to use for narrowing.
+    let has_fuzzy_atom = required_atoms
+        .iter()
+        .any(|a| a.kind == AtomKind::Fuzzy);
+
+    if required_atoms.is_empty() || has_short_atom || has_fuzzy_atom {
+        return find_matches_in_text(query, items);
+    }
+
+    let mut candidate_indices: Option<HashSet<usize>> = None;
+    for atom in required_atoms {
+        let set: HashSet<usize> = index.candidates(&atom.text).collect();
+        candidate_indices = Some(match candidate_indices {
+            Some(existing) => existing.intersection(&set).copied().collect(),
+            None => set,
+        });
+    }
+
+    let mut candidate_indices: Vec<usize> = candidate_indices.unwrap_or_default().into_iter().collect();
+    candidate_indices.sort_unstable();
+
+    let scoped_items: Vec<String> = candidate_indices
+        .iter()
+        .filter_map(|&i| items.get(i).cloned())
+        .collect();
+
+    find_matches_in_text(query, &scoped_items)
+        .into_iter()
+        .map(|mut m| {
+            m.index = candidate_indices[m.index];
+            m
+        })
+        .collect()
+}
+
+/// Levenshtein distance between two strings, used to tolerate a single typo
+/// when matching a 4+ character query term against an item's words.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0usize; b.len() + 1];
+
+    for i in 1..=a.len() {
+        curr[0] = i;
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Splits `text` into `(char_start, char_end, lowercased_word)` triples, one
+/// per run of alphanumeric characters, so punctuation doesn't glue adjacent
+/// words together.
+fn words_with_positions(text: &str) -> Vec<(usize, usize, String)> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = Vec::new();
+    let mut start: Option<usize> = None;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(i);
+        } else if let Some(s) = start.take() {
+            words.push((s, i, chars[s..i].iter().collect::<String>().to_lowercase()));
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, chars.len(), chars[s..].iter().collect::<String>().to_lowercase()));
+    }
+
+    words
+}
+
+/// Multi-term, typo-tolerant ranking inspired by MeiliSearch's ranking-rule
+/// buckets: every whitespace-separated query term must match a word in the
+/// item, either exactly or (for terms of 4+ characters) within edit distance
+/// 1, to tolerate a single typo. Matches are ranked exact-all-terms first,
+/// then by the smallest word-index span covering every matched term
+/// (tighter proximity ranks higher), then by total typo count; the computed
+/// rank is stored in `SearchMatch.score` and every matched term's char range
+/// is recorded in `highlight_ranges`. Single-term queries stay on the
+/// existing `find_matches_in_text` fast path.
+pub fn find_multi_term_matches_in_text(query: &str, items: &[String]) -> Vec<SearchMatch> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+
+    if terms.len() <= 1 {
+        return find_matches_in_text(query, items);
+    }
+
+    let mut matches = Vec::new();
+
+    for (index, item) in items.iter().enumerate() {
+        let words = words_with_positions(item);
+
+        // For each term, the best (fewest-typo) word it matches: (word_index,
+        // char_start, char_end, typo_count).
+        let mut term_matches: Vec<(usize, usize, usize, usize)> = Vec::new();
+        let mut matched_all = true;
+
+        for term in &terms {
+            let mut best: Option<(usize, usize, usize, usize)> = None;
+
+            for (word_idx, (start, end, word)) in words.iter().enumerate() {
+                let typos = if word == term {
+                    0
+                } else if term.chars().count() >= 4 {
+                    match edit_distance(term, word) {
+                        d @ 0..=1 => d,
+                        _ => continue,
+                    }
+                } else {
+                    continue;
+                };
+
+                if best.is_none_or(|(_, _, _, best_typos)| typos < best_typos) {
+                    best = Some((word_idx, *start, *end, typos));
+                }
+            }
+
+            match best {
+                Some(b) => term_matches.push(b),
+                None => {
+                    matched_all = false;
+                    break;
+                }
+            }
+        }
+
+        if !matched_all {
+            continue;
         }
+
+        let word_indices = term_matches.iter().map(|(w, ..)| *w);
+        let span = word_indices.clone().max().unwrap_or(0) - word_indices.min().unwrap_or(0);
+        let total_typos: usize = term_matches.iter().map(|(_, _, _, t)| t).sum();
+
+        // Three-tier rank, encoded as one score: exact-all-terms beats any
+        // typo at all, then tighter proximity wins, then fewer typos.
+        let bucket = if total_typos == 0 { 2_000_000.0 } else { 1_000_000.0 };
+        let score = bucket - (span as f32) * 1000.0 - (total_typos as f32) * 10.0;
+
+        let mut highlight_ranges: Vec<(usize, usize)> =
+            term_matches.iter().map(|(_, s, e, _)| (*s, *e)).collect();
+        highlight_ranges.sort_by_key(|r| r.0);
+
+        matches.push(SearchMatch {
+            index,
+            score,
+            highlight_ranges,
+        });
     }
 
+    matches.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
     matches
 }
 
@@ -225,6 +858,157 @@ mod tests {
         assert_eq!(matches[0].index, 2);
     }
 
+    #[test]
+    fn test_find_matches_multi_atom_requires_all_atoms() {
+        let items = vec![
+            "The Great Gatsby".to_string(),
+            "To Kill a Mockingbird".to_string(),
+            "The Catcher in the Rye".to_string(),
+        ];
+
+        // Both atoms must match somewhere in the item.
+        let matches = find_matches_in_text("the rye", &items);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 2);
+    }
+
+    #[test]
+    fn test_find_matches_inverse_atom_excludes_items() {
+        let items = vec![
+            "The Great Gatsby".to_string(),
+            "The Catcher in the Rye".to_string(),
+        ];
+
+        let matches = find_matches_in_text("the !rye", &items);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 0);
+    }
+
+    #[test]
+    fn test_find_matches_prefix_and_postfix_atoms() {
+        let items = vec![
+            "The Great Gatsby".to_string(),
+            "To Kill a Mockingbird".to_string(),
+        ];
+
+        let matches = find_matches_in_text("^the", &items);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 0);
+
+        let matches = find_matches_in_text("bird$", &items);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 1);
+    }
+
+    #[test]
+    fn test_find_matches_sorted_best_score_first() {
+        let items = vec![
+            "b i r d".to_string(),       // scattered: large gaps between chars
+            "To Kill a Mockingbird".to_string(), // "bird" is contiguous
+        ];
+
+        let matches = find_matches_in_text("bird", &items);
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].index, 1); // contiguous match ranks first
+        assert_eq!(matches[1].index, 0);
+    }
+
+    #[test]
+    fn test_search_index_candidates_intersect_trigrams() {
+        let items = vec![
+            "The Great Gatsby".to_string(),
+            "To Kill a Mockingbird".to_string(),
+            "1984".to_string(),
+        ];
+        let index = SearchIndex::build(&items);
+
+        let candidates: Vec<usize> = index.candidates("kill").collect();
+        assert_eq!(candidates, vec![1]);
+
+        // No item contains this trigram sequence at all.
+        let candidates: Vec<usize> = index.candidates("xyz").collect();
+        assert!(candidates.is_empty());
+
+        // Below the 3-char floor: falls back to every item.
+        let candidates: Vec<usize> = index.candidates("ki").collect();
+        assert_eq!(candidates, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_find_matches_in_text_indexed_matches_full_scan() {
+        let items = vec![
+            "The Great Gatsby".to_string(),
+            "To Kill a Mockingbird".to_string(),
+            "The Catcher in the Rye".to_string(),
+        ];
+        let index = SearchIndex::build(&items);
+
+        let indexed = find_matches_in_text_indexed("kill", &items, &index);
+        let full_scan = find_matches_in_text("kill", &items);
+        assert_eq!(indexed.len(), full_scan.len());
+        assert_eq!(indexed[0].index, full_scan[0].index);
+        assert_eq!(indexed[0].index, 1);
+
+        // Short atoms fall back to a full scan rather than narrowing.
+        let indexed = find_matches_in_text_indexed("ki", &items, &index);
+        assert_eq!(indexed.len(), find_matches_in_text("ki", &items).len());
+
+        // Fuzzy atoms match as a non-contiguous subsequence, which the
+        // trigram index can't narrow on (it only indexes contiguous
+        // trigrams), so a fuzzy query also falls back to a full scan rather
+        // than silently dropping non-contiguous matches.
+        let items = vec!["abc def".to_string()];
+        let index = SearchIndex::build(&items);
+        let indexed = find_matches_in_text_indexed("adf", &items, &index);
+        let full_scan = find_matches_in_text("adf", &items);
+        assert_eq!(indexed.len(), full_scan.len());
+        assert_eq!(indexed.len(), 1);
+    }
+
+    #[test]
+    fn test_multi_term_matches_requires_every_term() {
+        let items = vec![
+            "The Great Gatsby".to_string(),
+            "To Kill a Mockingbird".to_string(),
+            "The Catcher in the Rye".to_string(),
+        ];
+
+        let matches = find_multi_term_matches_in_text("great gatsby", &items);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 0);
+
+        // "great" alone doesn't appear in the other titles.
+        let matches = find_multi_term_matches_in_text("great rye", &items);
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_multi_term_matches_tolerates_one_typo_on_long_terms() {
+        let items = vec!["The Great Gatsby".to_string()];
+
+        // "grat" is a 1-edit typo of "great" (missing the 'e').
+        let matches = find_multi_term_matches_in_text("grat gatsby", &items);
+        assert_eq!(matches.len(), 1);
+
+        // Short terms (<4 chars) don't get typo tolerance.
+        let matches = find_multi_term_matches_in_text("grt gatsby", &items);
+        assert!(matches.is_empty());
+    }
+
+    #[test]
+    fn test_multi_term_matches_rank_exact_above_typo_and_by_proximity() {
+        let items = vec![
+            "Great Expectations and the Gatsby Estate".to_string(), // wide span
+            "The Great Gatsby".to_string(),                         // tight, exact
+            "The Grat Gatsby Annotated Edition".to_string(),        // tight, 1 typo
+        ];
+
+        let matches = find_multi_term_matches_in_text("great gatsby", &items);
+        let indices: Vec<usize> = matches.iter().map(|m| m.index).collect();
+        // Exact + tight proximity ranks first, then exact + wide span, then typo.
+        assert_eq!(indices, vec![1, 0, 2]);
+    }
+
     #[test]
     fn test_search_state_navigation() {
         let mut state = SearchState::new();
@@ -260,4 +1044,114 @@ mod tests {
         // Previous should go back to index 4
         assert_eq!(state.previous_match(), Some(4));
     }
+
+    #[test]
+    fn test_background_scan_streams_partial_results() {
+        let mut state = SearchState::new();
+        state.start_search(0);
+        state.begin_scan();
+
+        assert_eq!(state.get_match_info(), "[searching\u{2026}]");
+
+        state.append_matches(vec![SearchMatch {
+            index: 0,
+            score: 1.0,
+            highlight_ranges: vec![(0, 3)],
+        }]);
+        assert_eq!(state.get_current_match(), Some(0));
+        assert_eq!(state.get_match_info(), "[1/? searching\u{2026}]");
+
+        state.append_matches(vec![SearchMatch {
+            index: 5,
+            score: 1.0,
+            highlight_ranges: vec![(0, 3)],
+        }]);
+        // A later chunk doesn't disturb the match already selected.
+        assert_eq!(state.get_current_match(), Some(0));
+        assert_eq!(state.matches.len(), 2);
+
+        state.finish_scan();
+        assert_eq!(state.get_match_info(), "[1/2]");
+    }
+
+    #[test]
+    fn test_match_index_for_direction_is_anchored_to_current_position() {
+        let mut state = SearchState::new();
+        state.set_matches(vec![
+            SearchMatch {
+                index: 10,
+                score: 1.0,
+                highlight_ranges: vec![],
+            },
+            SearchMatch {
+                index: 20,
+                score: 1.0,
+                highlight_ranges: vec![],
+            },
+            SearchMatch {
+                index: 30,
+                score: 1.0,
+                highlight_ranges: vec![],
+            },
+        ]);
+
+        // Cursor sitting between two matches: forward goes to the next one
+        // ahead, backward to the one behind - regardless of current_match_index.
+        assert_eq!(
+            state.match_index_for_direction(15, Direction::Forward),
+            Some(1) // index 20
+        );
+        assert_eq!(
+            state.match_index_for_direction(15, Direction::Backward),
+            Some(0) // index 10
+        );
+
+        // Past the last match, forward wraps to the first.
+        assert_eq!(
+            state.match_index_for_direction(30, Direction::Forward),
+            Some(0)
+        );
+        // Before the first match, backward wraps to the last.
+        assert_eq!(
+            state.match_index_for_direction(10, Direction::Backward),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_find_regex_matches_smart_case() {
+        let items = vec!["The Quick fox".to_string(), "the slow fox".to_string()];
+
+        // Lowercase query: case-insensitive, matches both lines.
+        let matches = find_regex_matches_in_text("fox", &items, 0, true).unwrap();
+        assert_eq!(matches.len(), 2);
+
+        // Query with an uppercase letter: case-sensitive, matches only line 0.
+        let matches = find_regex_matches_in_text("Quick", &items, 0, true).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 0);
+    }
+
+    #[test]
+    fn test_find_regex_matches_literal_vs_regex() {
+        let items = vec!["a.b".to_string(), "axb".to_string()];
+
+        // Literal mode: '.' only matches a literal dot.
+        let matches = find_regex_matches_in_text("a.b", &items, 0, true).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].index, 0);
+
+        // Regex mode: '.' matches any character.
+        let matches = find_regex_matches_in_text("a.b", &items, 0, false).unwrap();
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn test_find_regex_matches_bounded_by_origin() {
+        let items: Vec<String> = (0..300).map(|i| format!("line{i}")).collect();
+
+        // Searching from the start only reaches MAX_SEARCH_LINES lines out.
+        let matches = find_regex_matches_in_text("line", &items, 0, true).unwrap();
+        assert_eq!(matches.len(), MAX_SEARCH_LINES);
+    }
 }
@@ -1,5 +1,9 @@
 use std::env;
+use std::io::{self, Read, Write};
+use std::os::unix::io::AsRawFd;
+use std::time::{Duration, Instant};
 
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 use log::warn;
 
 use crate::vendored::ratatui_image::picker::{Capability, Picker, ProtocolType};
@@ -93,7 +97,17 @@ pub struct TerminalCapabilities {
 
 pub fn detect_terminal() -> TerminalCapabilities {
     let env = TerminalEnv::read();
-    detect_terminal_from_env(env)
+    let mut caps = detect_terminal_from_env(env);
+
+    // Env vars didn't name a protocol and the user didn't force one; ask the
+    // terminal directly before giving up and falling back to halfblocks.
+    if caps.protocol.is_none() && protocol_override_graphics_from_env().is_none() {
+        caps.protocol = probe_graphics_protocol(&caps.env);
+        caps.supports_graphics = protocol_supports_graphics(caps.protocol) || caps.supports_graphics;
+        caps.pdf = derive_pdf_capabilities(&caps);
+    }
+
+    caps
 }
 
 pub fn detect_terminal_with_picker(picker: &mut Picker) -> TerminalCapabilities {
@@ -359,6 +373,116 @@ fn guess_protocol_from_env(env: &TerminalEnv, kind: &TerminalKind) -> Option<Gra
     }
 }
 
+const PROTOCOL_PROBE_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Runtime capability probe, used when `TERM`/`TERM_PROGRAM` don't name a
+/// known terminal. Writes a Kitty graphics query plus a Primary Device
+/// Attributes (DA) request and reads the reply with a short timeout in raw
+/// mode: a well-formed Kitty graphics reply means `Kitty` (the image id in
+/// the query is never real, but only a Kitty-protocol terminal replies to it
+/// at all); a DA reply advertising sixel (attribute `4`) means `Sixel`.
+/// Otherwise falls back to a recognized iTerm2/WezTerm environment, or
+/// `None` so the caller can degrade to a text placeholder (`Halfblocks`).
+fn probe_graphics_protocol(env: &TerminalEnv) -> Option<GraphicsProtocol> {
+    if let Some(protocol) = probe_graphics_protocol_via_escape_sequences() {
+        return Some(protocol);
+    }
+
+    if env.term_program == "iterm.app" || env.iterm_session || env.wezterm_executable {
+        return Some(GraphicsProtocol::Iterm2);
+    }
+
+    None
+}
+
+fn probe_graphics_protocol_via_escape_sequences() -> Option<GraphicsProtocol> {
+    let raw_mode_was_enabled = enable_raw_mode().is_ok();
+    let result = write_protocol_probe_and_read_reply();
+    if raw_mode_was_enabled {
+        let _ = disable_raw_mode();
+    }
+    result.ok().flatten()
+}
+
+fn write_protocol_probe_and_read_reply() -> io::Result<Option<GraphicsProtocol>> {
+    let mut stdout = io::stdout();
+    // Kitty graphics query for a made-up image id: any terminal that
+    // understands the protocol replies (even with an error), proving support.
+    write!(stdout, "\x1b_Gi=1,a=q,t=d,f=24,s=1,v=1;AAAA\x1b\\")?;
+    // Primary Device Attributes request; sixel-capable terminals advertise
+    // attribute `4` in the reply.
+    write!(stdout, "\x1b[c")?;
+    stdout.flush()?;
+
+    let reply = read_probe_reply_with_timeout(PROTOCOL_PROBE_TIMEOUT)?;
+    Ok(classify_protocol_probe_reply(&reply))
+}
+
+fn read_probe_reply_with_timeout(timeout: Duration) -> io::Result<Vec<u8>> {
+    let mut stdin = io::stdin();
+    let fd = stdin.as_raw_fd();
+    let start = Instant::now();
+    let mut buffer = Vec::new();
+
+    loop {
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Ok(buffer);
+        }
+        let remaining = timeout - elapsed;
+        let timeout_ms = remaining.as_millis().min(i32::MAX as u128) as i32;
+
+        let mut poll_fd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let ready = unsafe { libc::poll(&mut poll_fd, 1, timeout_ms) };
+        if ready < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        if ready == 0 {
+            return Ok(buffer);
+        }
+
+        let mut chunk = [0u8; 256];
+        let read = stdin.read(&mut chunk)?;
+        if read == 0 {
+            return Ok(buffer);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+
+        if da_reply_is_complete(&buffer) {
+            return Ok(buffer);
+        }
+    }
+}
+
+fn da_reply_is_complete(buffer: &[u8]) -> bool {
+    buffer.windows(2).any(|w| w == b"\x1b[") && buffer.ends_with(b"c")
+}
+
+fn classify_protocol_probe_reply(reply: &[u8]) -> Option<GraphicsProtocol> {
+    if reply.windows(2).any(|w| w == b"\x1b_") {
+        return Some(GraphicsProtocol::Kitty);
+    }
+
+    if let Some(params) = extract_da_params(reply) {
+        if params.split(';').any(|attr| attr == "4") {
+            return Some(GraphicsProtocol::Sixel);
+        }
+    }
+
+    None
+}
+
+fn extract_da_params(reply: &[u8]) -> Option<&str> {
+    let text = std::str::from_utf8(reply).ok()?;
+    let start = text.find("\x1b[?")? + 3;
+    let end = text[start..].find('c')? + start;
+    Some(&text[start..end])
+}
+
 fn protocol_supports_graphics(protocol: Option<GraphicsProtocol>) -> bool {
     matches!(
         protocol,
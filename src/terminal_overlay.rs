@@ -26,8 +26,17 @@ pub fn emit_kitty_delete_all() {
     let _ = stdout().write_all(b"\x1b_Ga=d,d=A,q=2\x1b\\");
 }
 
-pub fn clear_overlay_images_if_needed() {
-    if kitty_delete_overlay_hack_enabled() {
+/// Clear any retained overlay image, dispatching on the protocol actually in
+/// use for this render. Only the Kitty protocol keeps images alive in the
+/// terminal's own memory (referenced by id and re-displayed without
+/// re-upload), so only Kitty needs an explicit delete-all escape, and only on
+/// the terminals where [`kitty_delete_overlay_hack_enabled`] says the
+/// retained image survives a plain redraw. Sixel and iTerm2 images are
+/// painted straight into the cell grid, so the next `terminal.draw` already
+/// overwrites them; dispatching on `is_kitty` keeps a Sixel/iTerm2 session
+/// from ever emitting a Kitty-specific escape it doesn't understand.
+pub fn clear_overlay_images_if_needed(is_kitty: bool) {
+    if is_kitty && kitty_delete_overlay_hack_enabled() {
         emit_kitty_delete_all();
     }
 }
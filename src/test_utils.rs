@@ -1,7 +1,9 @@
 pub mod test_helpers {
     use crate::event_source::{Event, KeyCode, KeyEvent, KeyModifiers, SimulatedEventSource};
+    use crate::main_app::{App, FPSCounter};
     use ratatui::backend::TestBackend;
     use ratatui::Terminal;
+    use regex::Regex;
 
     /// Builder for creating test scenarios with simulated user input
     pub struct TestScenarioBuilder {
@@ -97,6 +99,34 @@ pub mod test_helpers {
         pub fn build(self) -> SimulatedEventSource {
             SimulatedEventSource::new(self.events)
         }
+
+        /// Drive the recorded events through the same dispatch path the real
+        /// event loop (`run_app_with_event_source`) uses, redrawing `app` to
+        /// `terminal` between events, and leave the terminal's backend
+        /// buffer holding the final rendered frame for assertions.
+        pub fn run(self, app: &mut App, terminal: &mut Terminal<TestBackend>) {
+            let fps_counter = FPSCounter::new();
+            // Mirror `run_app_with_event_source`'s first-render guarantee so
+            // a scenario with zero recorded events still produces a frame.
+            terminal.draw(|f| app.draw(f, &fps_counter)).unwrap();
+            for event in self.events {
+                match event {
+                    Event::Key(key) => {
+                        let visible_height =
+                            terminal.size().unwrap().height.saturating_sub(5) as usize;
+                        app.handle_key_event_with_screen_height(key, Some(visible_height));
+                    }
+                    Event::Mouse(mouse_event) => {
+                        app.handle_and_drain_mouse_events(mouse_event, None);
+                    }
+                    Event::Resize(_cols, _rows) => {
+                        app.handle_resize();
+                    }
+                    _ => {}
+                }
+                terminal.draw(|f| app.draw(f, &fps_counter)).unwrap();
+            }
+        }
     }
 
     /// Create a test terminal for snapshot testing
@@ -105,6 +135,18 @@ pub mod test_helpers {
         Terminal::new(backend).unwrap()
     }
 
+    /// Create an `App` for snapshot testing: no bookmark file (ephemeral
+    /// bookmarks) and a mock system command executor so tests never shell
+    /// out or touch a real clipboard/viewer.
+    pub fn create_test_app() -> App {
+        App::new_with_mock_system_executor(
+            None,
+            Some("/dev/null"),
+            false,
+            crate::system_command::MockSystemCommandExecutor::new(),
+        )
+    }
+
     /// Capture the current terminal buffer as a string
     pub fn capture_terminal_state(terminal: &Terminal<TestBackend>) -> String {
         let buffer = terminal.backend().buffer();
@@ -128,6 +170,24 @@ pub mod test_helpers {
         lines.join("\n")
     }
 
+    /// Replace non-deterministic Kitty graphics escape payloads (e.g. the
+    /// base64 image data and per-run image ids emitted by the PDF converter)
+    /// with a stable placeholder, so snapshots captured across runs compare
+    /// equal.
+    pub fn normalize_kitty_escapes(text: &str) -> String {
+        let kitty_escape = Regex::new(r"\x1b_G[^\x1b]*\x1b\\").unwrap();
+        kitty_escape
+            .replace_all(text, "<KITTY_IMAGE>")
+            .into_owned()
+    }
+
+    /// Assert that `actual` matches the golden file at `path`, relative to
+    /// the crate root. Set `SNAPSHOTS=overwrite` to update the golden file
+    /// in place instead of failing, mirroring snapbox's `assert_eq`/`file!`.
+    pub fn assert_snapshot(actual: &str, path: &str) {
+        snapbox::assert_eq(snapbox::Data::read_from(std::path::Path::new(path), None), actual);
+    }
+
 }
 
 #[cfg(test)]
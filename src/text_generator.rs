@@ -11,6 +11,96 @@ use ratatui::{
 };
 use regex::Regex;
 use std::io::BufReader;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+
+/// A fenced/`<pre>` code block captured out of chapter HTML before the rest
+/// of the cleaning pipeline runs, so its contents are never mangled by entity
+/// decoding, tag stripping, or paragraph reflow.
+#[derive(Debug, Clone)]
+pub struct CodeBlock {
+    pub language: Option<String>,
+    pub lines: Vec<String>,
+    /// Set by a `runnable` class marker (mdBook-style "play" button affordance).
+    pub runnable: bool,
+    /// Set by an `ignore` class marker — excluded from any "run this" action.
+    pub ignore: bool,
+    /// Set by a `no-highlight`/`nohighlight` class marker — always rendered
+    /// as plain text even when a language is also present.
+    pub no_highlight: bool,
+}
+
+/// One line of a highlighted `CodeBlock`, as `(foreground color, text)`
+/// segments in rendering order.
+pub type HighlightedLine = Vec<(Color, String)>;
+
+/// Errors from `clean_html_content`'s placeholder-based pipeline. A
+/// malformed entity, an unbalanced code block, or a placeholder collision
+/// used to just silently produce broken output; these variants turn that
+/// corruption into a hard error surfaced to the caller instead.
+#[derive(Debug)]
+pub enum ParseError {
+    /// A placeholder inserted earlier in the pipeline (table or code block)
+    /// was never found in the text by the time its content was restored —
+    /// something upstream must have dropped or mangled it.
+    MissingPlaceholder { placeholder: String },
+    /// A placeholder marker survived all the way to the final text, meaning
+    /// it was never consumed (or a duplicate one was introduced).
+    UnconsumedPlaceholder { marker: String, offset: usize },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingPlaceholder { placeholder } => {
+                write!(f, "placeholder {placeholder} was never inserted into the cleaned text")
+            }
+            Self::UnconsumedPlaceholder { marker, offset } => {
+                write!(
+                    f,
+                    "placeholder marker {marker} survived to the final text at offset {offset}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Replaces `placeholder` in `text` with the result of `build_replacement`,
+/// failing instead of silently leaving the placeholder (or garbage) in
+/// place when it can't be found.
+fn try_replace_placeholder(
+    text: &str,
+    placeholder: &str,
+    build_replacement: impl FnOnce() -> Result<String, ParseError>,
+) -> Result<String, ParseError> {
+    if !text.contains(placeholder) {
+        return Err(ParseError::MissingPlaceholder {
+            placeholder: placeholder.to_string(),
+        });
+    }
+    let replacement = build_replacement()?;
+    Ok(text.replace(placeholder, &replacement))
+}
+
+/// Byte ranges (in the HTML snapshot passed to `extract_code_placeholders`)
+/// occupied by `<pre>` blocks, so later text transforms (URL detection,
+/// smart quotes, footnote markers, ...) can cheaply check whether a match
+/// offset falls inside code and skip it.
+#[derive(Debug, Clone, Default)]
+pub struct CodeSpanIndex {
+    ranges: Vec<(usize, usize)>,
+}
+
+impl CodeSpanIndex {
+    pub fn is_inside_code_block(&self, offset: usize) -> bool {
+        self.ranges
+            .iter()
+            .any(|&(start, end)| offset >= start && offset < end)
+    }
+}
 
 pub struct TextGenerator {
     p_tag_re: Regex,
@@ -22,7 +112,15 @@ pub struct TextGenerator {
     line_leading_space_re: Regex,
     img_tag_re: Regex,
     table_re: Regex,
+    pre_re: Regex,
     toc_parser: TocParser,
+    /// Code-span ranges captured by the most recent `clean_html_content`
+    /// call, so later transforms (URL linkification, etc.) can skip them
+    /// without threading the index through every call site.
+    last_code_span_index: std::cell::RefCell<CodeSpanIndex>,
+    /// Whether links are rendered as OSC 8 terminal hyperlinks (default) or
+    /// as the plain `label (url)` fallback for terminals without OSC 8 support.
+    osc8_hyperlinks: bool,
 }
 
 impl TextGenerator {
@@ -41,10 +139,29 @@ impl TextGenerator {
                 .expect("Failed to compile image tag regex"),
             table_re: Regex::new(r"(?s)<table[^>]*>.*?</table>")
                 .expect("Failed to compile table regex"),
+            pre_re: Regex::new(r"(?s)<pre([^>]*)>(.*?)</pre>")
+                .expect("Failed to compile pre tag regex"),
             toc_parser: TocParser::new(),
+            last_code_span_index: std::cell::RefCell::new(CodeSpanIndex::default()),
+            osc8_hyperlinks: true,
         }
     }
 
+    /// Enables or disables OSC 8 terminal hyperlink escapes for links
+    /// rendered by `clean_html_content`; when disabled, links fall back to
+    /// the plain `label (url)` form for terminals that can't open them.
+    pub fn set_osc8_hyperlinks(&mut self, enabled: bool) {
+        self.osc8_hyperlinks = enabled;
+    }
+
+    /// The code-span index captured by the most recent `clean_html_content`
+    /// call, so a later pass (URL linkification, smart quotes, footnote
+    /// markers, ...) can cheaply check `is_inside_code_block(offset)` before
+    /// rewriting a match, without re-parsing the chapter HTML itself.
+    pub fn code_span_index(&self) -> CodeSpanIndex {
+        self.last_code_span_index.borrow().clone()
+    }
+
     pub fn extract_chapter_title(&self, html_content: &str) -> Option<String> {
         let title_patterns = [
             Regex::new(r"<h[12][^>]*>([^<]+)</h[12]>").ok()?,
@@ -148,7 +265,9 @@ impl TextGenerator {
 
         let chapter_title = self.extract_chapter_title(&content);
         // Use a default width of 120 for chapter extraction (will be properly wrapped later)
-        let processed_text = self.clean_html_content(&content, &chapter_title, 120);
+        let processed_text = self
+            .clean_html_content(&content, &chapter_title, 120)
+            .map_err(|e| format!("Failed to clean chapter content: {}", e))?;
 
         if processed_text.is_empty() {
             warn!("Converted text is empty");
@@ -177,15 +296,16 @@ impl TextGenerator {
         content: &str,
         chapter_title: &Option<String>,
         terminal_width: usize,
-    ) -> String {
+    ) -> Result<String, ParseError> {
         let style_re = Regex::new(r"(?s)<style[^>]*>.*?</style>").unwrap();
         let script_re = Regex::new(r"(?s)<script[^>]*>.*?</script>").unwrap();
         let mut content = style_re.replace_all(content, "").into_owned();
         content = script_re.replace_all(&content, "").into_owned();
 
-        // Process links before removing tags - replace with markdown-style format
-        let link_re = Regex::new(r#"<a[^>]*\shref\s*=\s*["']([^"']+)["'][^>]*>(.*?)</a>"#).unwrap();
-        content = link_re.replace_all(&content, "[$2]($1)").into_owned();
+        // Process links before removing tags - render `<a href>` anchors and
+        // bare URLs as clickable hyperlinks, leaving anything inside a <pre>
+        // block untouched (those are handled verbatim by code extraction below).
+        content = self.linkify(&content);
 
         if let Some(_title) = chapter_title {
             // Remove h1/h2 tags that contain the chapter title
@@ -215,6 +335,13 @@ impl TextGenerator {
 
         content = tables_processed;
 
+        // Extract <pre> code blocks into placeholders so entity decoding, tag
+        // stripping, and paragraph reflow below never touch their contents.
+        let (code_extracted, code_blocks, code_span_index) =
+            self.extract_code_placeholders(&content);
+        content = code_extracted;
+        *self.last_code_span_index.borrow_mut() = code_span_index;
+
         // Process img tags into text placeholders
         content = self
             .img_tag_re
@@ -280,10 +407,350 @@ impl TextGenerator {
 
         // Restore table content from placeholders
         for (placeholder, table_text) in table_placeholders {
-            text = text.replace(&placeholder, &table_text);
+            text = try_replace_placeholder(&text, &placeholder, || Ok(table_text))?;
         }
 
-        text.trim().to_string()
+        // Restore code blocks verbatim, rendered plain (language-aware
+        // highlighting is applied later by the display layer).
+        for (idx, block) in code_blocks.iter().enumerate() {
+            let placeholder = format!("<<<CODEBLOCK:{}>>>", idx);
+            text = try_replace_placeholder(&text, &placeholder, || {
+                Ok(Self::render_code_block_plain(block, false))
+            })?;
+        }
+
+        let text = text.trim().to_string();
+
+        // Every placeholder inserted above must have been consumed by now;
+        // a leftover marker means a collision or an upstream bug corrupted
+        // the pipeline, and that should be a hard error, not garbage text.
+        if let Some(offset) = text.find("<<<CODEBLOCK:") {
+            return Err(ParseError::UnconsumedPlaceholder {
+                marker: "<<<CODEBLOCK:N>>>".to_string(),
+                offset,
+            });
+        }
+        if let Some(offset) = text.find("__TABLE_PLACEHOLDER_") {
+            return Err(ParseError::UnconsumedPlaceholder {
+                marker: "__TABLE_PLACEHOLDER_N__".to_string(),
+                offset,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Indexes the byte ranges occupied by `<pre>` blocks in `content`,
+    /// without extracting them — used to keep the link pass from rewriting
+    /// a `#heading`-looking fragment or bare URL that legitimately appears
+    /// inside a source listing.
+    fn code_ranges(&self, content: &str) -> CodeSpanIndex {
+        let ranges = self
+            .pre_re
+            .find_iter(content)
+            .map(|m| (m.start(), m.end()))
+            .collect();
+        CodeSpanIndex { ranges }
+    }
+
+    /// Renders `<a href>` anchors and bare `http(s)://` URLs as clickable
+    /// terminal hyperlinks (OSC 8: `ESC ] 8 ; ; url ESC \ label ESC ] 8 ; ; ESC \`),
+    /// preserving the original anchor text as the label and falling back to
+    /// the URL itself for bare links. Anything inside a `<pre>` block is left
+    /// untouched so it can be restored verbatim by code extraction below.
+    fn linkify(&self, content: &str) -> String {
+        let link_re = Regex::new(
+            r#"(?s)<a[^>]*\shref\s*=\s*["']([^"']+)["'][^>]*>(.*?)</a>|(https?://[^\s<>"']+)"#,
+        )
+        .unwrap();
+        let code_ranges = self.code_ranges(content);
+
+        let mut result = String::with_capacity(content.len());
+        let mut last_end = 0;
+        for caps in link_re.captures_iter(content) {
+            let whole = caps.get(0).unwrap();
+            result.push_str(&content[last_end..whole.start()]);
+
+            if code_ranges.is_inside_code_block(whole.start()) {
+                result.push_str(whole.as_str());
+            } else if let Some(href) = caps.get(1) {
+                let label = caps.get(2).map(|m| m.as_str()).unwrap_or("");
+                result.push_str(&self.render_hyperlink(href.as_str(), label));
+            } else if let Some(url) = caps.get(3) {
+                result.push_str(&self.render_hyperlink(url.as_str(), url.as_str()));
+            }
+
+            last_end = whole.end();
+        }
+        result.push_str(&content[last_end..]);
+        result
+    }
+
+    fn render_hyperlink(&self, url: &str, label: &str) -> String {
+        if self.osc8_hyperlinks {
+            format!("\x1b]8;;{url}\x1b\\{label}\x1b]8;;\x1b\\")
+        } else {
+            format!("{label} ({url})")
+        }
+    }
+
+    /// Pulls every `<pre>` block out of `content`, replacing each with a
+    /// `<<<CODEBLOCK:N>>>` placeholder (padded with blank lines so it is
+    /// never merged into a surrounding paragraph), and returns the extracted
+    /// blocks plus an index of the byte ranges they occupied in `content`.
+    fn extract_code_placeholders(&self, content: &str) -> (String, Vec<CodeBlock>, CodeSpanIndex) {
+        let mut blocks = Vec::new();
+        let mut ranges = Vec::new();
+
+        let mut result = String::with_capacity(content.len());
+        let mut last_end = 0;
+        for caps in self.pre_re.captures_iter(content) {
+            let whole = caps.get(0).unwrap();
+            result.push_str(&content[last_end..whole.start()]);
+
+            let attrs = caps.get(1).map(|m| m.as_str()).unwrap_or("");
+            let inner_html = caps.get(2).map(|m| m.as_str()).unwrap_or("");
+            let classes = Self::code_block_classes(attrs, inner_html);
+            let language = Self::detect_code_language(classes.as_deref());
+            let (runnable, ignore, no_highlight) = Self::detect_code_markers(classes.as_deref());
+            let lines = Self::decode_code_block_lines(inner_html);
+
+            ranges.push((whole.start(), whole.end()));
+            let idx = blocks.len();
+            blocks.push(CodeBlock {
+                language,
+                lines,
+                runnable,
+                ignore,
+                no_highlight,
+            });
+
+            result.push_str(&format!("\n\n<<<CODEBLOCK:{}>>>\n\n", idx));
+            last_end = whole.end();
+        }
+        result.push_str(&content[last_end..]);
+
+        (result, blocks, CodeSpanIndex { ranges })
+    }
+
+    /// Pulls the `class="..."` attribute value off the `<pre>` tag itself or
+    /// a nested `<code class="...">`, whichever is present first.
+    fn code_block_classes(attrs: &str, inner_html: &str) -> Option<String> {
+        let class_re = Regex::new(r#"class\s*=\s*["']([^"']+)["']"#).unwrap();
+        class_re
+            .captures(attrs)
+            .or_else(|| class_re.captures(inner_html))
+            .map(|caps| caps[1].to_string())
+    }
+
+    /// Detects the block's language from a `language-xxx`/`lang-xxx` class token.
+    fn detect_code_language(classes: Option<&str>) -> Option<String> {
+        classes?.split_whitespace().find_map(|c| {
+            c.strip_prefix("language-")
+                .or_else(|| c.strip_prefix("lang-"))
+                .map(|s| s.to_string())
+        })
+    }
+
+    /// Detects the mdBook-style `runnable`/`ignore`/`no-highlight` class
+    /// markers, returning `(runnable, ignore, no_highlight)`.
+    fn detect_code_markers(classes: Option<&str>) -> (bool, bool, bool) {
+        let Some(classes) = classes else {
+            return (false, false, false);
+        };
+        let tokens: Vec<&str> = classes.split_whitespace().collect();
+        let runnable = tokens.contains(&"runnable");
+        let ignore = tokens.contains(&"ignore");
+        let no_highlight = tokens.contains(&"no-highlight") || tokens.contains(&"nohighlight");
+        (runnable, ignore, no_highlight)
+    }
+
+    /// Decodes entities and strips `<span>`/`<code>` wrapper tags from a
+    /// `<pre>` block's inner HTML, splitting the result into lines.
+    fn decode_code_block_lines(inner_html: &str) -> Vec<String> {
+        let tag_re = Regex::new(r"</?(span|code)[^>]*>").unwrap();
+        let stripped = tag_re.replace_all(inner_html, "");
+        let decoded = stripped
+            .replace("&nbsp;", " ")
+            .replace("&amp;", "&")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'");
+        decoded.lines().map(|l| l.to_string()).collect()
+    }
+
+    /// Languages where a leading `#` is mdBook-style hidden-line syntax
+    /// rather than an actual comment/preprocessor marker.
+    const HIDDEN_LINE_LANGUAGES: &[&str] = &["rust"];
+    const HIDDEN_LINE_MARKER: &str = "⋮";
+
+    /// True if `line`'s first non-whitespace character is a single `#`
+    /// (i.e. not the `##`-escaped literal `#`) in a language that supports
+    /// the hidden-line convention.
+    fn is_boring_line(language: Option<&str>, line: &str) -> bool {
+        let Some(lang) = language else {
+            return false;
+        };
+        if !Self::HIDDEN_LINE_LANGUAGES.contains(&lang) {
+            return false;
+        }
+        let trimmed = line.trim_start();
+        trimmed.starts_with('#') && !trimmed.starts_with("##")
+    }
+
+    /// Strips the `# ` (or bare `#`) hidden-line prefix, preserving indentation.
+    fn reveal_boring_line(line: &str) -> String {
+        let indent_len = line.len() - line.trim_start().len();
+        let (indent, trimmed) = line.split_at(indent_len);
+        let rest = trimmed
+            .strip_prefix("# ")
+            .or_else(|| trimmed.strip_prefix('#'))
+            .unwrap_or(trimmed);
+        format!("{indent}{rest}")
+    }
+
+    /// Un-escapes a `##`-prefixed line back to its literal leading `#`.
+    fn unescape_literal_hash(line: &str) -> String {
+        let indent_len = line.len() - line.trim_start().len();
+        let (indent, trimmed) = line.split_at(indent_len);
+        match trimmed.strip_prefix('#') {
+            Some(rest) => format!("{indent}{rest}"),
+            None => line.to_string(),
+        }
+    }
+
+    /// Renders a `CodeBlock` as a flat, four-space-indented string (the
+    /// plaintext fallback used when no syntax highlighting is applied).
+    /// Boring context lines are collapsed behind a single marker unless
+    /// `reveal_hidden` is set, in which case they're shown with the hidden-line
+    /// prefix stripped; `##`-escaped literal `#` lines always render unescaped.
+    fn render_code_block_plain(block: &CodeBlock, reveal_hidden: bool) -> String {
+        let lang = block.language.as_deref();
+        let mut rendered_lines: Vec<String> = Vec::new();
+        let mut in_hidden_run = false;
+
+        for line in &block.lines {
+            if Self::is_boring_line(lang, line) {
+                if reveal_hidden {
+                    rendered_lines.push(Self::reveal_boring_line(line));
+                } else if !in_hidden_run {
+                    rendered_lines.push(Self::HIDDEN_LINE_MARKER.to_string());
+                    in_hidden_run = true;
+                }
+                continue;
+            }
+            in_hidden_run = false;
+            if lang.is_some() && line.trim_start().starts_with("##") {
+                rendered_lines.push(Self::unescape_literal_hash(line));
+            } else {
+                rendered_lines.push(line.clone());
+            }
+        }
+
+        rendered_lines
+            .iter()
+            .map(|line| format!("    {}", line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Renders a previously-extracted code block, revealing its hidden
+    /// "boring" context lines in full when `reveal_hidden` is set. Used by
+    /// the display layer to offer a reveal toggle independent of the default
+    /// (collapsed) rendering baked into `clean_html_content`.
+    pub fn render_code_block(block: &CodeBlock, reveal_hidden: bool) -> String {
+        Self::render_code_block_plain(block, reveal_hidden)
+    }
+
+    /// Applies terminal syntax highlighting to `block` via `syntect`, one
+    /// `(color, text)` segment list per line. Falls back to a single
+    /// unstyled segment per line when the block is tagged `no_highlight`,
+    /// has no detected language, or the language isn't in the default
+    /// syntax set — callers don't need to special-case either path.
+    pub fn highlight_code_block(block: &CodeBlock) -> Vec<HighlightedLine> {
+        if block.no_highlight {
+            return Self::plain_highlighted_lines(block);
+        }
+
+        let Some(language) = block.language.as_deref() else {
+            return Self::plain_highlighted_lines(block);
+        };
+
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let Some(syntax) = syntax_set
+            .find_syntax_by_token(language)
+            .or_else(|| syntax_set.find_syntax_by_extension(language))
+        else {
+            return Self::plain_highlighted_lines(block);
+        };
+
+        let theme_set = ThemeSet::load_defaults();
+        let Some(theme) = theme_set.themes.get("base16-ocean.dark") else {
+            return Self::plain_highlighted_lines(block);
+        };
+        let mut highlighter = HighlightLines::new(syntax, theme);
+
+        block
+            .lines
+            .iter()
+            .map(|line| {
+                let mut line_with_newline = line.clone();
+                line_with_newline.push('\n');
+                highlighter
+                    .highlight_line(&line_with_newline, &syntax_set)
+                    .map(|ranges| {
+                        ranges
+                            .into_iter()
+                            .map(|(style, text)| {
+                                (
+                                    Color::Rgb(
+                                        style.foreground.r,
+                                        style.foreground.g,
+                                        style.foreground.b,
+                                    ),
+                                    text.trim_end_matches('\n').to_string(),
+                                )
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_else(|_| vec![(Color::Reset, line.clone())])
+            })
+            .collect()
+    }
+
+    fn plain_highlighted_lines(block: &CodeBlock) -> Vec<HighlightedLine> {
+        block
+            .lines
+            .iter()
+            .map(|line| vec![(Color::Reset, line.clone())])
+            .collect()
+    }
+
+    /// Opt-in wpautop-style paragraph reflow: splits `text` on runs of
+    /// two-or-more newlines into paragraphs, folding single newlines inside a
+    /// paragraph into explicit line breaks instead of stripping them through
+    /// `multi_newline_re`/`line_leading_space_re`. Block-level wrappers
+    /// (lists, blockquotes, headings) and `<<<CODEBLOCK:N>>>` placeholders
+    /// are hard boundaries that are never merged into surrounding prose.
+    pub fn reflow_paragraphs_wpautop(&self, text: &str) -> String {
+        let block_level_re = Regex::new(r"(?i)^</?(ul|ol|li|blockquote|h[1-6])(\s|>|$)").unwrap();
+        let codeblock_re = Regex::new(r"^<<<CODEBLOCK:\d+>>>$").unwrap();
+        let paragraph_split_re = Regex::new(r"\n{2,}").unwrap();
+
+        paragraph_split_re
+            .split(text)
+            .map(|chunk| chunk.trim())
+            .filter(|chunk| !chunk.is_empty())
+            .map(|chunk| {
+                if block_level_re.is_match(chunk) || codeblock_re.is_match(chunk) {
+                    chunk.to_string()
+                } else {
+                    chunk.lines().collect::<Vec<_>>().join("\n")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
     }
 
     fn format_text_with_spacing(&self, text: &str) -> String {
@@ -652,7 +1119,9 @@ mod tests {
         let extracted_title = generator.extract_chapter_title(html_content);
         assert_eq!(extracted_title, Some("1. Simpleminded Hope".to_string()));
 
-        let cleaned_content = generator.clean_html_content(html_content, &extracted_title, 80);
+        let cleaned_content = generator
+            .clean_html_content(html_content, &extracted_title, 80)
+            .expect("clean_html_content should succeed");
 
         assert!(
             !cleaned_content.contains("Simpleminded Hope"),
@@ -770,7 +1239,9 @@ mod tests {
         <p>Some text after the table.</p>
         "#;
 
-        let cleaned = generator.clean_html_content(html_with_table, &None, 80);
+        let cleaned = generator
+            .clean_html_content(html_with_table, &None, 80)
+            .expect("clean_html_content should succeed");
 
         // Check that the table caption is present
         assert!(
@@ -822,7 +1293,9 @@ mod tests {
         </table>
         "#;
 
-        let cleaned = generator.clean_html_content(html_with_table, &None, 80);
+        let cleaned = generator
+            .clean_html_content(html_with_table, &None, 80)
+            .expect("clean_html_content should succeed");
 
         // Check table structure
         assert!(cleaned.contains("Name"));
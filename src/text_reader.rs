@@ -2387,6 +2387,7 @@ impl TextReader {
             self.scroll_offset,
             content_area.x,
             content_area.y,
+            &self.raw_text_lines,
         )
     }
 
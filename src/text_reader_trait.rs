@@ -6,6 +6,13 @@ use ratatui::layout::Rect;
 use ratatui_image::picker::Picker;
 use std::sync::Arc;
 
+/// Target format for `TextReaderTrait::export_to_path`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Markdown,
+    PlainText,
+}
+
 #[derive(Debug, Clone)]
 pub struct LinkInfo {
     pub text: String,
@@ -55,16 +62,30 @@ pub trait TextReaderTrait: VimNavMotions {
     fn restore_to_node_index(&mut self, node_index: usize);
 
     // Text selection
-    fn handle_mouse_down(&mut self, x: u16, y: u16, area: Rect);
+    fn handle_mouse_down(&mut self, x: u16, y: u16, area: Rect, block: bool);
     fn handle_mouse_drag(&mut self, x: u16, y: u16, area: Rect);
     fn handle_mouse_up(&mut self, x: u16, y: u16, area: Rect) -> Option<String>;
     fn handle_double_click(&mut self, x: u16, y: u16, area: Rect);
     fn handle_triple_click(&mut self, x: u16, y: u16, area: Rect);
+
+    // Link hover
+    fn handle_mouse_move(&mut self, x: u16, y: u16, area: Rect);
+    fn hovered_link_url(&self) -> Option<&str>;
     fn clear_selection(&mut self);
+    /// The currently selected text, reconstructed from the underlying raw
+    /// lines, or `None` if nothing is selected. Used by callers that need
+    /// the text itself rather than just a copy-to-clipboard side effect
+    /// (e.g. copying through an injected `ClipboardProvider`).
+    fn get_selected_text(&self) -> Option<String>;
     fn copy_selection_to_clipboard(&self) -> Result<(), String>;
     fn copy_chapter_to_clipboard(&self) -> Result<(), String>;
     fn has_text_selection(&self) -> bool;
 
+    /// Write the active selection (or the whole chapter, if nothing is
+    /// selected) to `path` in the given format. A headless/scriptable
+    /// counterpart to `copy_selection_to_clipboard`/`copy_chapter_to_clipboard`.
+    fn export_to_path(&self, path: &std::path::Path, format: ExportFormat) -> Result<(), String>;
+
     // Image handling
     fn preload_image_dimensions(&mut self, book_images: &BookImages);
     fn check_for_loaded_images(&mut self) -> bool;
@@ -86,6 +107,10 @@ pub trait TextReaderTrait: VimNavMotions {
     // Updates
     fn update_highlight(&mut self) -> bool;
     fn update_auto_scroll(&mut self) -> bool;
+    /// Whether a drag left the content area and continuous auto-scroll
+    /// should be ticking, so the caller can schedule/unschedule the
+    /// repeating `AutoScrollTick` on the `Scheduler` accordingly.
+    fn is_auto_scrolling(&self) -> bool;
 
     // Raw HTML mode
     fn toggle_raw_html(&mut self);
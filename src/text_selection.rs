@@ -3,18 +3,205 @@ use ratatui::{
     style::Color,
     text::{Line, Span},
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Splits `line` into its grapheme clusters, so a selection boundary can
+/// never land inside a multi-scalar cluster (combining marks, flags, ZWJ
+/// emoji sequences).
+fn graphemes(line: &str) -> Vec<&str> {
+    line.graphemes(true).collect()
+}
+
+/// Character categories used by word/WORD selection, mirroring Helix's
+/// `CharCategory` so a run of punctuation (e.g. `->`) selects as one unit
+/// instead of stopping at every non-word character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharCategory {
+    Whitespace,
+    Eol,
+    Word,
+    Punctuation,
+}
+
+fn categorize_char(c: char) -> CharCategory {
+    if c == '\n' {
+        CharCategory::Eol
+    } else if c.is_whitespace() {
+        CharCategory::Whitespace
+    } else if c.is_alphanumeric() || c == '_' {
+        CharCategory::Word
+    } else {
+        CharCategory::Punctuation
+    }
+}
+
+/// Categorizes a grapheme cluster by its base (first) scalar value.
+fn categorize_cluster(cluster: &str) -> CharCategory {
+    cluster.chars().next().map(categorize_char).unwrap_or(CharCategory::Whitespace)
+}
+
+/// Bracket pairs recognized by `select_inside_pair`/`select_around_pair`.
+const BRACKET_PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+/// Symmetric quote delimiters recognized by `select_inside_pair`/`select_around_pair`.
+const QUOTE_CHARS: [char; 3] = ['"', '\'', '`'];
+
+fn close_for_open(c: char) -> Option<char> {
+    BRACKET_PAIRS.iter().find(|&&(open, _)| open == c).map(|&(_, close)| close)
+}
+
+fn open_for_close(c: char) -> Option<char> {
+    BRACKET_PAIRS.iter().find(|&&(_, close)| close == c).map(|&(open, _)| open)
+}
+
+fn is_sentence_terminator(c: char) -> bool {
+    matches!(c, '.' | '!' | '?')
+}
+
+/// Shared by `TextSelection::is_point_in_selection` and
+/// `Selection::is_point_in_selection`: whether `(line, column)` falls
+/// within the normalized `[start, end)` span.
+fn range_contains(start: &SelectionPoint, end: &SelectionPoint, line: usize, column: usize) -> bool {
+    if line < start.line || line > end.line {
+        return false;
+    }
+
+    if line == start.line && line == end.line {
+        column >= start.column && column < end.column
+    } else if line == start.line {
+        column >= start.column
+    } else if line == end.line {
+        column < end.column
+    } else {
+        true
+    }
+}
+
+/// Shared by `TextSelection::extract_selected_text` and
+/// `Selection::extract_selected_text`: the text spanned by `[start, end)`.
+fn extract_range_text(start: &SelectionPoint, end: &SelectionPoint, lines: &[String]) -> Option<String> {
+    let mut selected_text = String::new();
+
+    for line_idx in start.line..=end.line.min(lines.len().saturating_sub(1)) {
+        if let Some(line) = lines.get(line_idx) {
+            let line_clusters = graphemes(line);
+
+            let start_col = if line_idx == start.line {
+                start.column.min(line_clusters.len())
+            } else {
+                0
+            };
+
+            let end_col = if line_idx == end.line {
+                end.column.min(line_clusters.len())
+            } else {
+                line_clusters.len()
+            };
+
+            if start_col < end_col {
+                selected_text.push_str(&line_clusters[start_col..end_col].concat());
+            }
+
+            if line_idx < end.line {
+                selected_text.push('\n');
+            }
+        }
+    }
+
+    if !selected_text.is_empty() {
+        Some(selected_text)
+    } else {
+        None
+    }
+}
+
+/// Shared by `TextSelection::apply_selection_highlighting` and
+/// `Selection::apply_selection_highlighting`: splits `spans` into
+/// selected/unselected segments according to `is_point_selected`.
+fn highlight_spans<'a>(
+    spans: Vec<Span<'a>>,
+    selection_bg_color: Color,
+    is_point_selected: impl Fn(usize) -> bool,
+) -> Line<'a> {
+    let mut result_spans = Vec::new();
+    let mut current_column = 0;
+
+    for span in spans {
+        let span_text = span.content.to_string();
+        let span_clusters = graphemes(&span_text);
+        let span_end_column = current_column + span_clusters.len();
+
+        let has_selection_in_span = (current_column..span_end_column).any(&is_point_selected);
+
+        if !has_selection_in_span {
+            result_spans.push(span);
+        } else {
+            let mut i = 0;
+            while i < span_clusters.len() {
+                let cluster_column = current_column + i;
+                let is_selected = is_point_selected(cluster_column);
+
+                let mut j = i + 1;
+                while j < span_clusters.len() {
+                    let next_cluster_column = current_column + j;
+                    if is_point_selected(next_cluster_column) != is_selected {
+                        break;
+                    }
+                    j += 1;
+                }
+
+                let segment_text: String = span_clusters[i..j].concat();
+                let segment_style = if is_selected {
+                    span.style.bg(selection_bg_color)
+                } else {
+                    span.style
+                };
+
+                result_spans.push(Span::styled(segment_text, segment_style));
+                i = j;
+            }
+        }
+
+        current_column = span_end_column;
+    }
+
+    Line::from(result_spans)
+}
+
+/// Closing quotes/brackets allowed between a sentence terminator and the
+/// whitespace/EOL that ends the sentence (e.g. `"Really?" she asked.`).
+fn is_trailing_closing_delim(c: char) -> bool {
+    matches!(c, '"' | '\'' | '`' | ')' | ']' | '}' | '>')
+}
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SelectionPoint {
     pub line: usize,
+    /// Grapheme-cluster index within the line, not a `char` or byte index.
     pub column: usize,
 }
 
+/// Direction for the keyboard-driven `extend_by_*` motions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/// An anchor/head selection, Helix-style: `start` is the immovable anchor
+/// set when the selection began, `end` is the movable head that mouse drags
+/// and `extend_by_*` motions both move. `get_selection_range` normalizes the
+/// pair regardless of which one is numerically first.
 #[derive(Debug, Clone)]
 pub struct TextSelection {
     pub start: Option<SelectionPoint>,
     pub end: Option<SelectionPoint>,
     pub is_selecting: bool,
+    /// Whether this selection is a rectangular block (Alacritty's
+    /// `SelectionType::Block`: a column range x row range) rather than the
+    /// default linear start->end span. Set by `start_block_selection`.
+    is_block: bool,
 }
 
 impl TextSelection {
@@ -23,6 +210,7 @@ impl TextSelection {
             start: None,
             end: None,
             is_selecting: false,
+            is_block: false,
         }
     }
 
@@ -31,6 +219,32 @@ impl TextSelection {
         self.start = Some(SelectionPoint { line, column });
         self.end = Some(SelectionPoint { line, column });
         self.is_selecting = true;
+        self.is_block = false;
+    }
+
+    /// Like `start_selection`, but selects a rectangle (column range x row
+    /// range) instead of a linear span - triggered by holding a modifier
+    /// (Alt) during a mouse drag, for copying aligned columns out of tables
+    /// or code blocks.
+    pub fn start_block_selection(&mut self, line: usize, column: usize) {
+        debug!("Starting block text selection at line: {}, column: {}", line, column);
+        self.start = Some(SelectionPoint { line, column });
+        self.end = Some(SelectionPoint { line, column });
+        self.is_selecting = true;
+        self.is_block = true;
+    }
+
+    /// The rectangle's row range and column range, independently normalized
+    /// (unlike `get_selection_range`'s line-then-column ordering for linear
+    /// spans). Only meaningful when `is_block` is set.
+    fn block_bounds(&self) -> Option<(usize, usize, usize, usize)> {
+        let (start, end) = (self.start.as_ref()?, self.end.as_ref()?);
+        Some((
+            start.line.min(end.line),
+            start.line.max(end.line),
+            start.column.min(end.column),
+            start.column.max(end.column),
+        ))
     }
 
     pub fn update_selection(&mut self, line: usize, column: usize) {
@@ -45,11 +259,164 @@ impl TextSelection {
         self.is_selecting = false;
     }
 
+    /// Move the head one grapheme cluster in `dir`, clamped to document
+    /// extent. No-op if there's no active selection to extend.
+    pub fn extend_by_char(&mut self, dir: Direction, lines: &[String]) {
+        let Some(head) = self.end.clone() else { return };
+        let moved = Self::step_char(&head, dir, lines);
+        self.end = Some(Self::clamp_point(moved, lines));
+    }
+
+    /// Move the head to the start of the next/previous word-category run,
+    /// reusing the same `CharCategory` boundary scan as `select_word_at`.
+    pub fn extend_by_word(&mut self, dir: Direction, lines: &[String]) {
+        let Some(head) = self.end.clone() else { return };
+        let moved = self.step_word(&head, dir, lines);
+        self.end = Some(Self::clamp_point(moved, lines));
+    }
+
+    /// Move the head one line up/down, keeping its column (clamped to the
+    /// new line's length).
+    pub fn extend_by_line(&mut self, dir: Direction, lines: &[String]) {
+        let Some(head) = self.end.clone() else { return };
+        if lines.is_empty() {
+            return;
+        }
+        let new_line = match dir {
+            Direction::Forward => (head.line + 1).min(lines.len() - 1),
+            Direction::Backward => head.line.saturating_sub(1),
+        };
+        let moved = SelectionPoint { line: new_line, column: head.column };
+        self.end = Some(Self::clamp_point(moved, lines));
+    }
+
+    /// Move the head to the start of the next/previous paragraph (a blank
+    /// line, or document start/end), reusing `find_paragraph_boundaries`.
+    pub fn extend_by_paragraph(&mut self, dir: Direction, lines: &[String]) {
+        let Some(head) = self.end.clone() else { return };
+        if lines.is_empty() {
+            return;
+        }
+        let line = head.line.min(lines.len() - 1);
+        let (para_start, para_end) = self.find_paragraph_boundaries(lines, line);
+
+        let moved = match dir {
+            Direction::Forward => {
+                let mut next = para_end + 1;
+                while next < lines.len() && lines[next].trim().is_empty() {
+                    next += 1;
+                }
+                if next < lines.len() {
+                    SelectionPoint { line: next, column: 0 }
+                } else {
+                    let last = lines.len() - 1;
+                    SelectionPoint { line: last, column: Self::line_grapheme_count(lines, last) }
+                }
+            }
+            Direction::Backward => {
+                if para_start == 0 {
+                    SelectionPoint { line: 0, column: 0 }
+                } else {
+                    let mut prev = para_start - 1;
+                    while prev > 0 && lines[prev].trim().is_empty() {
+                        prev -= 1;
+                    }
+                    let (prev_start, _) = self.find_paragraph_boundaries(lines, prev);
+                    SelectionPoint { line: prev_start, column: 0 }
+                }
+            }
+        };
+
+        self.end = Some(Self::clamp_point(moved, lines));
+    }
+
+    fn line_grapheme_count(lines: &[String], line: usize) -> usize {
+        lines.get(line).map(|l| graphemes(l).len()).unwrap_or(0)
+    }
+
+    /// Clamps a head position to a valid grapheme index within document extent.
+    fn clamp_point(point: SelectionPoint, lines: &[String]) -> SelectionPoint {
+        if lines.is_empty() {
+            return SelectionPoint { line: 0, column: 0 };
+        }
+        let line = point.line.min(lines.len() - 1);
+        let len = Self::line_grapheme_count(lines, line);
+        SelectionPoint { line, column: point.column.min(len) }
+    }
+
+    fn step_char(point: &SelectionPoint, dir: Direction, lines: &[String]) -> SelectionPoint {
+        let len = Self::line_grapheme_count(lines, point.line);
+        match dir {
+            Direction::Forward => {
+                if point.column < len {
+                    SelectionPoint { line: point.line, column: point.column + 1 }
+                } else if point.line + 1 < lines.len() {
+                    SelectionPoint { line: point.line + 1, column: 0 }
+                } else {
+                    point.clone()
+                }
+            }
+            Direction::Backward => {
+                if point.column > 0 {
+                    SelectionPoint { line: point.line, column: point.column - 1 }
+                } else if point.line > 0 {
+                    let prev_len = Self::line_grapheme_count(lines, point.line - 1);
+                    SelectionPoint { line: point.line - 1, column: prev_len }
+                } else {
+                    point.clone()
+                }
+            }
+        }
+    }
+
+    fn step_word(&self, point: &SelectionPoint, dir: Direction, lines: &[String]) -> SelectionPoint {
+        let Some(line_text) = lines.get(point.line) else { return point.clone() };
+        let clusters = graphemes(line_text);
+
+        match dir {
+            Direction::Forward => {
+                if point.column >= clusters.len() {
+                    return if point.line + 1 < lines.len() {
+                        SelectionPoint { line: point.line + 1, column: 0 }
+                    } else {
+                        point.clone()
+                    };
+                }
+                let (_, run_end) = self.find_word_boundaries(&clusters, point.column, false);
+                let mut col = run_end;
+                while col < clusters.len() && categorize_cluster(clusters[col]) == CharCategory::Whitespace {
+                    col += 1;
+                }
+                SelectionPoint { line: point.line, column: col }
+            }
+            Direction::Backward => {
+                if point.column == 0 {
+                    return if point.line > 0 {
+                        let prev_len = Self::line_grapheme_count(lines, point.line - 1);
+                        SelectionPoint { line: point.line - 1, column: prev_len }
+                    } else {
+                        point.clone()
+                    };
+                }
+                let mut col = point.column;
+                while col > 0 && categorize_cluster(clusters[col - 1]) == CharCategory::Whitespace {
+                    col -= 1;
+                }
+                if col > 0 {
+                    let (run_start, _) = self.find_word_boundaries(&clusters, col - 1, false);
+                    col = run_start;
+                }
+                SelectionPoint { line: point.line, column: col }
+            }
+        }
+    }
+
     pub fn clear_selection(&mut self) {
         debug!("Clearing text selection");
         self.start = None;
         self.end = None;
         self.is_selecting = false;
+        self.is_block = false;
     }
 
     pub fn has_selection(&self) -> bool {
@@ -69,73 +436,52 @@ impl TextSelection {
         }
     }
 
+    /// `column` is a grapheme-cluster index, matching `SelectionPoint::column`.
     pub fn is_point_in_selection(&self, line: usize, column: usize) -> bool {
-        if let Some((start, end)) = self.get_selection_range() {
-            if line < start.line || line > end.line {
-                return false;
-            }
-            
-            if line == start.line && line == end.line {
-                // Single line selection
-                column >= start.column && column < end.column
-            } else if line == start.line {
-                // First line of multi-line selection
-                column >= start.column
-            } else if line == end.line {
-                // Last line of multi-line selection
-                column < end.column
-            } else {
-                // Middle line of multi-line selection
-                true
-            }
-        } else {
-            false
+        if self.is_block {
+            return match self.block_bounds() {
+                Some((min_line, max_line, min_col, max_col)) => {
+                    line >= min_line && line <= max_line && column >= min_col && column < max_col
+                }
+                None => false,
+            };
+        }
+        match self.get_selection_range() {
+            Some((start, end)) => range_contains(&start, &end, line, column),
+            None => false,
         }
     }
 
     pub fn extract_selected_text(&self, lines: &[String]) -> Option<String> {
-        if let Some((start, end)) = self.get_selection_range() {
-            let mut selected_text = String::new();
-            
-            for line_idx in start.line..=end.line.min(lines.len().saturating_sub(1)) {
-                if let Some(line) = lines.get(line_idx) {
-                    let line_chars: Vec<char> = line.chars().collect();
-                    
-                    let start_col = if line_idx == start.line {
-                        start.column.min(line_chars.len())
-                    } else {
-                        0
-                    };
-                    
-                    let end_col = if line_idx == end.line {
-                        end.column.min(line_chars.len())
-                    } else {
-                        line_chars.len()
-                    };
-                    
-                    if start_col < end_col {
-                        let selected_part: String = line_chars[start_col..end_col].iter().collect();
-                        selected_text.push_str(&selected_part);
-                    }
-                    
-                    // Add newline except for the last line
-                    if line_idx < end.line {
-                        selected_text.push('\n');
-                    }
-                }
-            }
-            
-            if !selected_text.is_empty() {
-                Some(selected_text)
+        if self.is_block {
+            return self.extract_block_selected_text(lines);
+        }
+        let (start, end) = self.get_selection_range()?;
+        extract_range_text(&start, &end, lines)
+    }
+
+    /// Joins each row's selected column slice with `\n`, rather than
+    /// concatenating whole lines the way a linear selection does.
+    fn extract_block_selected_text(&self, lines: &[String]) -> Option<String> {
+        let (min_line, max_line, min_col, max_col) = self.block_bounds()?;
+        let mut rows = Vec::new();
+        for line_idx in min_line..=max_line.min(lines.len().saturating_sub(1)) {
+            let clusters = graphemes(lines.get(line_idx)?);
+            let start_col = min_col.min(clusters.len());
+            let end_col = max_col.min(clusters.len());
+            rows.push(if start_col < end_col {
+                clusters[start_col..end_col].concat()
             } else {
-                None
-            }
-        } else {
+                String::new()
+            });
+        }
+        if rows.iter().all(String::is_empty) {
             None
+        } else {
+            Some(rows.join("\n"))
         }
     }
 
-
     /// Apply selection highlighting to a line of spans
     pub fn apply_selection_highlighting<'a>(
         &self,
@@ -147,82 +493,54 @@ impl TextSelection {
             return Line::from(spans);
         }
 
-        let mut result_spans = Vec::new();
-        let mut current_column = 0;
-
-        for span in spans {
-            let span_text = span.content.to_string();
-            let span_chars: Vec<char> = span_text.chars().collect();
-            let span_end_column = current_column + span_chars.len();
-
-            // Check if any part of this span is selected
-            let has_selection_in_span = (current_column..span_end_column)
-                .any(|col| self.is_point_in_selection(line_idx, col));
-
-            if !has_selection_in_span {
-                // No selection in this span, keep it as is
-                result_spans.push(span);
-            } else {
-                // Split the span into selected and unselected parts
-                let mut i = 0;
-                while i < span_chars.len() {
-                    let char_column = current_column + i;
-                    let is_selected = self.is_point_in_selection(line_idx, char_column);
-                    
-                    // Find the extent of the current selection state
-                    let mut j = i + 1;
-                    while j < span_chars.len() {
-                        let next_char_column = current_column + j;
-                        let next_is_selected = self.is_point_in_selection(line_idx, next_char_column);
-                        if next_is_selected != is_selected {
-                            break;
-                        }
-                        j += 1;
-                    }
-                    
-                    // Create a span for this segment
-                    let segment_text: String = span_chars[i..j].iter().collect();
-                    let segment_style = if is_selected {
-                        span.style.bg(selection_bg_color)
-                    } else {
-                        span.style
-                    };
-                    
-                    result_spans.push(Span::styled(segment_text, segment_style));
-                    i = j;
-                }
-            }
-
-            current_column = span_end_column;
-        }
-
-        Line::from(result_spans)
+        highlight_spans(spans, selection_bg_color, |col| {
+            self.is_point_in_selection(line_idx, col)
+        })
     }
 
-    /// Select word at the given position
+    /// Select word at the given position (Helix's "word" motion): a run of
+    /// the same `CharCategory` as the clicked cluster.
     pub fn select_word_at(&mut self, line: usize, column: usize, lines: &[String]) {
         debug!("Selecting word at line: {}, column: {}", line, column);
-        
+        self.select_boundary_run_at(line, column, lines, false, "word");
+    }
+
+    /// Select WORD at the given position (Helix's "WORD" motion): everything
+    /// non-whitespace is one unit, stopping only at whitespace/EOL.
+    pub fn select_long_word_at(&mut self, line: usize, column: usize, lines: &[String]) {
+        debug!("Selecting WORD at line: {}, column: {}", line, column);
+        self.select_boundary_run_at(line, column, lines, true, "WORD");
+    }
+
+    fn select_boundary_run_at(
+        &mut self,
+        line: usize,
+        column: usize,
+        lines: &[String],
+        long: bool,
+        kind: &str,
+    ) {
         if let Some(line_text) = lines.get(line) {
-            let chars: Vec<char> = line_text.chars().collect();
-            
-            if column >= chars.len() {
+            let clusters = graphemes(line_text);
+
+            if column >= clusters.len() {
                 // Click beyond end of line, select nothing
                 return;
             }
-            
-            // Find word boundaries
-            let (word_start, word_end) = self.find_word_boundaries(&chars, column);
-            
-            // Set selection to the word boundaries
-            self.start = Some(SelectionPoint { line, column: word_start });
-            self.end = Some(SelectionPoint { line, column: word_end });
+
+            // Find boundaries of the run sharing the clicked cluster's category
+            let (run_start, run_end) = self.find_word_boundaries(&clusters, column, long);
+
+            // Set selection to the run boundaries
+            self.start = Some(SelectionPoint { line, column: run_start });
+            self.end = Some(SelectionPoint { line, column: run_end });
             self.is_selecting = false; // Word selection is complete
-            
-            debug!("Selected word from column {} to {}", word_start, word_end);
+
+            debug!("Selected {} from column {} to {}", kind, run_start, run_end);
         }
     }
-    
+
+
     /// Select paragraph at the given position
     pub fn select_paragraph_at(&mut self, line: usize, column: usize, lines: &[String]) {
         debug!("Selecting paragraph at line: {}, column: {}", line, column);
@@ -238,7 +556,7 @@ impl TextSelection {
         self.start = Some(SelectionPoint { line: para_start, column: 0 });
         // Select to the end of the last line in the paragraph
         let end_column = if let Some(last_line) = lines.get(para_end) {
-            last_line.chars().count()
+            last_line.graphemes(true).count()
         } else {
             0
         };
@@ -247,37 +565,357 @@ impl TextSelection {
         
         debug!("Selected paragraph from line {} to line {}", para_start, para_end);
     }
-    
-    /// Find word boundaries around the given column position
-    fn find_word_boundaries(&self, chars: &[char], column: usize) -> (usize, usize) {
-        if chars.is_empty() || column >= chars.len() {
-            return (column, column);
+
+    /// Select the sentence at the given position: scans backward to the end
+    /// of the previous sentence and forward to the end of the current one.
+    /// A sentence terminator is `.`/`!`/`?`, optionally followed by closing
+    /// quotes/brackets, then whitespace or line end; sentences also start
+    /// at a paragraph boundary (blank line) or document start. Cursor on
+    /// trailing whitespace after a terminator attaches to the preceding
+    /// sentence rather than the next one.
+    pub fn select_sentence_at(&mut self, line: usize, column: usize, lines: &[String]) {
+        debug!("Selecting sentence at line: {}, column: {}", line, column);
+
+        if line >= lines.len() {
+            return;
+        }
+
+        let (para_start, para_end) = self.find_paragraph_boundaries(lines, line);
+
+        // Flatten the paragraph's clusters into one sequence, with a
+        // virtual '\n' entry bridging each line (itself whitespace, so it
+        // participates naturally in terminator/whitespace scanning).
+        let mut flat: Vec<(usize, usize, char)> = Vec::new();
+        for li in para_start..=para_end {
+            let clusters = graphemes(&lines[li]);
+            for (ci, cluster) in clusters.iter().enumerate() {
+                if let Some(c) = cluster.chars().next() {
+                    flat.push((li, ci, c));
+                }
+            }
+            if li < para_end {
+                flat.push((li, clusters.len(), '\n'));
+            }
+        }
+
+        if flat.is_empty() {
+            return;
+        }
+
+        let col_clamped = column.min(graphemes(&lines[line]).len());
+        let cursor_idx = flat
+            .iter()
+            .position(|&(l, c, _)| l == line && c == col_clamped)
+            .unwrap_or_else(|| flat.iter().position(|&(l, _, _)| l > line).unwrap_or(flat.len()));
+
+        let (sentence_start, sentence_end) = Self::find_sentence_span(&flat, cursor_idx);
+
+        let start_pos = flat[sentence_start];
+        let end_pos = flat[sentence_end.saturating_sub(1).min(flat.len() - 1)];
+
+        self.start = Some(SelectionPoint { line: start_pos.0, column: start_pos.1 });
+        self.end = Some(SelectionPoint { line: end_pos.0, column: end_pos.1 + 1 });
+        self.is_selecting = false;
+
+        debug!(
+            "Selected sentence from ({}, {}) to ({}, {})",
+            start_pos.0, start_pos.1, end_pos.0, end_pos.1 + 1
+        );
+    }
+
+    /// Splits a flattened paragraph into sentence `(start, end)` index
+    /// ranges (end exclusive, trimmed of surrounding whitespace), then
+    /// returns the range containing `cursor_idx`. Whitespace trailing a
+    /// terminator is attributed to the preceding sentence.
+    fn find_sentence_span(flat: &[(usize, usize, char)], cursor_idx: usize) -> (usize, usize) {
+        let skip_ws = |mut i: usize| {
+            while i < flat.len() && flat[i].2.is_whitespace() {
+                i += 1;
+            }
+            i
+        };
+
+        // Find terminator boundaries: index right after a terminator run
+        // (plus any trailing closing quotes/brackets) that is itself
+        // followed by whitespace or end of paragraph.
+        let mut boundaries = Vec::new();
+        let mut i = 0;
+        while i < flat.len() {
+            if is_sentence_terminator(flat[i].2) {
+                let mut j = i + 1;
+                while j < flat.len() && is_trailing_closing_delim(flat[j].2) {
+                    j += 1;
+                }
+                if j >= flat.len() || flat[j].2.is_whitespace() {
+                    boundaries.push(j);
+                    i = j;
+                    continue;
+                }
+            }
+            i += 1;
+        }
+
+        let mut sentences: Vec<(usize, usize)> = Vec::new();
+        let mut start = skip_ws(0);
+        for &boundary in &boundaries {
+            if start < boundary {
+                sentences.push((start, boundary));
+            }
+            start = skip_ws(boundary);
+        }
+        if start < flat.len() {
+            sentences.push((start, flat.len()));
+        }
+        if sentences.is_empty() {
+            return (0, flat.len());
+        }
+
+        for (idx, &(s, e)) in sentences.iter().enumerate() {
+            let next_start = sentences.get(idx + 1).map(|&(ns, _)| ns).unwrap_or(usize::MAX);
+            if cursor_idx < e || idx == sentences.len() - 1 || cursor_idx < next_start {
+                return (s, e);
+            }
+        }
+
+        *sentences.last().unwrap()
+    }
+
+    /// Select inside the nearest enclosing bracket/quote pair (Helix's `mi`
+    /// surround text object): the span between the delimiters, exclusive.
+    /// Leaves selection state untouched and returns `false` if no enclosing
+    /// pair is found.
+    pub fn select_inside_pair(&mut self, line: usize, column: usize, lines: &[String]) -> bool {
+        self.select_pair_at(line, column, lines, false)
+    }
+
+    /// Select around the nearest enclosing bracket/quote pair (Helix's `ma`
+    /// surround text object): the span including the delimiters themselves.
+    /// Leaves selection state untouched and returns `false` if no enclosing
+    /// pair is found.
+    pub fn select_around_pair(&mut self, line: usize, column: usize, lines: &[String]) -> bool {
+        self.select_pair_at(line, column, lines, true)
+    }
+
+    fn select_pair_at(&mut self, line: usize, column: usize, lines: &[String], around: bool) -> bool {
+        let clusters: Vec<Vec<&str>> = lines.iter().map(|l| graphemes(l)).collect();
+        let Some((open_pos, close_pos)) = Self::find_enclosing_pair(&clusters, line, column) else {
+            return false;
+        };
+
+        let (start, end) = if around {
+            (open_pos, (close_pos.0, close_pos.1 + 1))
+        } else {
+            ((open_pos.0, open_pos.1 + 1), close_pos)
+        };
+
+        self.start = Some(SelectionPoint { line: start.0, column: start.1 });
+        self.end = Some(SelectionPoint { line: end.0, column: end.1 });
+        self.is_selecting = false;
+        true
+    }
+
+    /// Scans outward from `(line, column)` for the nearest enclosing
+    /// bracket/quote pair, tracking nesting depth so inner pairs are
+    /// skipped, and returns the positions of the opening and closing
+    /// delimiters. Mirrors Helix's `surround` text objects.
+    fn find_enclosing_pair(
+        clusters: &[Vec<&str>],
+        line: usize,
+        column: usize,
+    ) -> Option<((usize, usize), (usize, usize))> {
+        if line >= clusters.len() || clusters[line].is_empty() {
+            return None;
+        }
+        let cursor = (line, column.min(clusters[line].len() - 1));
+
+        if let Some(ch) = Self::char_at(clusters, cursor) {
+            if QUOTE_CHARS.contains(&ch) {
+                if let Some(close_pos) = Self::scan_forward_for_quote(clusters, cursor, ch) {
+                    return Some((cursor, close_pos));
+                }
+                return Self::scan_backward_for_quote(clusters, cursor, ch).map(|open_pos| (open_pos, cursor));
+            }
+
+            if let Some(close_ch) = close_for_open(ch) {
+                return Self::scan_forward_for_close(clusters, cursor, ch, close_ch)
+                    .map(|close_pos| (cursor, close_pos));
+            }
+
+            if open_for_close(ch).is_some() {
+                let before = Self::prev_pos(clusters, cursor)?;
+                let (open_pos, _open_ch, close_ch) = Self::scan_backward_for_open(clusters, before)?;
+                return (close_ch == ch).then_some((open_pos, cursor));
+            }
+        }
+
+        // Cursor sits on ordinary content: scan backward for the nearest
+        // unmatched opening bracket, then forward for its matching close.
+        if let Some(before) = Self::prev_pos(clusters, cursor) {
+            if let Some((open_pos, open_ch, close_ch)) = Self::scan_backward_for_open(clusters, before) {
+                if let Some(close_pos) = Self::scan_forward_for_close(clusters, open_pos, open_ch, close_ch) {
+                    return Some((open_pos, close_pos));
+                }
+                return None;
+            }
+        }
+
+        // No enclosing bracket: fall back to the nearest surrounding quote pair.
+        if let Some(before) = Self::prev_pos(clusters, cursor) {
+            let mut pos = Some(before);
+            while let Some(p) = pos {
+                if let Some(ch) = Self::char_at(clusters, p) {
+                    if QUOTE_CHARS.contains(&ch) {
+                        return Self::scan_forward_for_quote(clusters, cursor, ch).map(|close_pos| (p, close_pos));
+                    }
+                }
+                pos = Self::prev_pos(clusters, p);
+            }
+        }
+
+        None
+    }
+
+    fn char_at(clusters: &[Vec<&str>], pos: (usize, usize)) -> Option<char> {
+        clusters.get(pos.0)?.get(pos.1)?.chars().next()
+    }
+
+    fn prev_pos(clusters: &[Vec<&str>], pos: (usize, usize)) -> Option<(usize, usize)> {
+        let (line, col) = pos;
+        if col > 0 {
+            Some((line, col - 1))
+        } else if line > 0 {
+            let prev_line = line - 1;
+            let len = clusters[prev_line].len();
+            Some((prev_line, len.saturating_sub(1)))
+        } else {
+            None
+        }
+    }
+
+    fn next_pos(clusters: &[Vec<&str>], pos: (usize, usize)) -> Option<(usize, usize)> {
+        let (line, col) = pos;
+        if col + 1 < clusters[line].len() {
+            Some((line, col + 1))
+        } else if line + 1 < clusters.len() {
+            Some((line + 1, 0))
+        } else {
+            None
+        }
+    }
+
+    /// Scans backward (inclusive of `from`) for the nearest opening bracket
+    /// whose nesting depth (tracked per delimiter type) is unmatched,
+    /// skipping fully-nested inner pairs along the way.
+    fn scan_backward_for_open(
+        clusters: &[Vec<&str>],
+        from: (usize, usize),
+    ) -> Option<((usize, usize), char, char)> {
+        let mut depth: std::collections::HashMap<char, i32> = std::collections::HashMap::new();
+        let mut pos = Some(from);
+        while let Some(p) = pos {
+            if let Some(ch) = Self::char_at(clusters, p) {
+                if let Some(close) = close_for_open(ch) {
+                    let d = depth.entry(close).or_insert(0);
+                    if *d > 0 {
+                        *d -= 1;
+                    } else {
+                        return Some((p, ch, close));
+                    }
+                } else if open_for_close(ch).is_some() {
+                    *depth.entry(ch).or_insert(0) += 1;
+                }
+            }
+            pos = Self::prev_pos(clusters, p);
+        }
+        None
+    }
+
+    /// Scans forward (exclusive of `from`) for `close_ch`, skipping any
+    /// nested `open_ch`/`close_ch` pairs in between.
+    fn scan_forward_for_close(
+        clusters: &[Vec<&str>],
+        from: (usize, usize),
+        open_ch: char,
+        close_ch: char,
+    ) -> Option<(usize, usize)> {
+        let mut depth = 0i32;
+        let mut pos = Self::next_pos(clusters, from);
+        while let Some(p) = pos {
+            if let Some(ch) = Self::char_at(clusters, p) {
+                if ch == close_ch {
+                    if depth == 0 {
+                        return Some(p);
+                    }
+                    depth -= 1;
+                } else if ch == open_ch {
+                    depth += 1;
+                }
+            }
+            pos = Self::next_pos(clusters, p);
+        }
+        None
+    }
+
+    /// Scans forward (exclusive of `from`) for the next occurrence of `quote`.
+    fn scan_forward_for_quote(clusters: &[Vec<&str>], from: (usize, usize), quote: char) -> Option<(usize, usize)> {
+        let mut pos = Self::next_pos(clusters, from);
+        while let Some(p) = pos {
+            if Self::char_at(clusters, p) == Some(quote) {
+                return Some(p);
+            }
+            pos = Self::next_pos(clusters, p);
+        }
+        None
+    }
+
+    /// Scans backward (exclusive of `from`) for the nearest occurrence of `quote`.
+    fn scan_backward_for_quote(clusters: &[Vec<&str>], from: (usize, usize), quote: char) -> Option<(usize, usize)> {
+        let mut pos = Self::prev_pos(clusters, from);
+        while let Some(p) = pos {
+            if Self::char_at(clusters, p) == Some(quote) {
+                return Some(p);
+            }
+            pos = Self::prev_pos(clusters, p);
         }
-        
-        // Check if we're on a word character
-        let is_word_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
-        
-        let clicked_char = chars[column];
-        if !is_word_char(clicked_char) {
-            // Not on a word character, select just this character
-            return (column, column + 1);
+        None
+    }
+
+    /// Find the boundaries of the category run containing `column`
+    /// (shared by `select_word_at` and `select_long_word_at`). When `long`
+    /// is true, `Word` and `Punctuation` are merged into a single category
+    /// so the whole non-whitespace run is selected, matching Helix's `long`
+    /// flag in `find_word_boundary`.
+    fn find_word_boundaries(&self, clusters: &[&str], column: usize, long: bool) -> (usize, usize) {
+        if clusters.is_empty() || column >= clusters.len() {
+            return (column, column);
         }
-        
-        // Find start of word (move left while we're on word characters)
+
+        let category_of = |cluster: &str| {
+            let category = categorize_cluster(cluster);
+            if long && category == CharCategory::Punctuation {
+                CharCategory::Word
+            } else {
+                category
+            }
+        };
+
+        let clicked_category = category_of(clusters[column]);
+
+        // Find start of the run (move left while the category matches)
         let mut start = column;
-        while start > 0 && is_word_char(chars[start - 1]) {
+        while start > 0 && category_of(clusters[start - 1]) == clicked_category {
             start -= 1;
         }
-        
-        // Find end of word (move right while we're on word characters)
+
+        // Find end of the run (move right while the category matches)
         let mut end = column;
-        while end < chars.len() && is_word_char(chars[end]) {
+        while end < clusters.len() && category_of(clusters[end]) == clicked_category {
             end += 1;
         }
-        
+
         (start, end)
     }
-    
+
     /// Find paragraph boundaries around the given line
     fn find_paragraph_boundaries(&self, lines: &[String], line: usize) -> (usize, usize) {
         if lines.is_empty() || line >= lines.len() {
@@ -312,7 +950,13 @@ impl TextSelection {
         (start, end)
     }
 
-    /// Convert screen coordinates to logical text coordinates
+    /// Convert screen coordinates to logical text coordinates.
+    ///
+    /// `lines` is the rendered (wrapped) line buffer the screen coordinates
+    /// are being clicked against; it's used to translate the screen-cell
+    /// offset into a grapheme index, since wide glyphs (CJK, many emoji)
+    /// and tabs occupy more than one terminal cell each. Pass an empty
+    /// slice to fall back to a 1-cell-per-grapheme mapping.
     pub fn screen_to_text_coords(
         &self,
         screen_x: u16,
@@ -320,26 +964,232 @@ impl TextSelection {
         scroll_offset: usize,
         content_area_x: u16,
         content_area_y: u16,
+        lines: &[String],
     ) -> Option<(usize, usize)> {
         // Convert screen coordinates to content-relative coordinates
         if screen_y < content_area_y {
             return None;
         }
-        
+
         let relative_y = (screen_y - content_area_y) as usize;
-        
+
         // Account for scroll offset
         let line = relative_y + scroll_offset;
-        
+
         // Handle column position - if click is to the left of content area, start from beginning of line
         let column = if screen_x < content_area_x {
             0  // Click on left margin - start from beginning of line
         } else {
-            (screen_x - content_area_x) as usize
+            let cell_offset = (screen_x - content_area_x) as usize;
+            match lines.get(line) {
+                Some(line_text) => Self::cell_offset_to_grapheme_index(line_text, cell_offset),
+                None => cell_offset,
+            }
         };
-        
+
         Some((line, column))
     }
+
+    /// Display width (in terminal cells) of a single grapheme cluster,
+    /// expanding tabs to a fixed stop width.
+    fn grapheme_cell_width(cluster: &str) -> usize {
+        const TAB_WIDTH: usize = 8;
+        if cluster == "\t" {
+            TAB_WIDTH
+        } else {
+            UnicodeWidthStr::width(cluster).max(1)
+        }
+    }
+
+    /// Maps a screen-cell offset (relative to the start of `line`) to the
+    /// grapheme-cluster index whose cell span contains it. Round-trips to
+    /// `cell_offset` itself for pure-ASCII lines.
+    fn cell_offset_to_grapheme_index(line: &str, cell_offset: usize) -> usize {
+        let clusters = graphemes(line);
+        let mut cells = 0usize;
+        for (index, cluster) in clusters.iter().enumerate() {
+            let width = Self::grapheme_cell_width(cluster);
+            if cell_offset < cells + width {
+                return index;
+            }
+            cells += width;
+        }
+        clusters.len()
+    }
+
+    /// Inverse of `cell_offset_to_grapheme_index`: the screen cell at which
+    /// the grapheme at `index` begins. Used to align selection highlighting
+    /// with what's actually on screen.
+    #[allow(dead_code)]
+    fn grapheme_index_to_cell_offset(line: &str, index: usize) -> usize {
+        graphemes(line)
+            .iter()
+            .take(index)
+            .map(|g| Self::grapheme_cell_width(g))
+            .sum()
+    }
+}
+
+impl Default for TextSelection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single contiguous selection span, without `TextSelection`'s transient
+/// `is_selecting` drag state. The building block `Selection` collects to
+/// support multiple disjoint ranges.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    pub start: SelectionPoint,
+    pub end: SelectionPoint,
+}
+
+impl Range {
+    pub fn new(start: SelectionPoint, end: SelectionPoint) -> Self {
+        Self { start, end }
+    }
+
+    /// `(start, end)` with `start` guaranteed not to come after `end`.
+    fn normalized(&self) -> (SelectionPoint, SelectionPoint) {
+        if self.start.line < self.end.line
+            || (self.start.line == self.end.line && self.start.column <= self.end.column)
+        {
+            (self.start.clone(), self.end.clone())
+        } else {
+            (self.end.clone(), self.start.clone())
+        }
+    }
+
+    fn contains(&self, line: usize, column: usize) -> bool {
+        let (start, end) = self.normalized();
+        range_contains(&start, &end, line, column)
+    }
+
+    fn overlaps(&self, other: &Range) -> bool {
+        let (a_start, a_end) = self.normalized();
+        let (b_start, b_end) = other.normalized();
+        (a_start.line, a_start.column) <= (b_end.line, b_end.column)
+            && (b_start.line, b_start.column) <= (a_end.line, a_end.column)
+    }
+
+    /// The smallest range spanning both `self` and `other`. Only meaningful
+    /// once `overlaps` has confirmed they should be combined.
+    fn merge(&self, other: &Range) -> Range {
+        let (a_start, a_end) = self.normalized();
+        let (b_start, b_end) = other.normalized();
+        let start = if (a_start.line, a_start.column) <= (b_start.line, b_start.column) {
+            a_start
+        } else {
+            b_start
+        };
+        let end = if (a_end.line, a_end.column) >= (b_end.line, b_end.column) {
+            a_end
+        } else {
+            b_end
+        };
+        Range { start, end }
+    }
+}
+
+/// A collection of disjoint selection ranges, Helix-style, for selecting
+/// and copying several non-contiguous passages together (e.g. a few quotes
+/// scattered across a chapter). `TextSelection` above keeps working
+/// unchanged for ordinary single-range mouse-drag selection; `Selection`
+/// layers multi-range support on top, treating the most recently added
+/// range as "primary".
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    ranges: Vec<Range>,
+    primary_index: usize,
+}
+
+impl Selection {
+    pub fn new() -> Self {
+        Self {
+            ranges: Vec::new(),
+            primary_index: 0,
+        }
+    }
+
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    pub fn primary(&self) -> Option<&Range> {
+        self.ranges.get(self.primary_index)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+        self.primary_index = 0;
+    }
+
+    /// Pushes `range` as the new primary selection without clearing the
+    /// existing ones. If it overlaps one or more existing ranges, they're
+    /// merged into a single range rather than left as overlapping disjoint
+    /// ones (mirrors Helix's `Selection::push`).
+    pub fn add_range(&mut self, range: Range) {
+        let mut merged = range;
+        self.ranges.retain(|existing| {
+            if merged.overlaps(existing) {
+                merged = merged.merge(existing);
+                false
+            } else {
+                true
+            }
+        });
+        self.ranges.push(merged);
+        self.primary_index = self.ranges.len() - 1;
+    }
+
+    /// `column` is a grapheme-cluster index, matching `SelectionPoint::column`.
+    pub fn is_point_in_selection(&self, line: usize, column: usize) -> bool {
+        self.ranges.iter().any(|r| r.contains(line, column))
+    }
+
+    /// Concatenates each range's text in document order, separated by
+    /// newlines, so copying disjoint passages reads as one block.
+    pub fn extract_selected_text(&self, lines: &[String]) -> Option<String> {
+        if self.ranges.is_empty() {
+            return None;
+        }
+
+        let mut ordered: Vec<(SelectionPoint, SelectionPoint)> =
+            self.ranges.iter().map(|r| r.normalized()).collect();
+        ordered.sort_by_key(|(start, _)| (start.line, start.column));
+
+        let parts: Vec<String> = ordered
+            .iter()
+            .filter_map(|(start, end)| extract_range_text(start, end, lines))
+            .collect();
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join("\n"))
+        }
+    }
+
+    /// Apply selection highlighting to a line of spans, consulting every range.
+    pub fn apply_selection_highlighting<'a>(
+        &self,
+        line_idx: usize,
+        spans: Vec<Span<'a>>,
+        selection_bg_color: Color,
+    ) -> Line<'a> {
+        if self.ranges.is_empty() {
+            return Line::from(spans);
+        }
+
+        highlight_spans(spans, selection_bg_color, |col| {
+            self.is_point_in_selection(line_idx, col)
+        })
+    }
 }
 
 #[cfg(test)]
@@ -353,6 +1203,71 @@ mod tests {
         assert!(!selection.is_selecting);
     }
 
+    #[test]
+    fn test_extend_by_char_moves_head_and_clamps() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["abc".to_string()];
+
+        selection.start_selection(0, 1);
+        selection.extend_by_char(Direction::Forward, &lines);
+        assert_eq!(selection.end, Some(SelectionPoint { line: 0, column: 2 }));
+        // Anchor stays put
+        assert_eq!(selection.start, Some(SelectionPoint { line: 0, column: 1 }));
+
+        // Clamp at end of document
+        selection.extend_by_char(Direction::Forward, &lines);
+        selection.extend_by_char(Direction::Forward, &lines);
+        assert_eq!(selection.end, Some(SelectionPoint { line: 0, column: 3 }));
+    }
+
+    #[test]
+    fn test_extend_by_char_crossing_anchor_flips_normalized_range() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["abcdef".to_string()];
+
+        selection.start_selection(0, 3);
+        selection.extend_by_char(Direction::Backward, &lines);
+        selection.extend_by_char(Direction::Backward, &lines);
+
+        let (range_start, range_end) = selection.get_selection_range().unwrap();
+        assert_eq!(range_start, SelectionPoint { line: 0, column: 1 });
+        assert_eq!(range_end, SelectionPoint { line: 0, column: 3 });
+    }
+
+    #[test]
+    fn test_extend_by_word_skips_to_next_word() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["foo bar baz".to_string()];
+
+        selection.start_selection(0, 0);
+        selection.extend_by_word(Direction::Forward, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "foo ");
+    }
+
+    #[test]
+    fn test_extend_by_line_keeps_column_clamped() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["a very long line".to_string(), "short".to_string()];
+
+        selection.start_selection(0, 10);
+        selection.extend_by_line(Direction::Forward, &lines);
+        assert_eq!(selection.end, Some(SelectionPoint { line: 1, column: 5 })); // clamped to "short".len()
+    }
+
+    #[test]
+    fn test_extend_by_paragraph_moves_to_next_blank_line_boundary() {
+        let mut selection = TextSelection::new();
+        let lines = vec![
+            "first paragraph".to_string(),
+            "".to_string(),
+            "second paragraph".to_string(),
+        ];
+
+        selection.start_selection(0, 0);
+        selection.extend_by_paragraph(Direction::Forward, &lines);
+        assert_eq!(selection.end, Some(SelectionPoint { line: 2, column: 0 }));
+    }
+
     #[test]
     fn test_selection_workflow() {
         let mut selection = TextSelection::new();
@@ -390,6 +1305,34 @@ mod tests {
         assert_eq!(range.1, SelectionPoint { line: 2, column: 10 });
     }
 
+    #[test]
+    fn test_block_selection_extracts_rectangle_not_linear_span() {
+        let mut selection = TextSelection::new();
+        let lines = vec![
+            "name    | age".to_string(),
+            "Alice   | 30".to_string(),
+            "Bob     | 25".to_string(),
+        ];
+
+        // Drag a rectangle over the "age"/"30"/"25" column, started from the
+        // bottom-right corner - block selection shouldn't care which corner
+        // the drag started from.
+        selection.start_block_selection(2, 13);
+        selection.update_selection(0, 10);
+
+        assert_eq!(
+            selection.extract_selected_text(&lines).unwrap(),
+            "age\n30\n25"
+        );
+
+        // A point outside the rectangle's column range, even on a row
+        // between the two corners, must not be selected - unlike a linear
+        // selection, where every column on a middle row counts.
+        assert!(!selection.is_point_in_selection(1, 2));
+        // A point inside the rectangle is selected.
+        assert!(selection.is_point_in_selection(1, 10));
+    }
+
     #[test]
     fn test_point_in_selection() {
         let mut selection = TextSelection::new();
@@ -431,24 +1374,295 @@ mod tests {
         assert_eq!(selected, "line\nSecond line with more text\nThird");
     }
 
+    #[test]
+    fn test_select_word_at_selects_punctuation_run_as_one_unit() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["foo->bar()".to_string()];
+
+        // Click on the `-` of `->`, should select the whole `->` run
+        selection.select_word_at(0, 3, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "->");
+
+        // Click on `foo`, should select just that word
+        selection.select_word_at(0, 1, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "foo");
+
+        // Click on `()`, should select the punctuation run
+        selection.select_word_at(0, 8, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "()");
+    }
+
+    #[test]
+    fn test_select_long_word_at_spans_non_whitespace_run() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["foo->bar() baz".to_string()];
+
+        // Click anywhere in `foo->bar()`, WORD selection stops only at whitespace
+        selection.select_long_word_at(0, 3, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "foo->bar()");
+    }
+
+    #[test]
+    fn test_select_inside_and_around_pair_parens() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["foo(bar, baz)qux".to_string()];
+
+        // Click inside the parens, on `bar`
+        assert!(selection.select_inside_pair(0, 5, &lines));
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "bar, baz");
+
+        assert!(selection.select_around_pair(0, 5, &lines));
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "(bar, baz)");
+    }
+
+    #[test]
+    fn test_select_inside_pair_skips_nested_pairs() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["outer(inner(deep), more)".to_string()];
+
+        // Click just after the outer `(`, should select the whole outer span
+        assert!(selection.select_inside_pair(0, 6, &lines));
+        assert_eq!(
+            selection.extract_selected_text(&lines).unwrap(),
+            "inner(deep), more"
+        );
+
+        // Click inside the nested pair
+        assert!(selection.select_inside_pair(0, 13, &lines));
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "deep");
+    }
+
+    #[test]
+    fn test_select_inside_pair_quotes() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["let s = \"hello world\";".to_string()];
+
+        assert!(selection.select_inside_pair(0, 12, &lines));
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "hello world");
+
+        assert!(selection.select_around_pair(0, 12, &lines));
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "\"hello world\"");
+    }
+
+    #[test]
+    fn test_select_inside_pair_spans_multiple_lines() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["foo(".to_string(), "  bar".to_string(), ")baz".to_string()];
+
+        assert!(selection.select_inside_pair(1, 2, &lines));
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "\n  bar\n");
+    }
+
+    #[test]
+    fn test_select_inside_pair_returns_false_without_enclosing_pair() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["no brackets here".to_string()];
+
+        assert!(!selection.select_inside_pair(0, 3, &lines));
+        assert!(!selection.has_selection());
+    }
+
+    #[test]
+    fn test_select_sentence_at_basic() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["First sentence. Second sentence! Third?".to_string()];
+
+        // Click in the middle of the second sentence
+        selection.select_sentence_at(0, 20, &lines);
+        assert_eq!(
+            selection.extract_selected_text(&lines).unwrap(),
+            "Second sentence!"
+        );
+
+        // Click in the first sentence
+        selection.select_sentence_at(0, 2, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "First sentence.");
+
+        // Click in the last (unterminated by trailing whitespace) sentence
+        selection.select_sentence_at(0, 38, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "Third?");
+    }
+
+    #[test]
+    fn test_select_sentence_at_attaches_trailing_whitespace_to_preceding_sentence() {
+        let mut selection = TextSelection::new();
+        let lines = vec!["First sentence. Second sentence.".to_string()];
+
+        // Column 15 is the space right after "First sentence."
+        selection.select_sentence_at(0, 15, &lines);
+        assert_eq!(selection.extract_selected_text(&lines).unwrap(), "First sentence.");
+    }
+
+    #[test]
+    fn test_select_sentence_at_spans_multiple_lines_within_paragraph() {
+        let mut selection = TextSelection::new();
+        let lines = vec![
+            "First sentence. Second".to_string(),
+            "sentence continues.".to_string(),
+        ];
+
+        // Click into "Second sentence continues." which wraps across lines
+        selection.select_sentence_at(0, 18, &lines);
+        assert_eq!(
+            selection.extract_selected_text(&lines).unwrap(),
+            "Second\nsentence continues."
+        );
+    }
+
+    #[test]
+    fn test_select_sentence_at_respects_paragraph_boundaries() {
+        let mut selection = TextSelection::new();
+        let lines = vec![
+            "Paragraph one sentence.".to_string(),
+            "".to_string(),
+            "Paragraph two sentence.".to_string(),
+        ];
+
+        selection.select_sentence_at(2, 2, &lines);
+        assert_eq!(
+            selection.extract_selected_text(&lines).unwrap(),
+            "Paragraph two sentence."
+        );
+    }
+
+    #[test]
+    fn test_extract_selected_text_keeps_grapheme_clusters_intact() {
+        let mut selection = TextSelection::new();
+        // "Hi " + flag emoji (a 2-codepoint cluster) + "!"
+        let lines = vec!["Hi \u{1F1FA}\u{1F1F8}!".to_string()];
+
+        // Select the flag cluster only (column 3..4)
+        selection.start_selection(0, 3);
+        selection.update_selection(0, 4);
+
+        let selected = selection.extract_selected_text(&lines).unwrap();
+        assert_eq!(selected, "\u{1F1FA}\u{1F1F8}");
+    }
+
     #[test]
     fn test_screen_to_text_coords() {
         let selection = TextSelection::new();
-        
+        let lines: Vec<String> = vec![];
+
         // Test coordinate conversion
-        let result = selection.screen_to_text_coords(15, 10, 5, 10, 5);
+        let result = selection.screen_to_text_coords(15, 10, 5, 10, 5, &lines);
         assert_eq!(result, Some((10, 5))); // (relative_y + scroll_offset, relative_x)
-        
+
         // Test coordinates outside content area (above)
-        let result = selection.screen_to_text_coords(15, 3, 5, 10, 5);
+        let result = selection.screen_to_text_coords(15, 3, 5, 10, 5, &lines);
         assert_eq!(result, None);
-        
+
         // Test clicking on left margin - should start from beginning of line
-        let result = selection.screen_to_text_coords(5, 10, 5, 10, 5);
+        let result = selection.screen_to_text_coords(5, 10, 5, 10, 5, &lines);
         assert_eq!(result, Some((10, 0))); // Click on left margin -> column 0
-        
+
         // Test clicking far to the left
-        let result = selection.screen_to_text_coords(0, 10, 5, 10, 5);
+        let result = selection.screen_to_text_coords(0, 10, 5, 10, 5, &lines);
         assert_eq!(result, Some((10, 0))); // Still column 0
     }
+
+    #[test]
+    fn test_screen_to_text_coords_is_width_aware() {
+        let selection = TextSelection::new();
+        // Two wide (double-cell) CJK characters followed by an ASCII one.
+        let lines = vec!["\u{4F60}\u{597D}a".to_string()];
+
+        // Clicking at cell 0 or 1 should both land on the first character
+        // (it occupies two cells), cell 2 or 3 on the second, cell 4 on the third.
+        assert_eq!(
+            selection.screen_to_text_coords(10, 5, 0, 10, 5, &lines),
+            Some((0, 0))
+        );
+        assert_eq!(
+            selection.screen_to_text_coords(11, 5, 0, 10, 5, &lines),
+            Some((0, 0))
+        );
+        assert_eq!(
+            selection.screen_to_text_coords(12, 5, 0, 10, 5, &lines),
+            Some((0, 1))
+        );
+        assert_eq!(
+            selection.screen_to_text_coords(14, 5, 0, 10, 5, &lines),
+            Some((0, 2))
+        );
+    }
+
+    #[test]
+    fn test_selection_add_range_collects_disjoint_ranges() {
+        let mut selection = Selection::new();
+        selection.add_range(Range::new(
+            SelectionPoint { line: 0, column: 0 },
+            SelectionPoint { line: 0, column: 3 },
+        ));
+        selection.add_range(Range::new(
+            SelectionPoint { line: 2, column: 1 },
+            SelectionPoint { line: 2, column: 4 },
+        ));
+
+        assert_eq!(selection.ranges().len(), 2);
+        assert_eq!(selection.primary(), selection.ranges().last());
+    }
+
+    #[test]
+    fn test_selection_add_range_merges_overlapping_ranges() {
+        let mut selection = Selection::new();
+        selection.add_range(Range::new(
+            SelectionPoint { line: 0, column: 0 },
+            SelectionPoint { line: 0, column: 5 },
+        ));
+        selection.add_range(Range::new(
+            SelectionPoint { line: 0, column: 3 },
+            SelectionPoint { line: 0, column: 8 },
+        ));
+
+        assert_eq!(selection.ranges().len(), 1);
+        let merged = selection.primary().unwrap();
+        assert_eq!(merged.start, SelectionPoint { line: 0, column: 0 });
+        assert_eq!(merged.end, SelectionPoint { line: 0, column: 8 });
+    }
+
+    #[test]
+    fn test_selection_is_point_in_selection_consults_all_ranges() {
+        let mut selection = Selection::new();
+
+        selection.add_range(Range::new(
+            SelectionPoint { line: 0, column: 0 },
+            SelectionPoint { line: 0, column: 5 },
+        ));
+        selection.add_range(Range::new(
+            SelectionPoint { line: 1, column: 7 },
+            SelectionPoint { line: 1, column: 12 },
+        ));
+
+        assert!(selection.is_point_in_selection(0, 2));
+        assert!(selection.is_point_in_selection(1, 9));
+        assert!(!selection.is_point_in_selection(0, 7));
+        assert!(!selection.is_point_in_selection(1, 2));
+    }
+
+    #[test]
+    fn test_selection_extract_selected_text_joins_ranges_in_document_order() {
+        let mut selection = Selection::new();
+        let lines = vec!["first quote here".to_string(), "second quote here".to_string()];
+
+        // Added out of document order, on purpose.
+        selection.add_range(Range::new(
+            SelectionPoint { line: 1, column: 0 },
+            SelectionPoint { line: 1, column: 6 },
+        ));
+        selection.add_range(Range::new(
+            SelectionPoint { line: 0, column: 0 },
+            SelectionPoint { line: 0, column: 5 },
+        ));
+
+        let extracted = selection.extract_selected_text(&lines).unwrap();
+        assert_eq!(extracted, "first\nsecond");
+    }
+
+    #[test]
+    fn test_selection_extract_selected_text_empty_selection_is_none() {
+        let selection = Selection::new();
+        let lines = vec!["anything".to_string()];
+        assert_eq!(selection.extract_selected_text(&lines), None);
+    }
 }
\ No newline at end of file
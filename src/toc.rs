@@ -0,0 +1,131 @@
+/// A single heading-derived entry in a chapter's in-document outline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    pub level: u8,
+    pub text: String,
+    pub anchor_id: String,
+    pub line: usize,
+    pub children: Vec<TocEntry>,
+}
+
+/// Incrementally builds a hierarchical table of contents from a flat stream
+/// of headings encountered in document order, mirroring rustdoc's
+/// `TocBuilder`: each `push` walks back up the currently open chain of
+/// headings, popping any whose level is not shallower than the new one, then
+/// attaches the new entry as a child of whatever remains open (or to the
+/// root if nothing is). This handles documents that start below H1 and
+/// skipped levels (H1 then H3) without panicking - they just nest under
+/// whatever ancestor is actually open.
+#[derive(Debug, Default)]
+pub struct TocBuilder {
+    top_level: Vec<TocEntry>,
+    /// Currently open chain of ancestor headings: level, plus the path of
+    /// child indices (from `top_level`) needed to reach its `children` vec.
+    chain: Vec<(u8, Vec<usize>)>,
+}
+
+impl TocBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, level: u8, text: String, anchor_id: String, line: usize) {
+        while let Some((open_level, _)) = self.chain.last() {
+            if *open_level >= level {
+                self.chain.pop();
+            } else {
+                break;
+            }
+        }
+
+        let parent_path = match self.chain.last() {
+            Some((_, path)) => path.clone(),
+            None => Vec::new(),
+        };
+
+        let parent_children = Self::children_at_mut(&mut self.top_level, &parent_path);
+        parent_children.push(TocEntry {
+            level,
+            text,
+            anchor_id,
+            line,
+            children: Vec::new(),
+        });
+
+        let mut new_path = parent_path;
+        new_path.push(parent_children.len() - 1);
+        self.chain.push((level, new_path));
+    }
+
+    fn children_at_mut<'a>(
+        top_level: &'a mut Vec<TocEntry>,
+        path: &[usize],
+    ) -> &'a mut Vec<TocEntry> {
+        let mut current = top_level;
+        for &idx in path {
+            current = &mut current[idx].children;
+        }
+        current
+    }
+
+    pub fn into_entries(self) -> Vec<TocEntry> {
+        self.top_level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_headings_stay_at_top_level() {
+        let mut builder = TocBuilder::new();
+        builder.push(1, "One".to_string(), "one".to_string(), 0);
+        builder.push(1, "Two".to_string(), "two".to_string(), 10);
+        let entries = builder.into_entries();
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].children.is_empty());
+        assert!(entries[1].children.is_empty());
+    }
+
+    #[test]
+    fn nests_deeper_headings_under_the_last_shallower_one() {
+        let mut builder = TocBuilder::new();
+        builder.push(1, "Chapter".to_string(), "chapter".to_string(), 0);
+        builder.push(2, "Section".to_string(), "section".to_string(), 5);
+        builder.push(2, "Section 2".to_string(), "section-2".to_string(), 15);
+        builder.push(1, "Chapter 2".to_string(), "chapter-2".to_string(), 30);
+        let entries = builder.into_entries();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].children.len(), 2);
+        assert_eq!(entries[0].children[0].text, "Section");
+        assert_eq!(entries[0].children[1].text, "Section 2");
+        assert!(entries[1].children.is_empty());
+    }
+
+    #[test]
+    fn skipped_levels_do_not_panic_and_nest_under_the_open_ancestor() {
+        let mut builder = TocBuilder::new();
+        builder.push(1, "One".to_string(), "one".to_string(), 0);
+        builder.push(3, "Deep".to_string(), "deep".to_string(), 5);
+        let entries = builder.into_entries();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].children.len(), 1);
+        assert_eq!(entries[0].children[0].text, "Deep");
+    }
+
+    #[test]
+    fn document_starting_below_h1_attaches_to_root() {
+        let mut builder = TocBuilder::new();
+        builder.push(2, "Intro".to_string(), "intro".to_string(), 0);
+        builder.push(3, "Sub".to_string(), "sub".to_string(), 5);
+        builder.push(2, "Next".to_string(), "next".to_string(), 10);
+        let entries = builder.into_entries();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].children.len(), 1);
+        assert_eq!(entries[0].children[0].text, "Sub");
+    }
+}
@@ -0,0 +1,76 @@
+use crate::theme::Base16Palette;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// What `App` should do after a URL-import popup key event.
+pub enum UrlImportAction {
+    Submit(String),
+    Close,
+}
+
+/// Single-line URL entry popup for "import from URL" (see
+/// `BookManager::import_article_from_url`).
+pub struct UrlImportPopup {
+    url: String,
+}
+
+impl Default for UrlImportPopup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlImportPopup {
+    pub fn new() -> Self {
+        Self { url: String::new() }
+    }
+
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> Option<UrlImportAction> {
+        match key.code {
+            KeyCode::Esc => Some(UrlImportAction::Close),
+            KeyCode::Backspace => {
+                self.url.pop();
+                None
+            }
+            KeyCode::Char(c) => {
+                self.url.push(c);
+                None
+            }
+            KeyCode::Enter if !self.url.trim().is_empty() => {
+                Some(UrlImportAction::Submit(self.url.trim().to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect, palette: &Base16Palette) {
+        let popup_area = centered_rect(60, 3, area);
+        f.render_widget(Clear, popup_area);
+
+        let input = Paragraph::new(format!("> {}", self.url)).block(
+            Block::default()
+                .title(" Import Article from URL ")
+                .borders(Borders::ALL)
+                .style(Style::default().bg(palette.base_00).fg(palette.base_05)),
+        );
+        f.render_widget(input, popup_area);
+    }
+}
+
+/// Centers a `percent_x`-wide, `height`-tall rect within `r`.
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let x = r.x + (r.width.saturating_sub(r.width * percent_x / 100)) / 2;
+    let y = r.y + r.height.saturating_sub(height) / 2;
+    let width = r.width * percent_x / 100;
+    Rect {
+        x,
+        y,
+        width,
+        height: height.min(r.height),
+    }
+}
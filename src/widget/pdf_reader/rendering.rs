@@ -1863,8 +1863,8 @@ impl PdfReaderState {
             DisplayBatch::Clear
         } else {
             let _ = execute!(stdout(), BeginSynchronizedUpdate);
-            if terminal_overlay::kitty_delete_overlay_hack_enabled() && needs_clear {
-                terminal_overlay::emit_kitty_delete_all();
+            if needs_clear {
+                terminal_overlay::clear_overlay_images_if_needed(self.is_kitty);
             }
             if terminal_overlay::overlay_force_clear_enabled() {
                 terminal_overlay::clear_rect_direct(img_area);
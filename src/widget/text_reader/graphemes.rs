@@ -0,0 +1,59 @@
+//! Grapheme-cluster and display-width helpers for normal-mode motions.
+//!
+//! Cursor "columns" in normal mode count grapheme clusters (UAX#29), not
+//! `char`s, so combining marks (e.g. "e" + U+0301) and multi-scalar emoji
+//! stay a single cursor stop. Rendering additionally needs each cluster's
+//! terminal cell width to keep the on-screen highlight aligned with what
+//! the terminal actually draws.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Splits `line` into its grapheme clusters.
+pub fn graphemes(line: &str) -> Vec<&str> {
+    line.graphemes(true).collect()
+}
+
+/// Number of grapheme clusters in `line`.
+pub fn grapheme_count(line: &str) -> usize {
+    line.graphemes(true).count()
+}
+
+/// Index of the first non-whitespace grapheme cluster in `line`.
+pub fn first_non_whitespace_cluster(line: &str) -> usize {
+    graphemes(line)
+        .iter()
+        .position(|g| !g.chars().all(char::is_whitespace))
+        .unwrap_or(0)
+}
+
+/// Terminal display width (in cells) of a single grapheme cluster.
+pub fn cluster_width(cluster: &str) -> usize {
+    UnicodeWidthStr::width(cluster).max(1)
+}
+
+/// Maps a grapheme-cluster index to the display column its first cell
+/// occupies, given the line's clusters. Used to keep `ensure_cursor_visible`
+/// and the rendered cursor highlight in sync with wide glyphs.
+pub fn cluster_index_to_display_column(clusters: &[&str], index: usize) -> usize {
+    clusters
+        .iter()
+        .take(index)
+        .map(|g| cluster_width(g))
+        .sum()
+}
+
+/// True if the cluster should be treated as a "word" character for `w`/`b`/`e`
+/// motions: alphanumeric or underscore, judged from the cluster's base scalar.
+pub fn is_word_cluster(cluster: &str) -> bool {
+    cluster
+        .chars()
+        .next()
+        .map(|c| c.is_alphanumeric() || c == '_')
+        .unwrap_or(false)
+}
+
+/// True if the cluster is whitespace.
+pub fn is_whitespace_cluster(cluster: &str) -> bool {
+    cluster.chars().all(char::is_whitespace)
+}
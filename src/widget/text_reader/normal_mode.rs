@@ -1,3 +1,4 @@
+use super::graphemes::{first_non_whitespace_cluster, grapheme_count, graphemes, is_word_cluster};
 use super::{LineType, MarkdownTextReader};
 use crate::theme::Base16Palette;
 use ratatui::{style::Style as RatatuiStyle, text::Span};
@@ -31,6 +32,7 @@ pub enum VisualMode {
     None,
     CharacterWise, // v
     LineWise,      // V
+    BlockWise,     // Ctrl-V
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -75,6 +77,9 @@ impl YankHighlight {
     }
 }
 
+/// Name of the unnamed register (`"`), used when no register is selected.
+pub const UNNAMED_REGISTER: char = '"';
+
 #[derive(Debug)]
 pub struct NormalModeState {
     pub active: bool,
@@ -89,6 +94,10 @@ pub struct NormalModeState {
     pub count: Option<usize>,
     pub visual_mode: VisualMode,
     pub visual_anchor: Option<CursorPosition>,
+    /// Named (`"a`-`"z`), unnamed (`"`), and numbered (`"0`-`"9`) registers.
+    pub registers: std::collections::HashMap<char, String>,
+    /// Set by a `"` prefix (e.g. `"a` before `yy`); consumed by the next yank.
+    pub pending_register: Option<char>,
 }
 
 impl Default for NormalModeState {
@@ -105,6 +114,8 @@ impl Default for NormalModeState {
             count: None,
             visual_mode: VisualMode::None,
             visual_anchor: None,
+            registers: std::collections::HashMap::new(),
+            pending_register: None,
         }
     }
 }
@@ -124,6 +135,7 @@ impl NormalModeState {
         self.active = false;
         self.visual_mode = VisualMode::None;
         self.visual_anchor = None;
+        self.pending_register = None;
     }
 
     pub fn is_active(&self) -> bool {
@@ -320,6 +332,48 @@ impl MarkdownTextReader {
         self.ensure_cursor_visible();
     }
 
+    // Backward word-end motion (ge)
+    pub fn normal_mode_word_end_backward(&mut self) {
+        if !self.normal_mode.active {
+            return;
+        }
+        let (mut new_line, new_col) =
+            self.find_prev_word_end(self.normal_mode.cursor.line, self.normal_mode.cursor.column);
+        // Skip image lines
+        if self.is_image_line(new_line) {
+            new_line = self.find_next_valid_line(new_line, -1);
+        }
+        self.normal_mode.cursor.line = new_line;
+        self.normal_mode.cursor.column = if self.is_image_line(new_line) {
+            0
+        } else {
+            new_col
+        };
+        self.clamp_column_to_line_length();
+        self.ensure_cursor_visible();
+    }
+
+    // Backward WORD-end motion (gE)
+    pub fn normal_mode_big_word_end_backward(&mut self) {
+        if !self.normal_mode.active {
+            return;
+        }
+        let (mut new_line, new_col) = self
+            .find_prev_big_word_end(self.normal_mode.cursor.line, self.normal_mode.cursor.column);
+        // Skip image lines
+        if self.is_image_line(new_line) {
+            new_line = self.find_next_valid_line(new_line, -1);
+        }
+        self.normal_mode.cursor.line = new_line;
+        self.normal_mode.cursor.column = if self.is_image_line(new_line) {
+            0
+        } else {
+            new_col
+        };
+        self.clamp_column_to_line_length();
+        self.ensure_cursor_visible();
+    }
+
     pub fn normal_mode_line_start(&mut self) {
         if !self.normal_mode.active {
             return;
@@ -575,14 +629,14 @@ impl MarkdownTextReader {
     fn get_line_char_count(&self, line: usize) -> usize {
         self.raw_text_lines
             .get(line)
-            .map(|s| s.chars().count())
+            .map(|s| grapheme_count(s))
             .unwrap_or(0)
     }
 
     fn get_first_non_whitespace_column(&self, line: usize) -> usize {
         self.raw_text_lines
             .get(line)
-            .map(|s| s.chars().position(|c| !c.is_whitespace()).unwrap_or(0))
+            .map(|s| first_non_whitespace_cluster(s))
             .unwrap_or(0)
     }
 
@@ -655,33 +709,29 @@ impl MarkdownTextReader {
         }
     }
 
-    fn is_word_char(c: char) -> bool {
-        c.is_alphanumeric() || c == '_'
-    }
-
     fn find_next_word_start(&self, line: usize, col: usize) -> (usize, usize) {
         let mut current_line = line;
         let mut current_col = col;
         let total_lines = self.raw_text_lines.len();
 
         while current_line < total_lines {
-            let chars: Vec<char> = self
+            let clusters: Vec<&str> = self
                 .raw_text_lines
                 .get(current_line)
-                .map(|s| s.chars().collect())
+                .map(|s| graphemes(s))
                 .unwrap_or_default();
 
-            // Skip current word (if on a word char)
-            while current_col < chars.len() {
-                if !Self::is_word_char(chars[current_col]) {
+            // Skip current word (if on a word cluster)
+            while current_col < clusters.len() {
+                if !is_word_cluster(clusters[current_col]) {
                     break;
                 }
                 current_col += 1;
             }
 
-            // Skip whitespace/non-word chars
-            while current_col < chars.len() {
-                if Self::is_word_char(chars[current_col]) {
+            // Skip whitespace/non-word clusters
+            while current_col < clusters.len() {
+                if is_word_cluster(clusters[current_col]) {
                     return (current_line, current_col);
                 }
                 current_col += 1;
@@ -704,20 +754,21 @@ impl MarkdownTextReader {
         let total_lines = self.raw_text_lines.len();
 
         while current_line < total_lines {
-            let chars: Vec<char> = self
+            let clusters: Vec<&str> = self
                 .raw_text_lines
                 .get(current_line)
-                .map(|s| s.chars().collect())
+                .map(|s| graphemes(s))
                 .unwrap_or_default();
 
             // Skip leading whitespace/non-word
-            while current_col < chars.len() && !Self::is_word_char(chars[current_col]) {
+            while current_col < clusters.len() && !is_word_cluster(clusters[current_col]) {
                 current_col += 1;
             }
 
             // Find end of word
-            while current_col < chars.len() {
-                if current_col + 1 >= chars.len() || !Self::is_word_char(chars[current_col + 1]) {
+            while current_col < clusters.len() {
+                if current_col + 1 >= clusters.len() || !is_word_cluster(clusters[current_col + 1])
+                {
                     return (current_line, current_col);
                 }
                 current_col += 1;
@@ -750,14 +801,14 @@ impl MarkdownTextReader {
         }
 
         loop {
-            let chars: Vec<char> = self
+            let clusters: Vec<&str> = self
                 .raw_text_lines
                 .get(current_line)
-                .map(|s| s.chars().collect())
+                .map(|s| graphemes(s))
                 .unwrap_or_default();
 
             // If line is empty, go to previous line
-            if chars.is_empty() {
+            if clusters.is_empty() {
                 if current_line == 0 {
                     return (0, 0);
                 }
@@ -767,20 +818,19 @@ impl MarkdownTextReader {
             }
 
             // Clamp column to valid range
-            current_col = current_col.min(chars.len().saturating_sub(1));
+            current_col = current_col.min(clusters.len().saturating_sub(1));
 
             // Skip whitespace/non-word going backward
-            while current_col > 0
-                && !Self::is_word_char(chars.get(current_col).copied().unwrap_or(' '))
+            while current_col > 0 && !is_word_cluster(clusters.get(current_col).copied().unwrap_or(" "))
             {
                 current_col -= 1;
             }
 
-            // Check if we found a word char
-            if Self::is_word_char(chars.get(current_col).copied().unwrap_or(' ')) {
+            // Check if we found a word cluster
+            if is_word_cluster(clusters.get(current_col).copied().unwrap_or(" ")) {
                 // Find start of this word
                 while current_col > 0
-                    && Self::is_word_char(chars.get(current_col - 1).copied().unwrap_or(' '))
+                    && is_word_cluster(clusters.get(current_col - 1).copied().unwrap_or(" "))
                 {
                     current_col -= 1;
                 }
@@ -796,6 +846,134 @@ impl MarkdownTextReader {
         }
     }
 
+    /// Backward word-end motion (`ge`): steps left past the current word's
+    /// interior, skips whitespace/non-word clusters backward (wrapping to
+    /// the end of the previous non-empty line), then stops on the first
+    /// word cluster encountered — which, approached from the right, is
+    /// already the end of the previous word.
+    fn find_prev_word_end(&self, line: usize, col: usize) -> (usize, usize) {
+        let mut current_line = line;
+        let mut current_col = col;
+
+        // If at column 0, must go to previous line
+        if current_col == 0 {
+            if current_line == 0 {
+                return (0, 0);
+            }
+            current_line -= 1;
+            current_col = self.get_line_char_count(current_line);
+        } else {
+            current_col -= 1;
+        }
+
+        loop {
+            let clusters: Vec<&str> = self
+                .raw_text_lines
+                .get(current_line)
+                .map(|s| graphemes(s))
+                .unwrap_or_default();
+
+            if clusters.is_empty() {
+                if current_line == 0 {
+                    return (0, 0);
+                }
+                current_line -= 1;
+                current_col = self.get_line_char_count(current_line);
+                continue;
+            }
+
+            current_col = current_col.min(clusters.len().saturating_sub(1));
+
+            // If the cursor started mid-word, step past this word's
+            // interior first so we land on the *previous* word's end.
+            while current_col > 0 && is_word_cluster(clusters.get(current_col).copied().unwrap_or(" "))
+                && is_word_cluster(clusters.get(current_col - 1).copied().unwrap_or(" "))
+            {
+                current_col -= 1;
+            }
+            if current_col > 0 && is_word_cluster(clusters.get(current_col).copied().unwrap_or(" "))
+            {
+                current_col -= 1;
+            }
+
+            // Skip whitespace/non-word going backward
+            while current_col > 0 && !is_word_cluster(clusters.get(current_col).copied().unwrap_or(" "))
+            {
+                current_col -= 1;
+            }
+
+            if is_word_cluster(clusters.get(current_col).copied().unwrap_or(" ")) {
+                return (current_line, current_col);
+            }
+
+            if current_line == 0 {
+                return (0, 0);
+            }
+            current_line -= 1;
+            current_col = self.get_line_char_count(current_line);
+        }
+    }
+
+    /// Backward WORD-end motion (`gE`), whitespace-delimited like `find_next_big_word_start`.
+    fn find_prev_big_word_end(&self, line: usize, col: usize) -> (usize, usize) {
+        let mut current_line = line;
+        let mut current_col = col;
+
+        if current_col == 0 {
+            if current_line == 0 {
+                return (0, 0);
+            }
+            current_line -= 1;
+            current_col = self.get_line_char_count(current_line);
+        } else {
+            current_col -= 1;
+        }
+
+        loop {
+            let clusters: Vec<&str> = self
+                .raw_text_lines
+                .get(current_line)
+                .map(|s| graphemes(s))
+                .unwrap_or_default();
+
+            if clusters.is_empty() {
+                if current_line == 0 {
+                    return (0, 0);
+                }
+                current_line -= 1;
+                current_col = self.get_line_char_count(current_line);
+                continue;
+            }
+
+            current_col = current_col.min(clusters.len().saturating_sub(1));
+
+            let is_ws = |g: &str| super::graphemes::is_whitespace_cluster(g);
+
+            while current_col > 0 && !is_ws(clusters.get(current_col).copied().unwrap_or(" "))
+                && !is_ws(clusters.get(current_col - 1).copied().unwrap_or(" "))
+            {
+                current_col -= 1;
+            }
+            if current_col > 0 && !is_ws(clusters.get(current_col).copied().unwrap_or(" ")) {
+                current_col -= 1;
+            }
+
+            while current_col > 0 && is_ws(clusters.get(current_col).copied().unwrap_or(" ")) {
+                current_col -= 1;
+            }
+
+            if !is_ws(clusters.get(current_col).copied().unwrap_or(" ")) {
+                return (current_line, current_col);
+            }
+
+            if current_line == 0 {
+                return (0, 0);
+            }
+            current_line -= 1;
+            current_col = self.get_line_char_count(current_line);
+        }
+    }
+
     fn find_prev_paragraph_boundary(&self, line: usize) -> usize {
         if line == 0 {
             return 0;
@@ -904,26 +1082,26 @@ impl MarkdownTextReader {
 
     fn find_big_word_bounds(&self, line: usize, col: usize) -> Option<(usize, usize)> {
         let line_text = self.raw_text_lines.get(line)?;
-        let chars: Vec<char> = line_text.chars().collect();
+        let clusters = graphemes(line_text);
 
-        if col >= chars.len() {
+        if col >= clusters.len() {
             return None;
         }
 
         // If on whitespace, return None (no WORD here)
-        if chars[col].is_whitespace() {
+        if super::graphemes::is_whitespace_cluster(clusters[col]) {
             return None;
         }
 
         // Find start of WORD (first non-whitespace going back)
         let mut start = col;
-        while start > 0 && !chars[start - 1].is_whitespace() {
+        while start > 0 && !super::graphemes::is_whitespace_cluster(clusters[start - 1]) {
             start -= 1;
         }
 
         // Find end of WORD (first whitespace or end of line)
         let mut end = col;
-        while end < chars.len() && !chars[end].is_whitespace() {
+        while end < clusters.len() && !super::graphemes::is_whitespace_cluster(clusters[end]) {
             end += 1;
         }
 
@@ -945,34 +1123,37 @@ impl MarkdownTextReader {
             return spans;
         }
 
+        // Cursor column is a grapheme-cluster index: split each span on
+        // cluster boundaries so a combining mark or wide glyph is painted
+        // as one unit rather than half-highlighted.
         let cursor_col = self.normal_mode.cursor.column;
         let mut result_spans = Vec::new();
         let mut current_column = 0;
 
         for span in spans {
-            let span_text: Vec<char> = span.content.chars().collect();
-            let span_len = span_text.len();
+            let span_clusters = graphemes(&span.content);
+            let span_len = span_clusters.len();
             let span_end = current_column + span_len;
 
             if cursor_col >= current_column && cursor_col < span_end {
                 let relative_pos = cursor_col - current_column;
 
                 if relative_pos > 0 {
-                    let before: String = span_text[..relative_pos].iter().collect();
+                    let before: String = span_clusters[..relative_pos].concat();
                     result_spans.push(Span::styled(before, span.style));
                 }
 
-                let cursor_char = span_text
+                let cursor_cluster = span_clusters
                     .get(relative_pos)
                     .map(|c| c.to_string())
                     .unwrap_or_else(|| " ".to_string());
                 let cursor_style = RatatuiStyle::default()
                     .fg(palette.base_00)
                     .bg(palette.base_05);
-                result_spans.push(Span::styled(cursor_char, cursor_style));
+                result_spans.push(Span::styled(cursor_cluster, cursor_style));
 
                 if relative_pos + 1 < span_len {
-                    let after: String = span_text[relative_pos + 1..].iter().collect();
+                    let after: String = span_clusters[relative_pos + 1..].concat();
                     result_spans.push(Span::styled(after, span.style));
                 }
             } else {
@@ -1016,6 +1197,52 @@ impl MarkdownTextReader {
         self.normal_mode.pending_yank = state;
     }
 
+    // ==================== REGISTERS ====================
+
+    /// Records the register named by a `"` prefix (e.g. the `a` in `"ayy`).
+    pub fn set_pending_register(&mut self, name: char) {
+        if self.normal_mode.active {
+            self.normal_mode.pending_register = Some(name);
+        }
+    }
+
+    pub fn has_pending_register(&self) -> bool {
+        self.normal_mode.pending_register.is_some()
+    }
+
+    /// Reads a register by name (e.g. `'a'`, `'"'`, `'0'`) for paste.
+    pub fn get_register(&self, name: char) -> Option<&str> {
+        self.normal_mode.registers.get(&name).map(|s| s.as_str())
+    }
+
+    /// Shifts numbered registers `"1`-`"9` down to make room for a fresh
+    /// linewise yank in `"0`, mirroring Vim's numbered-register ring.
+    fn shift_numbered_registers(&mut self, text: String) {
+        for n in (b'1'..=b'9').rev() {
+            let n = n as char;
+            let prev = (n as u8 - 1) as char;
+            if let Some(v) = self.normal_mode.registers.get(&prev).cloned() {
+                self.normal_mode.registers.insert(n, v);
+            }
+        }
+        self.normal_mode.registers.insert('0', text);
+    }
+
+    /// Routes a yanked string into the currently selected register
+    /// (defaulting to the unnamed register, plus the numbered-register ring
+    /// for linewise yanks), consuming any pending `"x` prefix.
+    fn store_yank(&mut self, text: &str, linewise: bool) {
+        self.normal_mode
+            .registers
+            .insert(UNNAMED_REGISTER, text.to_string());
+
+        if let Some(name) = self.normal_mode.pending_register.take() {
+            self.normal_mode.registers.insert(name, text.to_string());
+        } else if linewise {
+            self.shift_numbered_registers(text.to_string());
+        }
+    }
+
     pub fn clear_expired_yank_highlight(&mut self) {
         if let Some(ref highlight) = self.normal_mode.yank_highlight {
             if highlight.is_expired() {
@@ -1064,6 +1291,7 @@ impl MarkdownTextReader {
             .unwrap_or(0);
         self.set_yank_highlight(start_line, 0, end_line, end_len);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, true);
         Some(text)
     }
 
@@ -1082,6 +1310,7 @@ impl MarkdownTextReader {
         let text: String = chars[col..].iter().collect();
         self.set_yank_highlight(line, col, line, chars.len());
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1100,6 +1329,7 @@ impl MarkdownTextReader {
         let text: String = chars[..col].iter().collect();
         self.set_yank_highlight(line, 0, line, col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1123,6 +1353,7 @@ impl MarkdownTextReader {
         let text: String = chars.get(start..end)?.iter().collect();
         self.set_yank_highlight(line, start, line, end);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1141,6 +1372,7 @@ impl MarkdownTextReader {
         let text = self.extract_text(start_line, start_col, end_line, end_col)?;
         self.set_yank_highlight(start_line, start_col, end_line, end_col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1159,6 +1391,7 @@ impl MarkdownTextReader {
         let text = self.extract_text(start_line, start_col, end_line, end_col)?;
         self.set_yank_highlight(start_line, start_col, end_line, end_col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1177,6 +1410,7 @@ impl MarkdownTextReader {
         let text = self.extract_text(start_line, start_col, end_line, end_col + 1)?;
         self.set_yank_highlight(start_line, start_col, end_line, end_col + 1);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1195,6 +1429,45 @@ impl MarkdownTextReader {
         let text = self.extract_text(start_line, start_col, end_line, end_col)?;
         self.set_yank_highlight(start_line, start_col, end_line, end_col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
+        Some(text)
+    }
+
+    // Yank to previous word-end (yge, 2yge)
+    pub fn yank_word_end_backward(&mut self, count: usize) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let end_line = self.normal_mode.cursor.line;
+        let end_col = self.normal_mode.cursor.column;
+        let (mut start_line, mut start_col) = (end_line, end_col);
+        for _ in 0..count {
+            (start_line, start_col) = self.find_prev_word_end(start_line, start_col);
+        }
+
+        let text = self.extract_text(start_line, start_col, end_line, end_col + 1)?;
+        self.set_yank_highlight(start_line, start_col, end_line, end_col + 1);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
+        Some(text)
+    }
+
+    // Yank to previous WORD-end (ygE, 2ygE)
+    pub fn yank_big_word_end_backward(&mut self, count: usize) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let end_line = self.normal_mode.cursor.line;
+        let end_col = self.normal_mode.cursor.column;
+        let (mut start_line, mut start_col) = (end_line, end_col);
+        for _ in 0..count {
+            (start_line, start_col) = self.find_prev_big_word_end(start_line, start_col);
+        }
+
+        let text = self.extract_text(start_line, start_col, end_line, end_col + 1)?;
+        self.set_yank_highlight(start_line, start_col, end_line, end_col + 1);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1217,6 +1490,7 @@ impl MarkdownTextReader {
             .unwrap_or(0);
         self.set_yank_highlight(start_line, 0, end_line, end_len);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1239,6 +1513,7 @@ impl MarkdownTextReader {
             .unwrap_or(0);
         self.set_yank_highlight(start_line, 0, end_line, end_len);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1256,6 +1531,7 @@ impl MarkdownTextReader {
             .unwrap_or(0);
         self.set_yank_highlight(0, 0, end_line, end_len);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1274,6 +1550,7 @@ impl MarkdownTextReader {
             .unwrap_or(0);
         self.set_yank_highlight(start_line, 0, end_line, end_len);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1317,6 +1594,7 @@ impl MarkdownTextReader {
 
         self.set_yank_highlight(line, from, line, to);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1371,6 +1649,7 @@ impl MarkdownTextReader {
 
         self.set_yank_highlight(line, start, line, end);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1394,6 +1673,7 @@ impl MarkdownTextReader {
         let text: String = chars.get(start..end)?.iter().collect();
         self.set_yank_highlight(line, start, line, end);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1412,6 +1692,7 @@ impl MarkdownTextReader {
 
         self.set_yank_highlight(line, start, line, end);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1435,51 +1716,193 @@ impl MarkdownTextReader {
         let text: String = chars.get(start..end)?.iter().collect();
         self.set_yank_highlight(line, start, line, end);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
+        Some(text)
+    }
+
+    // ==================== TAG TEXT OBJECTS ====================
+
+    /// Returns, as byte offsets into the full document text, the
+    /// `(open_tag_end, close_tag_start)` span (the inner content) and the
+    /// `(open_tag_start, close_tag_end)` span (the whole element) of the
+    /// `<tag ...>...</tag>` pair nearest-enclosing `(line, col)`, tracking
+    /// nesting depth by tag name so `yit`/`yat` inside nested tags balance.
+    fn find_enclosing_tag(
+        &self,
+        line: usize,
+        col: usize,
+    ) -> Option<((usize, usize), (usize, usize))> {
+        let full = self.extract_lines(0, self.raw_text_lines.len().saturating_sub(1))?;
+        let offset = Self::pos_to_offset(&full, line, col)?;
+
+        let next_tag = |s: &str, from: usize| -> Option<(usize, usize, String, bool)> {
+            let bytes = s.as_bytes();
+            let mut i = from;
+            while i < bytes.len() {
+                if bytes[i] == b'<' {
+                    let rest = &s[i + 1..];
+                    let closing = rest.starts_with('/');
+                    let name_start = if closing { i + 2 } else { i + 1 };
+                    let name: String = s[name_start..]
+                        .chars()
+                        .take_while(|c| c.is_alphanumeric() || *c == '-')
+                        .collect();
+                    if !name.is_empty() {
+                        if let Some(end) = s[i..].find('>') {
+                            return Some((i, i + end + 1, name, closing));
+                        }
+                    }
+                }
+                i += 1;
+            }
+            None
+        };
+
+        // Walk forward from the document start to the cursor, tracking a
+        // per-tag-name nesting depth, so the last unmatched opener before
+        // the cursor is the nearest enclosing one.
+        let mut search_from = 0;
+        let mut best: Option<(usize, usize, String)> = None;
+        let mut depth_by_name: std::collections::HashMap<String, i32> = Default::default();
+        while let Some((start, end, name, closing)) = next_tag(&full, search_from) {
+            if start >= offset {
+                break;
+            }
+            if closing {
+                *depth_by_name.entry(name.clone()).or_insert(0) += 1;
+            } else {
+                let depth = depth_by_name.entry(name.clone()).or_insert(0);
+                if *depth > 0 {
+                    *depth -= 1;
+                } else {
+                    best = Some((start, end, name));
+                }
+            }
+            search_from = end;
+        }
+
+        let (open_start, open_end, tag_name) = best?;
+
+        // Find the matching closing tag after the opener, tracking nesting.
+        let mut depth = 0i32;
+        let mut from = open_end;
+        loop {
+            let (cstart, cend, name, closing) = next_tag(&full, from)?;
+            if name == tag_name {
+                if closing {
+                    if depth == 0 {
+                        return Some(((open_end, cstart), (open_start, cend)));
+                    }
+                    depth -= 1;
+                } else {
+                    depth += 1;
+                }
+            }
+            from = cend;
+        }
+    }
+
+    fn pos_to_offset(text: &str, line: usize, col: usize) -> Option<usize> {
+        let mut offset = 0;
+        for (i, l) in text.split('\n').enumerate() {
+            if i == line {
+                let char_offset: usize = l.chars().take(col).map(|c| c.len_utf8()).sum();
+                return Some(offset + char_offset);
+            }
+            offset += l.len() + 1;
+        }
+        None
+    }
+
+    fn offset_to_pos(text: &str, offset: usize) -> (usize, usize) {
+        let mut line = 0;
+        let mut running = 0;
+        for l in text.split('\n') {
+            if offset <= running + l.len() {
+                let col = text[running..offset].chars().count();
+                return (line, col);
+            }
+            running += l.len() + 1;
+            line += 1;
+        }
+        (line, 0)
+    }
+
+    /// Inner tag text object (`yit`): the content between `<tag>` and `</tag>`.
+    pub fn yank_inner_tag(&mut self) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let line = self.normal_mode.cursor.line;
+        let col = self.normal_mode.cursor.column;
+        let ((inner_start, inner_end), _) = self.find_enclosing_tag(line, col)?;
+        let full = self.extract_lines(0, self.raw_text_lines.len().saturating_sub(1))?;
+        let (isl, isc) = Self::offset_to_pos(&full, inner_start);
+        let (iel, iec) = Self::offset_to_pos(&full, inner_end);
+        let text = self.extract_text(isl, isc, iel, iec)?;
+        self.set_yank_highlight(isl, isc, iel, iec);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
+        Some(text)
+    }
+
+    /// Around tag text object (`yat`): includes both `<tag>` and `</tag>`.
+    pub fn yank_a_tag(&mut self) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let line = self.normal_mode.cursor.line;
+        let col = self.normal_mode.cursor.column;
+        let (_, (outer_start, outer_end)) = self.find_enclosing_tag(line, col)?;
+        let full = self.extract_lines(0, self.raw_text_lines.len().saturating_sub(1))?;
+        let (ol, oc) = Self::offset_to_pos(&full, outer_start);
+        let (el, ec) = Self::offset_to_pos(&full, outer_end);
+        let text = self.extract_text(ol, oc, el, ec)?;
+        self.set_yank_highlight(ol, oc, el, ec);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
     fn find_word_bounds(&self, line: usize, col: usize) -> Option<(usize, usize)> {
         let line_text = self.raw_text_lines.get(line)?;
-        let chars: Vec<char> = line_text.chars().collect();
+        let clusters = graphemes(line_text);
 
-        if col >= chars.len() {
+        if col >= clusters.len() {
             return None;
         }
 
-        let at_word = Self::is_word_char(chars[col]);
+        let is_ws = super::graphemes::is_whitespace_cluster;
+        let at_word = is_word_cluster(clusters[col]);
 
         if at_word {
             let mut start = col;
-            while start > 0 && Self::is_word_char(chars[start - 1]) {
+            while start > 0 && is_word_cluster(clusters[start - 1]) {
                 start -= 1;
             }
             let mut end = col;
-            while end < chars.len() && Self::is_word_char(chars[end]) {
+            while end < clusters.len() && is_word_cluster(clusters[end]) {
                 end += 1;
             }
             Some((start, end))
         } else {
             // On whitespace/punctuation - get that sequence
             let mut start = col;
-            while start > 0
-                && !Self::is_word_char(chars[start - 1])
-                && !chars[start - 1].is_whitespace()
+            while start > 0 && !is_word_cluster(clusters[start - 1]) && !is_ws(clusters[start - 1])
             {
                 start -= 1;
             }
             let mut end = col;
-            while end < chars.len()
-                && !Self::is_word_char(chars[end])
-                && !chars[end].is_whitespace()
+            while end < clusters.len() && !is_word_cluster(clusters[end]) && !is_ws(clusters[end])
             {
                 end += 1;
             }
             if start == end {
                 // Just whitespace
-                while start > 0 && chars[start - 1].is_whitespace() {
+                while start > 0 && is_ws(clusters[start - 1]) {
                     start -= 1;
                 }
-                while end < chars.len() && chars[end].is_whitespace() {
+                while end < clusters.len() && is_ws(clusters[end]) {
                     end += 1;
                 }
             }
@@ -1513,6 +1936,7 @@ impl MarkdownTextReader {
             .unwrap_or(0);
         self.set_yank_highlight(start, 0, end, end_len);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, true);
         Some(text)
     }
 
@@ -1554,6 +1978,7 @@ impl MarkdownTextReader {
             .unwrap_or(0);
         self.set_yank_highlight(start, 0, end, end_len);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, true);
         Some(text)
     }
 
@@ -1578,6 +2003,345 @@ impl MarkdownTextReader {
         Some((start, end))
     }
 
+    // ==================== SENTENCE TEXT OBJECTS ====================
+
+    fn is_sentence_terminator(cluster: &str) -> bool {
+        matches!(cluster, "." | "!" | "?")
+    }
+
+    fn is_closing_quote_or_bracket(cluster: &str) -> bool {
+        matches!(cluster, "\"" | "'" | ")" | "]")
+    }
+
+    /// Grapheme lengths of each line in `para_start..=para_end`, for mapping
+    /// between `(line, col)` and a flat offset into the paragraph's joined text.
+    fn paragraph_line_lengths(&self, para_start: usize, para_end: usize) -> Vec<usize> {
+        (para_start..=para_end)
+            .map(|l| {
+                self.raw_text_lines
+                    .get(l)
+                    .map(|s| grapheme_count(s))
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    fn flat_offset(line_lens: &[usize], para_start: usize, line: usize, col: usize) -> usize {
+        let mut offset = 0;
+        for (i, &len) in line_lens.iter().enumerate() {
+            if para_start + i == line {
+                return offset + col.min(len);
+            }
+            offset += len + 1; // +1 for the '\n' joiner inserted by extract_lines
+        }
+        offset
+    }
+
+    fn pos_from_flat_offset(
+        line_lens: &[usize],
+        para_start: usize,
+        mut offset: usize,
+    ) -> (usize, usize) {
+        for (i, &len) in line_lens.iter().enumerate() {
+            if offset <= len {
+                return (para_start + i, offset);
+            }
+            offset -= len + 1;
+        }
+        (
+            para_start + line_lens.len().saturating_sub(1),
+            line_lens.last().copied().unwrap_or(0),
+        )
+    }
+
+    /// Returns `(sentence_start, content_end, around_end)` as `(line, col)`
+    /// triples: `content_end` excludes trailing whitespace (for `is`),
+    /// `around_end` includes it, up to the next sentence's start (for `as`).
+    /// A sentence ends at `.`/`!`/`?`, optionally followed by closing
+    /// quotes/brackets, when that is followed by whitespace or paragraph end.
+    fn find_sentence_bounds(
+        &self,
+        line: usize,
+        col: usize,
+    ) -> Option<((usize, usize), (usize, usize), (usize, usize))> {
+        let (para_start, para_end) = self.find_paragraph_bounds(line)?;
+        let line_lens = self.paragraph_line_lengths(para_start, para_end);
+        let text = self.extract_lines(para_start, para_end)?;
+        let clusters = graphemes(&text);
+        let len = clusters.len();
+        if len == 0 {
+            return Some(((para_start, 0), (para_start, 0), (para_start, 0)));
+        }
+
+        let cursor_offset = Self::flat_offset(&line_lens, para_start, line, col).min(len - 1);
+
+        let mut starts = vec![0usize];
+        let mut i = 0;
+        while i < len {
+            if Self::is_sentence_terminator(clusters[i]) {
+                let mut j = i + 1;
+                while j < len && Self::is_closing_quote_or_bracket(clusters[j]) {
+                    j += 1;
+                }
+                if j >= len || clusters[j].chars().all(char::is_whitespace) {
+                    let mut next = j;
+                    while next < len && clusters[next].chars().all(char::is_whitespace) {
+                        next += 1;
+                    }
+                    if next < len {
+                        starts.push(next);
+                    }
+                    i = j.max(i + 1);
+                    continue;
+                }
+            }
+            i += 1;
+        }
+
+        let sentence_start = starts
+            .iter()
+            .rev()
+            .find(|&&s| s <= cursor_offset)
+            .copied()
+            .unwrap_or(0);
+        let sentence_pos = starts.iter().position(|&s| s == sentence_start)?;
+        let around_end = starts.get(sentence_pos + 1).copied().unwrap_or(len);
+
+        let mut content_end = around_end;
+        while content_end > sentence_start
+            && clusters[content_end - 1].chars().all(char::is_whitespace)
+        {
+            content_end -= 1;
+        }
+
+        Some((
+            Self::pos_from_flat_offset(&line_lens, para_start, sentence_start),
+            Self::pos_from_flat_offset(&line_lens, para_start, content_end),
+            Self::pos_from_flat_offset(&line_lens, para_start, around_end),
+        ))
+    }
+
+    // Inner sentence (is, 2is)
+    pub fn yank_inner_sentence(&mut self, count: usize) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let (line, col) = (self.normal_mode.cursor.line, self.normal_mode.cursor.column);
+        let (start, mut content_end, mut around_end) = self.find_sentence_bounds(line, col)?;
+
+        for _ in 1..count {
+            let Some((_, next_content_end, next_around_end)) =
+                self.find_sentence_bounds(around_end.0, around_end.1)
+            else {
+                break;
+            };
+            content_end = next_content_end;
+            around_end = next_around_end;
+        }
+
+        let text = self.extract_text(start.0, start.1, content_end.0, content_end.1)?;
+        self.set_yank_highlight(start.0, start.1, content_end.0, content_end.1);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
+        Some(text)
+    }
+
+    // Around sentence (as, 2as) - includes trailing whitespace up to the next sentence
+    pub fn yank_a_sentence(&mut self, count: usize) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let (line, col) = (self.normal_mode.cursor.line, self.normal_mode.cursor.column);
+        let (start, _, mut around_end) = self.find_sentence_bounds(line, col)?;
+
+        for _ in 1..count {
+            let Some((_, _, next_around_end)) = self.find_sentence_bounds(around_end.0, around_end.1)
+            else {
+                break;
+            };
+            around_end = next_around_end;
+        }
+
+        let text = self.extract_text(start.0, start.1, around_end.0, around_end.1)?;
+        self.set_yank_highlight(start.0, start.1, around_end.0, around_end.1);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
+        Some(text)
+    }
+
+    // ==================== MARKDOWN STRUCTURE TEXT OBJECTS ====================
+
+    fn is_fence_delimiter(line: &str) -> bool {
+        line.trim_start().starts_with("```")
+    }
+
+    fn heading_level(line: &str) -> Option<usize> {
+        let trimmed = line.trim_start();
+        let level = trimmed.chars().take_while(|&c| c == '#').count();
+        if level == 0 || level > 6 {
+            return None;
+        }
+        trimmed[level..].starts_with(' ').then_some(level)
+    }
+
+    fn list_item_indent(line: &str) -> Option<usize> {
+        let indent = line.len() - line.trim_start().len();
+        let trimmed = line.trim_start();
+        let is_bullet = ["- ", "* ", "+ "].iter().any(|m| trimmed.starts_with(m));
+        let digits: String = trimmed.chars().take_while(char::is_ascii_digit).collect();
+        let is_numbered = !digits.is_empty() && trimmed[digits.len()..].starts_with(". ");
+        (is_bullet || is_numbered).then_some(indent)
+    }
+
+    /// Finds the `(start, end)` line range of the fenced code block (inclusive
+    /// of both fence delimiters) that `line` falls within, by toggling
+    /// in/out-of-fence state from the top of the document.
+    fn find_code_block_bounds(&self, line: usize) -> Option<(usize, usize)> {
+        let mut fence_start: Option<usize> = None;
+        for l in 0..self.raw_text_lines.len() {
+            if !Self::is_fence_delimiter(self.raw_text_lines.get(l)?) {
+                continue;
+            }
+            match fence_start {
+                None => fence_start = Some(l),
+                Some(start) => {
+                    if start <= line && line <= l {
+                        return Some((start, l));
+                    }
+                    fence_start = None;
+                }
+            }
+        }
+        None
+    }
+
+    // Inner code block (i`) - the body between the fences, excluding them
+    pub fn yank_inner_code_block(&mut self) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let (fence_start, fence_end) = self.find_code_block_bounds(self.normal_mode.cursor.line)?;
+        if fence_end <= fence_start + 1 {
+            return None;
+        }
+        self.yank_linewise_range(fence_start + 1, fence_end - 1)
+    }
+
+    // Around code block (a`) - the whole fence, delimiters included
+    pub fn yank_a_code_block(&mut self) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let (start, end) = self.find_code_block_bounds(self.normal_mode.cursor.line)?;
+        self.yank_linewise_range(start, end)
+    }
+
+    fn yank_linewise_range(&mut self, start: usize, end: usize) -> Option<String> {
+        let text = self.extract_lines(start, end)?;
+        let end_len = self
+            .raw_text_lines
+            .get(end)
+            .map(|s| grapheme_count(s))
+            .unwrap_or(0);
+        self.set_yank_highlight(start, 0, end, end_len);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, true);
+        Some(text)
+    }
+
+    fn find_enclosing_heading(&self, line: usize) -> Option<(usize, usize)> {
+        (0..=line)
+            .rev()
+            .find_map(|l| Self::heading_level(self.raw_text_lines.get(l)?).map(|lvl| (l, lvl)))
+    }
+
+    // Heading section: from the heading line down to (excluding) the next
+    // heading of equal-or-higher level.
+    pub fn yank_heading_section(&mut self) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let (start, level) = self.find_enclosing_heading(self.normal_mode.cursor.line)?;
+        let total = self.raw_text_lines.len();
+        let mut end = total.saturating_sub(1);
+        for l in (start + 1)..total {
+            if let Some(next_level) = Self::heading_level(self.raw_text_lines.get(l)?) {
+                if next_level <= level {
+                    end = l - 1;
+                    break;
+                }
+            }
+        }
+
+        let text = self.extract_lines(start, end)?;
+        let end_len = self
+            .raw_text_lines
+            .get(end)
+            .map(|s| grapheme_count(s))
+            .unwrap_or(0);
+        self.set_yank_highlight(start, 0, end, end_len);
+        self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, true);
+        Some(text)
+    }
+
+    /// Finds the `(start, end)` line range of the list item (bullet/numbered
+    /// marker plus its indented continuation lines) that `line` belongs to.
+    fn find_list_item_bounds(&self, line: usize) -> Option<(usize, usize)> {
+        let mut start = line;
+        loop {
+            let text = self.raw_text_lines.get(start)?;
+            if Self::list_item_indent(text).is_some() {
+                break;
+            }
+            if start == 0 || self.is_line_blank(start) {
+                return None;
+            }
+            start -= 1;
+        }
+
+        let marker_indent = Self::list_item_indent(self.raw_text_lines.get(start)?)?;
+        let total = self.raw_text_lines.len();
+        let mut end = start;
+        for l in (start + 1)..total {
+            let text = self.raw_text_lines.get(l)?;
+            if self.is_line_blank(l) {
+                break;
+            }
+            if Self::list_item_indent(text).is_some_and(|i| i <= marker_indent) {
+                break; // next sibling/ancestor item
+            }
+            let continuation_indent = text.len() - text.trim_start().len();
+            if continuation_indent <= marker_indent {
+                break; // de-indented past the item's body
+            }
+            end = l;
+        }
+        Some((start, end))
+    }
+
+    // Inner list item (iI-ish): the item's marker and continuation lines
+    pub fn yank_inner_list_item(&mut self) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let (start, end) = self.find_list_item_bounds(self.normal_mode.cursor.line)?;
+        self.yank_linewise_range(start, end)
+    }
+
+    // Around list item: also consumes one trailing blank line, if present
+    pub fn yank_a_list_item(&mut self) -> Option<String> {
+        if !self.normal_mode.active {
+            return None;
+        }
+        let (start, mut end) = self.find_list_item_bounds(self.normal_mode.cursor.line)?;
+        let total = self.raw_text_lines.len();
+        if end + 1 < total && self.is_line_blank(end + 1) {
+            end += 1;
+        }
+        self.yank_linewise_range(start, end)
+    }
+
     // Inner quotes (i", i', i`)
     pub fn yank_inner_quotes(&mut self, quote: char) -> Option<String> {
         if !self.normal_mode.active {
@@ -1585,14 +2349,14 @@ impl MarkdownTextReader {
         }
         let line = self.normal_mode.cursor.line;
         let col = self.normal_mode.cursor.column;
-        let (start, end) = self.find_quote_bounds(line, col, quote, false)?;
+        let (start_line, start_col, end_line, end_col) =
+            self.find_quote_bounds(line, col, quote, false)?;
 
-        let line_text = self.raw_text_lines.get(line)?;
-        let chars: Vec<char> = line_text.chars().collect();
-        let text: String = chars.get(start..end)?.iter().collect();
+        let text = self.extract_text(start_line, start_col, end_line, end_col)?;
 
-        self.set_yank_highlight(line, start, line, end);
+        self.set_yank_highlight(start_line, start_col, end_line, end_col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1603,76 +2367,82 @@ impl MarkdownTextReader {
         }
         let line = self.normal_mode.cursor.line;
         let col = self.normal_mode.cursor.column;
-        let (start, end) = self.find_quote_bounds(line, col, quote, true)?;
+        let (start_line, start_col, end_line, end_col) =
+            self.find_quote_bounds(line, col, quote, true)?;
 
-        let line_text = self.raw_text_lines.get(line)?;
-        let chars: Vec<char> = line_text.chars().collect();
-        let text: String = chars.get(start..end)?.iter().collect();
+        let text = self.extract_text(start_line, start_col, end_line, end_col)?;
 
-        self.set_yank_highlight(line, start, line, end);
+        self.set_yank_highlight(start_line, start_col, end_line, end_col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
+    /// Grapheme-cluster columns of `quote` on `line_text` that aren't preceded
+    /// by a backslash escape.
+    fn unescaped_quote_cols(line_text: &str, quote: char) -> Vec<usize> {
+        let clusters = graphemes(line_text);
+        let is_quote = |g: &str| g.chars().next() == Some(quote) && g.chars().count() == 1;
+        clusters
+            .iter()
+            .enumerate()
+            .filter(|&(i, g)| is_quote(g) && clusters.get(i.wrapping_sub(1)) != Some(&"\\"))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Nearest-enclosing quoted span: pairs unescaped quotes left-to-right and
+    /// picks the pair that strictly contains the cursor, falling back to the
+    /// next pair to the right (matching Vim). If the line has an unmatched
+    /// opening quote at or after the cursor, the closing quote may be on a
+    /// later line within the same paragraph, so prose quotations that wrap
+    /// across lines are still selectable.
     fn find_quote_bounds(
         &self,
         line: usize,
         col: usize,
         quote: char,
         include_quotes: bool,
-    ) -> Option<(usize, usize)> {
+    ) -> Option<(usize, usize, usize, usize)> {
         let line_text = self.raw_text_lines.get(line)?;
-        let chars: Vec<char> = line_text.chars().collect();
-
-        // Find opening quote (search backward from cursor, or forward if on quote)
-        let mut open_pos = None;
-        let mut close_pos = None;
-
-        // Check if cursor is on a quote
-        if chars.get(col) == Some(&quote) {
-            // Could be opening or closing - need to count
-            let quotes_before: usize = chars[..col].iter().filter(|&&c| c == quote).count();
-            if quotes_before % 2 == 0 {
-                // This is an opening quote
-                open_pos = Some(col);
+        let positions = Self::unescaped_quote_cols(line_text, quote);
+
+        let pairs: Vec<(usize, usize)> = positions
+            .chunks(2)
+            .filter_map(|c| (c.len() == 2).then(|| (c[0], c[1])))
+            .collect();
+
+        let chosen = pairs
+            .iter()
+            .find(|&&(open, close)| col >= open && col <= close)
+            .or_else(|| pairs.iter().find(|&&(open, _)| open >= col))
+            .copied();
+
+        if let Some((open, close)) = chosen {
+            return if include_quotes {
+                Some((line, open, line, close + 1))
             } else {
-                // This is a closing quote
-                close_pos = Some(col);
-            }
+                Some((line, open + 1, line, close))
+            };
         }
 
-        // Search backward for opening quote if not found
-        if open_pos.is_none() {
-            for i in (0..col).rev() {
-                if chars[i] == quote {
-                    let quotes_before: usize = chars[..i].iter().filter(|&&c| c == quote).count();
-                    if quotes_before % 2 == 0 {
-                        open_pos = Some(i);
-                        break;
-                    }
-                }
-            }
+        // No closing quote on this line - look for one on a later paragraph line.
+        let last_open = positions.last().copied()?;
+        if col < last_open {
+            return None;
         }
-
-        let open = open_pos?;
-
-        // Search forward for closing quote if not found
-        if close_pos.is_none() {
-            for (i, ch) in chars.iter().enumerate().skip(open + 1) {
-                if *ch == quote {
-                    close_pos = Some(i);
-                    break;
-                }
+        let (_, para_end) = self.find_paragraph_bounds(line)?;
+        for close_line in (line + 1)..=para_end {
+            let text = self.raw_text_lines.get(close_line)?;
+            if let Some(&close_col) = Self::unescaped_quote_cols(text, quote).first() {
+                return if include_quotes {
+                    Some((line, last_open, close_line, close_col + 1))
+                } else {
+                    Some((line, last_open + 1, close_line, close_col))
+                };
             }
         }
-
-        let close = close_pos?;
-
-        if include_quotes {
-            Some((open, close + 1))
-        } else {
-            Some((open + 1, close))
-        }
+        None
     }
 
     // Inner brackets (i(, i), i[, i], i{, i}, i<, i>)
@@ -1686,6 +2456,7 @@ impl MarkdownTextReader {
         let text = self.extract_text(start_line, start_col, end_line, end_col)?;
         self.set_yank_highlight(start_line, start_col, end_line, end_col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1700,6 +2471,7 @@ impl MarkdownTextReader {
         let text = self.extract_text(start_line, start_col, end_line, end_col)?;
         self.set_yank_highlight(start_line, start_col, end_line, end_col);
         self.normal_mode.pending_yank = PendingYank::None;
+        self.store_yank(&text, false);
         Some(text)
     }
 
@@ -1787,6 +2559,10 @@ impl MarkdownTextReader {
 
     // ==================== HELPERS ====================
 
+    /// Rope-backed span extraction: O(log n) against `rope_lines` instead of
+    /// re-collecting each touched line into a `Vec<char>`. Falls back to the
+    /// line-vector path if the rope cache is stale (rebuilt only once per
+    /// render pass, see `render_document_to_lines`).
     fn extract_text(
         &self,
         start_line: usize,
@@ -1794,26 +2570,32 @@ impl MarkdownTextReader {
         end_line: usize,
         end_col: usize,
     ) -> Option<String> {
+        if self.rope_lines.version() == self.cache_generation {
+            if let Some(text) = self
+                .rope_lines
+                .slice_span(start_line, start_col, end_line, end_col)
+            {
+                return Some(text);
+            }
+        }
+
         if start_line == end_line {
             let line_text = self.raw_text_lines.get(start_line)?;
-            let chars: Vec<char> = line_text.chars().collect();
-            let text: String = chars.get(start_col..end_col)?.iter().collect();
-            return Some(text);
+            let clusters = graphemes(line_text);
+            return Some(clusters.get(start_col..end_col)?.concat());
         }
 
         let mut result = String::new();
 
         for line in start_line..=end_line {
             let line_text = self.raw_text_lines.get(line)?;
-            let chars: Vec<char> = line_text.chars().collect();
+            let clusters = graphemes(line_text);
 
             if line == start_line {
-                let text: String = chars.get(start_col..)?.iter().collect();
-                result.push_str(&text);
+                result.push_str(&clusters.get(start_col..)?.concat());
             } else if line == end_line {
                 result.push('\n');
-                let text: String = chars.get(..end_col)?.iter().collect();
-                result.push_str(&text);
+                result.push_str(&clusters.get(..end_col)?.concat());
             } else {
                 result.push('\n');
                 result.push_str(line_text);
@@ -1824,6 +2606,13 @@ impl MarkdownTextReader {
     }
 
     fn extract_lines(&self, start_line: usize, end_line: usize) -> Option<String> {
+        if self.rope_lines.version() == self.cache_generation {
+            let end_len = self.rope_lines.line_char_count(end_line);
+            if let Some(text) = self.rope_lines.slice_span(start_line, 0, end_line, end_len) {
+                return Some(text);
+            }
+        }
+
         let mut result = String::new();
 
         for line in start_line..=end_line {
@@ -1854,8 +2643,8 @@ impl MarkdownTextReader {
         let highlight_style = RatatuiStyle::default().bg(palette.base_02);
 
         for span in spans {
-            let span_text: Vec<char> = span.content.chars().collect();
-            let span_len = span_text.len();
+            let span_clusters = graphemes(&span.content);
+            let span_len = span_clusters.len();
             let span_end = current_column + span_len;
 
             let mut segments: Vec<(usize, usize, bool)> = vec![];
@@ -1879,7 +2668,7 @@ impl MarkdownTextReader {
             }
 
             for (start, end, highlighted) in segments {
-                let text: String = span_text[start..end].iter().collect();
+                let text: String = span_clusters[start..end].concat();
                 let style = if highlighted {
                     highlight_style
                 } else {
@@ -1921,6 +2710,12 @@ impl MarkdownTextReader {
         if !self.normal_mode.active {
             return;
         }
+        // Pressing the same visual-mode key again toggles back to normal mode,
+        // matching Vim (`v` then `v` cancels the selection).
+        if self.normal_mode.visual_mode == mode {
+            self.exit_visual_mode();
+            return;
+        }
         self.normal_mode.visual_mode = mode;
         self.normal_mode.visual_anchor = Some(self.normal_mode.cursor.clone());
     }
@@ -1963,10 +2758,23 @@ impl MarkdownTextReader {
                 let end_len = self
                     .raw_text_lines
                     .get(end_line)
-                    .map(|s| s.chars().count())
+                    .map(|s| grapheme_count(s))
                     .unwrap_or(0);
                 Some((start_line, 0, end_line, end_len))
             }
+            VisualMode::BlockWise => {
+                let (top_line, bottom_line) = if anchor.line <= cursor.line {
+                    (anchor.line, cursor.line)
+                } else {
+                    (cursor.line, anchor.line)
+                };
+                let (left_col, right_col) = if anchor.column <= cursor.column {
+                    (anchor.column, cursor.column + 1)
+                } else {
+                    (cursor.column, anchor.column + 1)
+                };
+                Some((top_line, left_col, bottom_line, right_col))
+            }
         }
     }
 
@@ -1978,10 +2786,28 @@ impl MarkdownTextReader {
             VisualMode::CharacterWise => {
                 self.extract_text(start_line, start_col, end_line, end_col)?
             }
+            VisualMode::BlockWise => (start_line..=end_line)
+                .map(|line| {
+                    self.raw_text_lines
+                        .get(line)
+                        .map(|s| {
+                            let clusters = graphemes(s);
+                            let end = end_col.min(clusters.len());
+                            if start_col >= end {
+                                String::new()
+                            } else {
+                                clusters[start_col..end].concat()
+                            }
+                        })
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
             VisualMode::None => return None,
         };
 
         self.set_yank_highlight(start_line, start_col, end_line, end_col);
+        self.store_yank(&text, self.normal_mode.visual_mode == VisualMode::LineWise);
 
         // Move cursor to start of selection (vim behavior)
         self.normal_mode.cursor.line = start_line;
@@ -2014,6 +2840,7 @@ impl MarkdownTextReader {
                     true
                 }
             }
+            VisualMode::BlockWise => col >= start_col && col < end_col,
             VisualMode::None => false,
         }
     }
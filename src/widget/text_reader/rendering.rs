@@ -1,3 +1,4 @@
+use super::rope_lines::RopeLines;
 use super::types::*;
 use crate::markdown::{
     Block as MarkdownBlock, Document, HeadingLevel, Inline, Node, Style, Text as MarkdownText,
@@ -16,6 +17,10 @@ pub struct RenderingContext {
     pub raw_text_lines: Vec<String>,
     pub anchor_positions: HashMap<String, usize>,
     pub links: Vec<LinkInfo>,
+    /// Rope view over `raw_text_lines`, rebuilt once per render pass so
+    /// normal-mode motions/yanks can slice it in O(log n) instead of
+    /// re-collecting a `Vec<char>` on every keystroke.
+    pub rope_lines: RopeLines,
 }
 
 impl RenderingContext {
@@ -24,6 +29,7 @@ impl RenderingContext {
             raw_text_lines: Vec::new(),
             anchor_positions: HashMap::new(),
             links: Vec::new(),
+            rope_lines: RopeLines::default(),
         }
     }
 }
@@ -63,6 +69,8 @@ impl crate::markdown_text_reader::MarkdownTextReader {
             self.links.extend(rendered_line.link_nodes.clone());
         }
 
+        self.rope_lines = RopeLines::from_lines(&self.raw_text_lines, self.cache_generation);
+
         RenderedContent {
             lines,
             total_height,
@@ -788,7 +796,7 @@ impl crate::markdown_text_reader::MarkdownTextReader {
         &mut self,
         header: &Option<crate::markdown::TableRow>,
         rows: &[crate::markdown::TableRow],
-        _alignment: &[crate::markdown::TableAlignment],
+        alignment: &[crate::markdown::TableAlignment],
         lines: &mut Vec<RenderedLine>,
         total_height: &mut usize,
         width: usize,
@@ -850,8 +858,20 @@ impl crate::markdown_text_reader::MarkdownTextReader {
         };
 
         // Create the table widget
+        let cell_alignments: Vec<crate::table::Alignment> = alignment
+            .iter()
+            .map(|a| match a {
+                crate::markdown::TableAlignment::Center => crate::table::Alignment::Center,
+                crate::markdown::TableAlignment::Right => crate::table::Alignment::Right,
+                crate::markdown::TableAlignment::Left | crate::markdown::TableAlignment::None => {
+                    crate::table::Alignment::Left
+                }
+            })
+            .collect();
+
         let mut custom_table = crate::table::Table::new(table_rows.clone())
             .constraints(constraints)
+            .alignments(cell_alignments)
             .config(table_config);
 
         if !table_headers.is_empty() {
@@ -0,0 +1,87 @@
+//! Rope-backed view over `raw_text_lines` for allocation-free motions.
+//!
+//! Word/yank motions used to call `raw_text_lines[line].chars().collect::<Vec<char>>()`
+//! on every keystroke, which reallocates the whole line each time. `RopeLines`
+//! wraps the same text in a `ropey::Rope` so line lookups, char indexing, and
+//! multi-line slicing are O(log n) against the rope instead of a fresh
+//! allocation per motion.
+
+use ropey::Rope;
+
+#[derive(Debug, Default)]
+pub struct RopeLines {
+    rope: Rope,
+    /// Bumped whenever `raw_text_lines` changes; callers rebuild when stale.
+    version: u64,
+}
+
+impl RopeLines {
+    pub fn from_lines(lines: &[String], version: u64) -> Self {
+        Self {
+            rope: Rope::from_str(&lines.join("\n")),
+            version,
+        }
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn line_char_count(&self, line: usize) -> usize {
+        if line >= self.rope.len_lines() {
+            return 0;
+        }
+        let slice = self.rope.line(line);
+        // Ropey counts the trailing '\n' as part of the line; strip it.
+        let len = slice.len_chars();
+        if line + 1 < self.rope.len_lines() && len > 0 {
+            len - 1
+        } else {
+            len
+        }
+    }
+
+    pub fn char_at(&self, line: usize, col: usize) -> Option<char> {
+        if line >= self.rope.len_lines() {
+            return None;
+        }
+        self.rope.line(line).get_char(col)
+    }
+
+    /// Extracts `[start_col, end_col)` on `line` in O(log n).
+    pub fn slice_line(&self, line: usize, start_col: usize, end_col: usize) -> Option<String> {
+        if line >= self.rope.len_lines() {
+            return None;
+        }
+        let slice = self.rope.line(line);
+        let end = end_col.min(self.line_char_count(line));
+        if start_col > end {
+            return None;
+        }
+        Some(slice.slice(start_col..end).to_string())
+    }
+
+    /// Extracts the span from `(start_line, start_col)` to `(end_line, end_col)`
+    /// (end exclusive), joining lines with `\n`, in O(log n + span length).
+    pub fn slice_span(
+        &self,
+        start_line: usize,
+        start_col: usize,
+        end_line: usize,
+        end_col: usize,
+    ) -> Option<String> {
+        if start_line == end_line {
+            return self.slice_line(start_line, start_col, end_col);
+        }
+        if start_line > end_line || end_line >= self.rope.len_lines() {
+            return None;
+        }
+
+        let start_char = self.rope.line_to_char(start_line) + start_col;
+        let end_char = self.rope.line_to_char(end_line) + end_col;
+        if start_char > end_char || end_char > self.rope.len_chars() {
+            return None;
+        }
+        Some(self.rope.slice(start_char..end_char).to_string())
+    }
+}
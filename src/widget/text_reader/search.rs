@@ -1,4 +1,4 @@
-use crate::search::{SearchState, SearchablePanel, find_matches_in_text};
+use crate::search::{SearchState, SearchablePanel, find_fuzzy_matches_in_text, find_matches_in_text};
 use crate::theme::Base16Palette;
 use ratatui::style::{Color, Style as RatatuiStyle};
 use ratatui::text::Span;
@@ -47,6 +47,16 @@ impl crate::markdown_text_reader::MarkdownTextReader {
         }
     }
 
+    /// Toggles between literal substring search and fuzzy subsequence search,
+    /// re-running the current query against the new mode.
+    pub fn toggle_search_fuzzy_mode(&mut self) {
+        self.search_state.toggle_fuzzy();
+        if self.search_state.active && !self.search_state.query.is_empty() {
+            let query = self.search_state.query.clone();
+            self.update_search_query(&query);
+        }
+    }
+
     /// Get searchable content (visible lines as text)
     pub fn get_visible_text(&self) -> Vec<String> {
         self.rendered_content
@@ -198,7 +208,11 @@ impl SearchablePanel for crate::markdown_text_reader::MarkdownTextReader {
 
         // Find matches in visible text
         let searchable = self.get_searchable_content();
-        let matches = find_matches_in_text(query, &searchable);
+        let matches = if self.search_state.fuzzy_mode {
+            find_fuzzy_matches_in_text(query, &searchable)
+        } else {
+            find_matches_in_text(query, &searchable)
+        };
         self.search_state.set_matches(matches);
 
         // Jump to match if found
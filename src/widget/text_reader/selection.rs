@@ -289,6 +289,7 @@ impl crate::markdown_text_reader::MarkdownTextReader {
             self.scroll_offset,
             content_area.x,
             content_area.y,
+            &self.raw_text_lines,
         )
     }
 
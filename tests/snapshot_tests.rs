@@ -1,81 +1,85 @@
-/// Snapshot tests for the BookRat application using clean initial conditions
-/// - No bookmarks (fresh start)
-/// - EPUB files only from testdata/ folder
-
+/// Harness smoke tests for the BookRat application using clean initial
+/// conditions (no bookmarks, no book directory).
+///
+/// These tests drive `App` through `TestScenarioBuilder::run`, which feeds
+/// simulated key events through the same dispatch path as the real event
+/// loop (`run_app_with_event_source`) and redraws between events. Captured
+/// buffers are run through `normalize_kitty_escapes` before comparison so
+/// non-deterministic image payloads wouldn't break a golden-file snapshot.
+///
+/// Scoped down from real golden-file assertions: this tree has no committed
+/// EPUB fixtures under `tests/testdata` (and no working `simple_fake_books`
+/// module to fabricate one in-memory - `mod simple_fake_books` is declared
+/// but the file doesn't exist in this tree), and no build tooling here to
+/// actually run the app and capture a golden file from real output. Writing
+/// a golden file by hand without running the renderer would just encode a
+/// guess at ratatui's exact byte output, which is worse than no fixture -
+/// it would fail the first time someone actually runs it. So these tests
+/// assert only that `run()` drives the dispatch path without panicking and
+/// produces a non-empty frame. Once a fixture (or `simple_fake_books`)
+/// exists and someone can run `cargo test` to capture real output, swap the
+/// `press_enter()` scenarios to `assert_snapshot` against a golden file
+/// captured that way.
 #[cfg(test)]
 mod snapshot_tests {
     use bookrat::test_utils::test_helpers::*;
-    
+
     #[test]
     fn test_initial_file_list_view() {
-        // Test the initial file list view with testdata EPUBs and no bookmarks
+        // Test the initial file list view with no bookmarks.
         let mut terminal = create_test_terminal(80, 24);
         let mut app = create_test_app();
-        
-        // Draw the initial state (should show file list with testdata EPUBs)
-        terminal.draw(|f| app.draw(f)).unwrap();
-        let snapshot = capture_terminal_state(&terminal);
-        
-        // Verify the snapshot is not empty
+
+        TestScenarioBuilder::new().build().run(&mut app, &mut terminal);
+        let snapshot = normalize_kitty_escapes(&capture_terminal_state(&terminal));
+
         assert!(!snapshot.is_empty());
-        
-        // For actual snapshot testing, you would use:
-        // snapbox::assert_eq(snapshot, snapbox::file!["snapshots/initial_file_list.txt"]);
     }
-    
+
     #[test]
     fn test_file_navigation() {
-        // Test navigating through the file list
+        // Test navigating through the file list.
         let mut terminal = create_test_terminal(80, 24);
         let mut app = create_test_app();
-        
-        // TODO: In a full implementation, you would simulate navigation events:
-        // let _event_source = TestScenarioBuilder::new()
-        //     .navigate_down(2)     // Press 'j' twice to navigate down
-        //     .build();
-        // and process them through the event loop
-        
-        // For now, just render the initial state
-        terminal.draw(|f| app.draw(f)).unwrap();
-        let snapshot = capture_terminal_state(&terminal);
-        
+
+        TestScenarioBuilder::new()
+            .navigate_down(2)
+            .build()
+            .run(&mut app, &mut terminal);
+        let snapshot = normalize_kitty_escapes(&capture_terminal_state(&terminal));
+
         assert!(!snapshot.is_empty());
     }
-    
-    #[test] 
+
+    #[test]
     fn test_file_selection_and_reading() {
-        // Test selecting a file and switching to reading mode
+        // Test selecting a file and switching to reading mode.
         let mut terminal = create_test_terminal(120, 40);
         let mut app = create_test_app();
-        
-        // TODO: In a full implementation, you would simulate file selection:
-        // let _event_source = TestScenarioBuilder::new()
-        //     .press_enter()       // Open the first file
-        //     .build();
-        // and process the events to switch to reading mode
-            
-        // For now, render initial state
-        terminal.draw(|f| app.draw(f)).unwrap();
-        let snapshot = capture_terminal_state(&terminal);
+
+        TestScenarioBuilder::new()
+            .press_enter() // Open the first file, if any are listed
+            .build()
+            .run(&mut app, &mut terminal);
+        let snapshot = normalize_kitty_escapes(&capture_terminal_state(&terminal));
+
         assert!(!snapshot.is_empty());
     }
 
     #[test]
     fn test_content_scrolling() {
-        // Test scrolling within content view
+        // Test opening a file and scrolling within content view.
         let mut terminal = create_test_terminal(100, 30);
         let mut app = create_test_app();
-        
-        // TODO: In a full implementation, you would simulate file opening and scrolling:
-        // let _event_source = TestScenarioBuilder::new()
-        //     .press_enter()        // Open first file
-        //     .navigate_down(5)     // Scroll down 5 lines
-        //     .half_screen_down()   // Half-screen scroll down
-        //     .build();
-        // and process the events through the event loop
-            
-        terminal.draw(|f| app.draw(f)).unwrap();
-        let snapshot = capture_terminal_state(&terminal);
+
+        TestScenarioBuilder::new()
+            .press_enter() // Open first file
+            .navigate_down(5) // Scroll down 5 lines
+            .half_screen_down() // Half-screen scroll down
+            .build()
+            .run(&mut app, &mut terminal);
+        let snapshot = normalize_kitty_escapes(&capture_terminal_state(&terminal));
+
         assert!(!snapshot.is_empty());
     }
-}
\ No newline at end of file
+}